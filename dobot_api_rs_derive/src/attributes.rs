@@ -0,0 +1,47 @@
+use syn::{Attribute, LitInt};
+
+/// The `#[body(...)]` options accepted on a field.
+#[derive(Default)]
+pub struct FieldAttributes {
+    pub pad_before: usize,
+    pub pad_after: usize,
+    pub reserved: bool,
+}
+
+impl FieldAttributes {
+    pub fn parse(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+        for attribute in attributes {
+            if !attribute.path().is_ident("body") {
+                continue;
+            }
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("pad_before") {
+                    parsed.pad_before = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                } else if meta.path.is_ident("pad_after") {
+                    parsed.pad_after = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                } else if meta.path.is_ident("reserved") {
+                    parsed.reserved = true;
+                } else {
+                    return Err(meta.error("expected `pad_before`, `pad_after` or `reserved`"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(parsed)
+    }
+}
+
+/// Rejects `#[body(...)]` where no options are accepted.
+pub fn reject(attributes: &[Attribute], place: &str) -> syn::Result<()> {
+    match attributes
+        .iter()
+        .find(|attribute| attribute.path().is_ident("body"))
+    {
+        Some(attribute) => Err(syn::Error::new_spanned(
+            attribute,
+            format!("`#[body]` is not accepted on {}", place),
+        )),
+        None => Ok(()),
+    }
+}
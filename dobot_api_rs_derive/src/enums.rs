@@ -0,0 +1,69 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataEnum, DeriveInput, Fields};
+
+use crate::{attributes, fixed_size_impls, protocol_path, round_trip};
+
+/// A fieldless `#[repr(u8)]` enum is one byte on the wire. Decoding goes
+/// through the enum's own `TryFrom<u8>`, so the set of accepted values and the
+/// error for the others stay with the enum.
+pub fn expand(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
+    attributes::reject(&input.attrs, "enums")?;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "Body cannot be derived for generic enums",
+        ));
+    }
+    if !is_repr_u8(input)? {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Body can only be derived for `#[repr(u8)]` enums",
+        ));
+    }
+
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        attributes::reject(&variant.attrs, "enum variants")?;
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "Body can only be derived for enums without fields",
+            ));
+        }
+        let name = &variant.ident;
+        arms.push(quote!(Self::#name => Self::#name as u8));
+    }
+
+    let protocol = protocol_path();
+    let size = quote!(1);
+    let write = quote! {
+        buffer[0] = match self {
+            #(#arms,)*
+        };
+    };
+    let read = quote! {
+        <Self as ::core::convert::TryFrom<u8>>::try_from(buffer[0])
+            .map_err(::core::convert::Into::<#protocol::ProtocolError>::into)
+    };
+
+    let impls = fixed_size_impls(input, &size, &write, &read);
+    let test = round_trip::expand(&input.ident, &size, false);
+    Ok(quote! {
+        #impls
+        #test
+    })
+}
+
+fn is_repr_u8(input: &DeriveInput) -> syn::Result<bool> {
+    let mut found = false;
+    for attribute in &input.attrs {
+        if attribute.path().is_ident("repr") {
+            attribute.parse_nested_meta(|meta| {
+                found |= meta.path.is_ident("u8");
+                Ok(())
+            })?;
+        }
+    }
+    Ok(found)
+}
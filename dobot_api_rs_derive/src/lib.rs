@@ -0,0 +1,109 @@
+//! `#[derive(Body)]` for the protocol bodies of `dobot_api_rs`.
+//!
+//! The derive writes the `Body` implementation that every module in
+//! `protocol::bodies` used to spell out by hand: a little-endian layout of the
+//! fields in declaration order, a `BufferTooSmall` check on both directions and
+//! no allocation, so the generated code works in `no_std` builds.
+//!
+//! Supported shapes:
+//!
+//! * structs whose fields implement `BodyField`: the integer and float
+//!   primitives, `bool`, fixed arrays of those, and other derived bodies;
+//! * a trailing `&'a [u8]` field, which takes whatever is left of the buffer;
+//! * fieldless `#[repr(u8)]` enums that implement `TryFrom<u8>`.
+//!
+//! Field attributes:
+//!
+//! * `#[body(pad_before = N)]` / `#[body(pad_after = N)]` write `N` zero bytes
+//!   around the field and skip them when reading;
+//! * `#[body(reserved)]` writes the field's `Default` value and ignores the
+//!   bytes on the wire when reading.
+//!
+//! Each derive also emits a round-trip test for the type.
+
+mod attributes;
+mod enums;
+mod round_trip;
+mod structs;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, parse_macro_input};
+
+#[proc_macro_derive(Body, attributes(body))]
+pub fn derive_body(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let expanded = match &input.data {
+        Data::Struct(data) => structs::expand(&input, data),
+        Data::Enum(data) => enums::expand(&input, data),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "Body cannot be derived for unions",
+        )),
+    };
+    expanded
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Path to the `protocol` module of `dobot_api_rs`.
+fn protocol_path() -> TokenStream2 {
+    quote!(::dobot_api_rs::dobot::dobot_trait::protocol)
+}
+
+/// The `Body` and `BodyField` impls shared by fixed-size structs and enums.
+///
+/// `write` and `read` are the bodies of `BodyField::write` and
+/// `BodyField::read`; they see `buffer` already cut to `SIZE` bytes.
+fn fixed_size_impls(
+    input: &DeriveInput,
+    size: &TokenStream2,
+    write: &TokenStream2,
+    read: &TokenStream2,
+) -> TokenStream2 {
+    let protocol = protocol_path();
+    let ident = &input.ident;
+
+    quote! {
+        impl #protocol::BodyField for #ident {
+            const SIZE: usize = #size;
+
+            fn write(&self, buffer: &mut [u8]) {
+                #write
+            }
+
+            fn read(buffer: &[u8]) -> ::core::result::Result<Self, #protocol::ProtocolError> {
+                #read
+            }
+        }
+
+        impl<'a> #protocol::Body<'a> for #ident {
+            fn size(&self) -> usize {
+                <Self as #protocol::BodyField>::SIZE
+            }
+
+            fn serialize(
+                &self,
+                buffer: &mut [u8],
+            ) -> ::core::result::Result<usize, #protocol::ProtocolError> {
+                let size = <Self as #protocol::BodyField>::SIZE;
+                if buffer.len() < size {
+                    return ::core::result::Result::Err(#protocol::ProtocolError::BufferTooSmall);
+                }
+                #protocol::BodyField::write(self, &mut buffer[..size]);
+                ::core::result::Result::Ok(size)
+            }
+
+            fn deserialize(
+                buffer: &'a [u8],
+            ) -> ::core::result::Result<Self, #protocol::ProtocolError> {
+                let size = <Self as #protocol::BodyField>::SIZE;
+                if buffer.len() < size {
+                    return ::core::result::Result::Err(#protocol::ProtocolError::BufferTooSmall);
+                }
+                <Self as #protocol::BodyField>::read(&buffer[..size])
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+use crate::protocol_path;
+
+/// Bytes appended after the fixed part when the body ends in a byte slice.
+const TRAILING_BYTES: usize = 3;
+
+/// Emits a `#[cfg(test)]` module next to the type that decodes every
+/// single-byte fill pattern the type accepts and checks that encoding is
+/// stable across a second decode/encode pass, that `size` matches what is
+/// written and that short buffers are rejected in both directions.
+pub fn expand(ident: &Ident, fixed_size: &TokenStream, trailing: bool) -> TokenStream {
+    let protocol = protocol_path();
+    let module = format_ident!("__body_round_trip_{}", ident);
+    let trailing_bytes = if trailing { TRAILING_BYTES } else { 0 };
+    let name = ident.to_string();
+
+    quote! {
+        #[cfg(test)]
+        #[allow(non_snake_case)]
+        mod #module {
+            #[allow(unused_imports)]
+            use super::*;
+            use #protocol::{Body, ProtocolError};
+
+            #[test]
+            fn round_trip() {
+                const FIXED: usize = #fixed_size;
+                const LEN: usize = FIXED + #trailing_bytes;
+
+                let mut decoded = 0usize;
+                for fill in 0..=u8::MAX {
+                    let bytes = [fill; LEN];
+                    let Ok(value) = <super::#ident as Body>::deserialize(&bytes) else {
+                        continue;
+                    };
+                    decoded += 1;
+
+                    let mut first = [0u8; LEN];
+                    let written = value.serialize(&mut first).unwrap();
+                    assert_eq!(written, LEN);
+                    assert_eq!(value.size(), LEN);
+
+                    let again = <super::#ident as Body>::deserialize(&first).unwrap();
+                    let mut second = [0u8; LEN];
+                    again.serialize(&mut second).unwrap();
+                    assert_eq!(first, second, "{} is not stable for fill {:#04x}", #name, fill);
+
+                    if let Some(short) = LEN.checked_sub(1) {
+                        let mut too_small = [0u8; LEN];
+                        assert_eq!(
+                            value.serialize(&mut too_small[..short]),
+                            Err(ProtocolError::BufferTooSmall)
+                        );
+                    }
+                }
+                assert!(decoded > 0, "no fill pattern decodes as {}", #name);
+
+                if let Some(short) = FIXED.checked_sub(1) {
+                    let bytes = [0u8; LEN];
+                    assert_eq!(
+                        <super::#ident as Body>::deserialize(&bytes[..short]).err(),
+                        Some(ProtocolError::BufferTooSmall)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DataStruct, DeriveInput, GenericParam, Lifetime, Member, Type, TypeReference};
+
+use crate::{attributes, attributes::FieldAttributes, fixed_size_impls, protocol_path, round_trip};
+
+/// Fields are laid out back to back in declaration order. `offset` is kept as
+/// a token expression so the sizes of nested bodies and enums are resolved by
+/// the compiler rather than by the macro.
+pub fn expand(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStream> {
+    attributes::reject(&input.attrs, "structs")?;
+    if let Some(param) = input
+        .generics
+        .params
+        .iter()
+        .find(|param| !matches!(param, GenericParam::Lifetime(_)))
+    {
+        return Err(syn::Error::new_spanned(
+            param,
+            "Body can only be derived for structs whose generics are lifetimes",
+        ));
+    }
+
+    let protocol = protocol_path();
+    let field_count = data.fields.len();
+
+    let mut offset = quote!(0);
+    let mut writes = Vec::new();
+    let mut reads = Vec::new();
+    let mut inits = Vec::new();
+    let mut trailing = None;
+
+    for (index, field) in data.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(index.into()),
+        };
+        let local = format_ident!("field_{}", index);
+        let options = FieldAttributes::parse(&field.attrs)?;
+
+        if let Some(lifetime) = byte_slice_lifetime(&field.ty) {
+            if index + 1 != field_count {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "a byte slice must be the last field of a body",
+                ));
+            }
+            if options.pad_before != 0 || options.pad_after != 0 || options.reserved {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "a trailing byte slice cannot be padded or reserved",
+                ));
+            }
+            writes.push(quote! {
+                buffer[#offset..#offset + self.#member.len()].copy_from_slice(self.#member);
+            });
+            reads.push(quote!(let #local = &buffer[#offset..];));
+            inits.push(quote!(#member: #local));
+            trailing = Some((member, lifetime));
+            continue;
+        }
+
+        let ty = &field.ty;
+        let field_size = quote!(<#ty as #protocol::BodyField>::SIZE);
+
+        if options.pad_before != 0 {
+            let pad = options.pad_before;
+            writes.push(quote!(buffer[#offset..#offset + #pad].fill(0);));
+            offset = quote!(#offset + #pad);
+        }
+
+        let range = quote!(#offset..#offset + #field_size);
+        if options.reserved {
+            writes.push(quote! {
+                #protocol::BodyField::write(
+                    &<#ty as ::core::default::Default>::default(),
+                    &mut buffer[#range],
+                );
+            });
+            reads.push(quote!(let #local = <#ty as ::core::default::Default>::default();));
+        } else {
+            writes.push(quote!(#protocol::BodyField::write(&self.#member, &mut buffer[#range]);));
+            reads.push(quote!(let #local = <#ty as #protocol::BodyField>::read(&buffer[#range])?;));
+        }
+        inits.push(quote!(#member: #local));
+        offset = quote!(#offset + #field_size);
+
+        if options.pad_after != 0 {
+            let pad = options.pad_after;
+            writes.push(quote!(buffer[#offset..#offset + #pad].fill(0);));
+            offset = quote!(#offset + #pad);
+        }
+    }
+
+    let ident = &input.ident;
+    let read = quote! {
+        #(#reads)*
+        ::core::result::Result::Ok(Self { #(#inits),* })
+    };
+
+    let Some((member, lifetime)) = trailing else {
+        let write = quote!(#(#writes)*);
+        let impls = fixed_size_impls(input, &offset, &write, &read);
+        let test = round_trip::expand(ident, &offset, false);
+        return Ok(quote! {
+            #impls
+            #test
+        });
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let test = round_trip::expand(ident, &offset, true);
+    Ok(quote! {
+        impl #impl_generics #protocol::Body<#lifetime> for #ident #ty_generics #where_clause {
+            fn size(&self) -> usize {
+                #offset + self.#member.len()
+            }
+
+            fn serialize(
+                &self,
+                buffer: &mut [u8],
+            ) -> ::core::result::Result<usize, #protocol::ProtocolError> {
+                let size = #protocol::Body::size(self);
+                if buffer.len() < size {
+                    return ::core::result::Result::Err(#protocol::ProtocolError::BufferTooSmall);
+                }
+                #(#writes)*
+                ::core::result::Result::Ok(size)
+            }
+
+            fn deserialize(
+                buffer: &#lifetime [u8],
+            ) -> ::core::result::Result<Self, #protocol::ProtocolError> {
+                if buffer.len() < #offset {
+                    return ::core::result::Result::Err(#protocol::ProtocolError::BufferTooSmall);
+                }
+                #read
+            }
+        }
+
+        #test
+    })
+}
+
+/// Returns the lifetime of a `&'a [u8]` field.
+fn byte_slice_lifetime(ty: &Type) -> Option<Lifetime> {
+    let Type::Reference(TypeReference {
+        lifetime: Some(lifetime),
+        mutability: None,
+        elem,
+        ..
+    }) = ty
+    else {
+        return None;
+    };
+    let Type::Slice(slice) = elem.as_ref() else {
+        return None;
+    };
+    let Type::Path(path) = slice.elem.as_ref() else {
+        return None;
+    };
+    path.path.is_ident("u8").then(|| lifetime.clone())
+}
@@ -0,0 +1,808 @@
+mod test;
+
+use std::{
+    error::Error,
+    fs,
+    net::Ipv4Addr,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use clap::{Args, Subcommand, ValueEnum};
+use dobot_api_rs::dobot::{
+    dobot_core_serial::{
+        handle::DobotHandle,
+        jog_session::{JogSession, JogSessionOptions},
+        profile::RobotProfile,
+    },
+    dobot_trait::{
+        dobot_core::{
+            command_sender::CommandSender,
+            dobot_error::DobotError,
+            sub_command_dobot::{
+                alarm_control::AlarmControl, arc_control::ArcControl, cp_control::CPControl,
+                device_control::DeviceControl, end_effector_control::EndEffectorControl,
+                home_control::HomeControl, io_control::IOControl, ptp_control::PTPControl,
+                queue_control::QueueControl, real_time_control::RealTimeControl,
+                wifi_control::WifiControl,
+            },
+        },
+        protocol::bodies::{
+            level::Level,
+            tag_arc_cmd::{Point, TagARCCmd},
+            tag_cp_cmd::{CPMode, TagCPCmd},
+            tag_home_cmd::TagHomeCmd,
+            tag_io_do::TagIODO,
+            tag_io_multiplexing::{IOFunction, TagIOMultiplexing},
+            tag_io_pwm::TagIOPWM,
+            tag_jog_cmd::{JogCmd, JogMode},
+            tag_ptp_cmd::{PTPMode, TagPTPCmd},
+            tag_ptp_with_l_cmd::TagPTPWithLCmd,
+            tag_wifi_dns::TagWIFIDNS,
+            tag_wifi_gateway::TagWIFIGateway,
+            tag_wifi_ip_address::TagWIFIIPAddress,
+            tag_wifi_netmask::TagWIFINetmask,
+        },
+    },
+};
+use serde_json::{Value, json};
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Show the name, serial number, firmware version and rail of the arm.
+    Info,
+    /// Show the pose, joint angles and rail position.
+    Pose,
+    /// Home the arm.
+    Home(WaitArgs),
+    /// Move the arm with a queued PTP, CP or ARC command.
+    #[command(subcommand)]
+    Move(MoveCommand),
+    /// Jog one axis for a time or a distance.
+    Jog(JogArgs),
+    /// Read and drive the extended IO interfaces.
+    #[command(subcommand)]
+    Io(IoCommand),
+    /// Show or switch the gripper, suction cup or laser.
+    Effector(EffectorArgs),
+    /// List or clear the active alarms.
+    #[command(subcommand)]
+    Alarms(AlarmsCommand),
+    /// Control the command queue.
+    #[command(subcommand)]
+    Queue(QueueCommand),
+    /// Show or change the WiFi settings.
+    #[command(subcommand)]
+    Wifi(WifiCommand),
+    /// Save or restore every user-configurable parameter.
+    #[command(subcommand)]
+    Params(ParamsCommand),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct WaitArgs {
+    /// Return once the command is queued instead of when it has run.
+    #[arg(long)]
+    pub no_wait: bool,
+    /// Seconds to wait for the command to run.
+    #[arg(long, default_value = "60", value_parser = parse_seconds)]
+    pub timeout: Duration,
+}
+
+/// Parses a non-negative, finite number of seconds such as `2.5`.
+pub fn parse_seconds(seconds: &str) -> Result<Duration, String> {
+    let value: f64 = seconds
+        .parse()
+        .map_err(|_| format!("`{}` is not a number of seconds", seconds))?;
+    Duration::try_from_secs_f64(value).map_err(|e| format!("`{}` seconds: {}", seconds, e))
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MoveCommand {
+    /// Point-to-point move to X Y Z R, or to four joint angles.
+    #[command(allow_negative_numbers = true)]
+    Ptp {
+        #[arg(long, value_enum, default_value_t = PtpMode::MovjXyz)]
+        mode: PtpMode,
+        x: f32,
+        y: f32,
+        z: f32,
+        r: f32,
+        /// Also move the rail to this position, in mm.
+        #[arg(long)]
+        rail: Option<f32>,
+        #[command(flatten)]
+        wait: WaitArgs,
+    },
+    /// Continuous path move to X Y Z.
+    #[command(allow_negative_numbers = true)]
+    Cp {
+        /// Treat X Y Z as an offset from the current position.
+        #[arg(long)]
+        relative: bool,
+        x: f32,
+        y: f32,
+        z: f32,
+        /// Velocity in mm/s, or laser power in laser engraving mode.
+        #[arg(long, default_value_t = 100.0)]
+        velocity: f32,
+        #[command(flatten)]
+        wait: WaitArgs,
+    },
+    /// Circular arc through CX CY CZ CR ending at X Y Z R.
+    #[command(allow_negative_numbers = true)]
+    Arc {
+        cx: f32,
+        cy: f32,
+        cz: f32,
+        cr: f32,
+        x: f32,
+        y: f32,
+        z: f32,
+        r: f32,
+        #[command(flatten)]
+        wait: WaitArgs,
+    },
+}
+
+/// The PTP modes, as named on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PtpMode {
+    JumpXyz,
+    MovjXyz,
+    MovlXyz,
+    JumpAngle,
+    MovjAngle,
+    MovlAngle,
+    MovjInc,
+    MovlInc,
+    MovjXyzInc,
+    JumpMovlXyz,
+}
+
+impl From<PtpMode> for PTPMode {
+    fn from(mode: PtpMode) -> Self {
+        match mode {
+            PtpMode::JumpXyz => PTPMode::JumpXyz,
+            PtpMode::MovjXyz => PTPMode::MovjXyz,
+            PtpMode::MovlXyz => PTPMode::MovlXyz,
+            PtpMode::JumpAngle => PTPMode::JumpAngle,
+            PtpMode::MovjAngle => PTPMode::MovjAngle,
+            PtpMode::MovlAngle => PTPMode::MovlAngle,
+            PtpMode::MovjInc => PTPMode::MovjInc,
+            PtpMode::MovlInc => PTPMode::MovlInc,
+            PtpMode::MovjXyzInc => PTPMode::MovjXyzInc,
+            PtpMode::JumpMovlXyz => PTPMode::JumpMovlXyz,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct JogArgs {
+    /// Axis and direction: x+ to r-, j1+ to j4-, or rail+ and rail-.
+    #[arg(value_parser = parse_jog_axis, allow_hyphen_values = true)]
+    pub axis: (JogMode, JogCmd),
+    /// Jog for this many milliseconds.
+    #[arg(
+        long,
+        required_unless_present = "distance",
+        conflicts_with = "distance"
+    )]
+    pub ms: Option<u64>,
+    /// Jog until the axis has moved this far, in mm or degrees.
+    #[arg(long)]
+    pub distance: Option<f32>,
+}
+
+/// Parses a jog axis such as `z-` or `j2+`.
+pub fn parse_jog_axis(axis: &str) -> Result<(JogMode, JogCmd), String> {
+    let (name, positive) = match axis.to_ascii_lowercase() {
+        name if name.ends_with('+') => (name.trim_end_matches('+').to_string(), true),
+        name if name.ends_with('-') => (name.trim_end_matches('-').to_string(), false),
+        _ => return Err(format!("`{}` needs a direction, + or -", axis)),
+    };
+    let (mode, index) = match name.as_str() {
+        "x" => (JogMode::Coordinate, 0),
+        "y" => (JogMode::Coordinate, 1),
+        "z" => (JogMode::Coordinate, 2),
+        "r" => (JogMode::Coordinate, 3),
+        "j1" => (JogMode::Joint, 0),
+        "j2" => (JogMode::Joint, 1),
+        "j3" => (JogMode::Joint, 2),
+        "j4" => (JogMode::Joint, 3),
+        "rail" => (JogMode::Coordinate, 4),
+        _ => return Err(format!("unknown axis `{}`", name)),
+    };
+    let cmd = match (index, positive) {
+        (0, true) => JogCmd::ApDown,
+        (0, false) => JogCmd::AnDown,
+        (1, true) => JogCmd::BpDown,
+        (1, false) => JogCmd::BnDown,
+        (2, true) => JogCmd::CpDown,
+        (2, false) => JogCmd::CnDown,
+        (3, true) => JogCmd::DpDown,
+        (3, false) => JogCmd::DnDown,
+        (_, true) => JogCmd::LpDown,
+        (_, false) => JogCmd::LnDown,
+    };
+    Ok((mode, cmd))
+}
+
+#[derive(Debug, Subcommand)]
+pub enum IoCommand {
+    /// Read an interface according to the function it is configured for.
+    Get { address: u8 },
+    /// Configure an interface as a digital output and drive it.
+    Set {
+        address: u8,
+        #[arg(value_enum)]
+        level: LevelArg,
+    },
+    /// Configure an interface as a PWM output and drive it.
+    Pwm {
+        address: u8,
+        /// Frequency in Hz, 10 to 1000000.
+        frequency: f32,
+        /// Duty cycle in percent.
+        duty_cycle: f32,
+    },
+    /// Read an analog input.
+    Adc { address: u8 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LevelArg {
+    High,
+    Low,
+}
+
+#[derive(Debug, Args)]
+pub struct EffectorArgs {
+    #[arg(value_enum)]
+    pub tool: Tool,
+    /// Leave out to show the state of the tool.
+    #[arg(value_enum)]
+    pub state: Option<ToolState>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Tool {
+    Gripper,
+    Suction,
+    Laser,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ToolState {
+    /// Close the gripper, start sucking or fire the laser.
+    On,
+    /// Open the gripper, release the suction cup or stop the laser.
+    Off,
+    /// Stop driving the tool altogether.
+    Disable,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AlarmsCommand {
+    List,
+    Clear,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum QueueCommand {
+    /// Start executing queued commands.
+    Start,
+    /// Stop executing queued commands once the current one has finished.
+    Stop {
+        /// Stop the current command immediately.
+        #[arg(long)]
+        force: bool,
+    },
+    /// Remove every queued command that has not run yet.
+    Clear,
+    /// Show the index of the last queued command that ran.
+    Index,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum WifiCommand {
+    /// Show the WiFi settings and connection state.
+    Show,
+    /// Change the given WiFi settings.
+    Set(WifiSettings),
+}
+
+#[derive(Debug, Args)]
+pub struct WifiSettings {
+    #[arg(long)]
+    pub ssid: Option<String>,
+    #[arg(long)]
+    pub password: Option<String>,
+    /// Static address of the arm; implies --dhcp off.
+    #[arg(long, conflicts_with = "dhcp")]
+    pub ip: Option<Ipv4Addr>,
+    /// Take the address from a DHCP server.
+    #[arg(long)]
+    pub dhcp: bool,
+    #[arg(long)]
+    pub netmask: Option<Ipv4Addr>,
+    #[arg(long)]
+    pub gateway: Option<Ipv4Addr>,
+    #[arg(long)]
+    pub dns: Option<Ipv4Addr>,
+    /// Turn WiFi configuration mode on or off.
+    #[arg(long)]
+    pub config_mode: Option<bool>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ParamsCommand {
+    /// Read every parameter, optionally into a TOML or JSON file.
+    Dump {
+        /// File to write; JSON if it ends in .json, TOML otherwise.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Apply the parameters of a file written by `params dump`.
+    Load {
+        file: PathBuf,
+        /// Only show what would change.
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+/// Runs `command` against `arm` and returns what it reports.
+pub fn run<T: CommandSender + 'static>(
+    arm: &DobotHandle<T>,
+    command: Command,
+) -> Result<Value, Box<dyn Error>> {
+    match command {
+        Command::Info => info(arm),
+        Command::Pose => pose(arm),
+        Command::Home(wait) => {
+            let index = arm
+                .controller()
+                .homing_controller
+                .set_home_cmd(TagHomeCmd { reserved: 0 }, true)?;
+            finish_queued(arm, index, &wait)
+        }
+        Command::Move(command) => move_arm(arm, command),
+        Command::Jog(args) => jog(arm, args),
+        Command::Io(command) => io(arm, command),
+        Command::Effector(args) => effector(arm, args),
+        Command::Alarms(AlarmsCommand::List) => alarms(arm),
+        Command::Alarms(AlarmsCommand::Clear) => {
+            arm.controller().alarm_controller.clear_all_alarms_state()?;
+            alarms(arm)
+        }
+        Command::Queue(command) => queue(arm, command),
+        Command::Wifi(WifiCommand::Show) => wifi(arm),
+        Command::Wifi(WifiCommand::Set(settings)) => {
+            set_wifi(arm, settings)?;
+            wifi(arm)
+        }
+        Command::Params(command) => params(arm, command),
+    }
+}
+
+/// Decodes a string the arm padded with zeros.
+fn device_str(buffer: &[u8]) -> String {
+    String::from_utf8_lossy(buffer)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn info<T: CommandSender>(arm: &DobotHandle<T>) -> Result<Value, Box<dyn Error>> {
+    let mut device = arm.controller().device_controller;
+    let mut buffer = [0u8; 64];
+    let len = device.get_device_name(&mut buffer)?;
+    let name = device_str(&buffer[..len]);
+    let len = device.get_device_sn(&mut buffer)?;
+    let serial_number = device_str(&buffer[..len]);
+    let (major, minor, revision) = device.get_device_version()?;
+    let (id0, id1, id2) = device.get_device_id()?;
+
+    Ok(json!({
+        "name": name,
+        "serial_number": serial_number,
+        "firmware": format!("{}.{}.{}", major, minor, revision),
+        "device_id": [id0, id1, id2],
+        "rail": device.get_device_rail_capability()?,
+        "uptime_ms": device.get_device_time()?,
+    }))
+}
+
+fn pose<T: CommandSender>(arm: &DobotHandle<T>) -> Result<Value, Box<dyn Error>> {
+    let with_rail = arm
+        .controller()
+        .device_controller
+        .get_device_rail_capability()?;
+    let mut realtime = arm.controller().realtime_controller;
+    let pose = realtime.get_pose()?;
+    let rail = if with_rail {
+        Some(realtime.get_pose_rail()?)
+    } else {
+        None
+    };
+
+    Ok(json!({
+        "x": pose.x,
+        "y": pose.y,
+        "z": pose.z,
+        "r": pose.r,
+        "joint_angle": pose.joint_angle,
+        "rail": rail,
+    }))
+}
+
+/// Waits for the queued command `index` to run, unless told not to.
+fn finish_queued<T: CommandSender>(
+    arm: &DobotHandle<T>,
+    index: Option<u64>,
+    wait: &WaitArgs,
+) -> Result<Value, Box<dyn Error>> {
+    let Some(index) = index else {
+        return Ok(Value::Null);
+    };
+    if wait.no_wait {
+        return Ok(json!({ "queue_index": index }));
+    }
+
+    match arm.wait_for_queue_index(index, wait.timeout) {
+        Err(DobotError::Timeout) => {
+            return Err(format!("queued command {} did not run in time", index).into());
+        }
+        waited => waited?,
+    }
+    Ok(json!({ "queue_index": index, "pose": pose(arm)? }))
+}
+
+fn move_arm<T: CommandSender>(
+    arm: &DobotHandle<T>,
+    command: MoveCommand,
+) -> Result<Value, Box<dyn Error>> {
+    let mut controller = arm.controller();
+    match command {
+        MoveCommand::Ptp {
+            mode,
+            x,
+            y,
+            z,
+            r,
+            rail,
+            wait,
+        } => {
+            let mut ptp = controller.ptp_controller;
+            let index = match rail {
+                Some(l) => ptp.set_ptp_with_rail_cmd(
+                    TagPTPWithLCmd {
+                        ptp_mode: mode.into(),
+                        x,
+                        y,
+                        z,
+                        r,
+                        l,
+                    },
+                    true,
+                )?,
+                None => ptp.set_ptp_cmd(
+                    TagPTPCmd {
+                        ptp_mode: mode.into(),
+                        x,
+                        y,
+                        z,
+                        r,
+                    },
+                    true,
+                )?,
+            };
+            finish_queued(arm, index, &wait)
+        }
+        MoveCommand::Cp {
+            relative,
+            x,
+            y,
+            z,
+            velocity,
+            wait,
+        } => {
+            let cp_mode = if relative {
+                CPMode::Relative
+            } else {
+                CPMode::Absolute
+            };
+            let index = controller.cp_controller.set_cp_cmd(
+                TagCPCmd {
+                    cp_mode,
+                    x,
+                    y,
+                    z,
+                    velocity_or_power: velocity,
+                },
+                true,
+            )?;
+            finish_queued(arm, index, &wait)
+        }
+        MoveCommand::Arc {
+            cx,
+            cy,
+            cz,
+            cr,
+            x,
+            y,
+            z,
+            r,
+            wait,
+        } => {
+            let index = controller.arc_controller.set_arc_cmd(
+                TagARCCmd {
+                    circ_point: Point {
+                        x: cx,
+                        y: cy,
+                        z: cz,
+                        r: cr,
+                    },
+                    to_point: Point { x, y, z, r },
+                },
+                true,
+            )?;
+            finish_queued(arm, index, &wait)
+        }
+    }
+}
+
+fn jog<T: CommandSender + 'static>(
+    arm: &DobotHandle<T>,
+    args: JogArgs,
+) -> Result<Value, Box<dyn Error>> {
+    let (mode, cmd) = args.axis;
+    let mut session = JogSession::start(arm.clone(), mode, JogSessionOptions::default());
+    match (args.ms, args.distance) {
+        (Some(ms), _) => session.nudge_for(cmd, Duration::from_millis(ms))?,
+        (None, Some(distance)) => {
+            let travelled = session.nudge_by(cmd, distance)?;
+            drop(session);
+            return Ok(json!({ "travelled": travelled, "pose": pose(arm)? }));
+        }
+        (None, None) => return Err("give --ms or --distance".into()),
+    }
+    drop(session);
+    Ok(json!({ "pose": pose(arm)? }))
+}
+
+fn level_text(level: Level) -> &'static str {
+    match level {
+        Level::High => "high",
+        Level::Low => "low",
+    }
+}
+
+fn io<T: CommandSender>(arm: &DobotHandle<T>, command: IoCommand) -> Result<Value, Box<dyn Error>> {
+    let mut io = arm.controller().io_controller;
+    match command {
+        IoCommand::Get { address } => {
+            let function = io.get_io_multiplexing(address)?.multiplex;
+            let value = match function {
+                IOFunction::Dummy => Value::Null,
+                IOFunction::Do => json!(level_text(io.get_io_do(address)?)),
+                IOFunction::Di | IOFunction::Dipu | IOFunction::Dipd => {
+                    json!(level_text(io.get_io_di(address)?))
+                }
+                IOFunction::Adc => json!(io.get_io_adc(address)?),
+                IOFunction::Pwm => {
+                    let pwm = io.get_io_pwm(address)?;
+                    json!({ "frequency": pwm.frequency, "duty_cycle": pwm.duty_cycle })
+                }
+            };
+            Ok(json!({
+                "address": address,
+                "function": serde_json::to_value(function)?,
+                "value": value,
+            }))
+        }
+        IoCommand::Set { address, level } => {
+            let level = match level {
+                LevelArg::High => Level::High,
+                LevelArg::Low => Level::Low,
+            };
+            io.set_io_multiplexing(
+                TagIOMultiplexing {
+                    address,
+                    multiplex: IOFunction::Do,
+                },
+                false,
+            )?;
+            io.set_io_do(TagIODO { address, level }, false)?;
+            Ok(json!({ "address": address, "value": level_text(level) }))
+        }
+        IoCommand::Pwm {
+            address,
+            frequency,
+            duty_cycle,
+        } => {
+            io.set_io_multiplexing(
+                TagIOMultiplexing {
+                    address,
+                    multiplex: IOFunction::Pwm,
+                },
+                false,
+            )?;
+            io.set_io_pwm(
+                TagIOPWM {
+                    address,
+                    frequency,
+                    duty_cycle,
+                },
+                false,
+            )?;
+            Ok(json!({
+                "address": address,
+                "frequency": frequency,
+                "duty_cycle": duty_cycle,
+            }))
+        }
+        IoCommand::Adc { address } => Ok(json!({
+            "address": address,
+            "value": io.get_io_adc(address)?,
+        })),
+    }
+}
+
+fn effector<T: CommandSender>(
+    arm: &DobotHandle<T>,
+    args: EffectorArgs,
+) -> Result<Value, Box<dyn Error>> {
+    let mut effector = arm.controller().end_effector_controller;
+    if let Some(state) = args.state {
+        let enable = state != ToolState::Disable;
+        let on = state == ToolState::On;
+        match args.tool {
+            Tool::Gripper => effector.set_gripper_state(enable, on, false)?,
+            Tool::Suction => effector.set_suction_cup_state(enable, on, false)?,
+            Tool::Laser => effector.set_laser_state(enable, on, false)?,
+        };
+    }
+    let (enabled, on) = match args.tool {
+        Tool::Gripper => effector.get_gripper_state()?,
+        Tool::Suction => effector.get_suction_cup_state()?,
+        Tool::Laser => effector.get_laser_state()?,
+    };
+    Ok(json!({ "enabled": enabled, "on": on }))
+}
+
+fn alarms<T: CommandSender>(arm: &DobotHandle<T>) -> Result<Value, Box<dyn Error>> {
+    let active = arm.controller().alarm_controller.get_active_alarms()?;
+    let alarms = active
+        .iter()
+        .map(|alarm| {
+            Ok(json!({
+                "code": format!("{:#04x}", alarm.code()),
+                "name": serde_json::to_value(alarm.alarm())?,
+                "category": serde_json::to_value(alarm.category())?,
+                "severity": serde_json::to_value(alarm.severity())?,
+                "description": alarm.description(),
+                "recovery": alarm.recovery(),
+            }))
+        })
+        .collect::<Result<Vec<Value>, serde_json::Error>>()?;
+    Ok(json!({ "alarms": alarms }))
+}
+
+fn queue<T: CommandSender>(
+    arm: &DobotHandle<T>,
+    command: QueueCommand,
+) -> Result<Value, Box<dyn Error>> {
+    let mut queue = arm.controller().queue_controller;
+    match command {
+        QueueCommand::Start => queue.set_queued_cmd_start_exec()?,
+        QueueCommand::Stop { force: false } => queue.set_queued_cmd_stop_exec()?,
+        QueueCommand::Stop { force: true } => queue.set_queued_cmd_force_stop_exec()?,
+        QueueCommand::Clear => queue.set_queued_cmd_clear()?,
+        QueueCommand::Index => {}
+    }
+    Ok(json!({ "index": queue.get_queued_cmd_current_index()? }))
+}
+
+fn wifi<T: CommandSender>(arm: &DobotHandle<T>) -> Result<Value, Box<dyn Error>> {
+    let mut wifi = arm.controller().wifi_controller;
+    let mut buffer = [0u8; 64];
+    let ssid = wifi.get_wifi_ssid(&mut buffer)?.to_string();
+    let ip = wifi.get_wifi_ip_address()?;
+
+    Ok(json!({
+        "config_mode": wifi.get_wifi_config_mode()?,
+        "connected": wifi.get_wifi_connect_status()?,
+        "ssid": ssid,
+        "dhcp": ip.dhcp,
+        "ip": Ipv4Addr::from(ip.addr).to_string(),
+        "netmask": Ipv4Addr::from(wifi.get_wifi_netmask()?.addr).to_string(),
+        "gateway": Ipv4Addr::from(wifi.get_wifi_gateway()?.addr).to_string(),
+        "dns": Ipv4Addr::from(wifi.get_wifi_dns()?.addr).to_string(),
+    }))
+}
+
+fn set_wifi<T: CommandSender>(
+    arm: &DobotHandle<T>,
+    settings: WifiSettings,
+) -> Result<(), Box<dyn Error>> {
+    let mut wifi = arm.controller().wifi_controller;
+    if let Some(enable) = settings.config_mode {
+        wifi.set_wifi_config_mode(enable)?;
+    }
+    if let Some(ssid) = &settings.ssid {
+        wifi.set_wifi_ssid(ssid.as_bytes())?;
+    }
+    if let Some(password) = &settings.password {
+        wifi.set_wifi_password(password.as_bytes())?;
+    }
+    if settings.dhcp {
+        let addr = wifi.get_wifi_ip_address()?.addr;
+        wifi.set_wifi_ip_address(TagWIFIIPAddress { dhcp: true, addr })?;
+    }
+    if let Some(ip) = settings.ip {
+        wifi.set_wifi_ip_address(TagWIFIIPAddress {
+            dhcp: false,
+            addr: ip.octets(),
+        })?;
+    }
+    if let Some(netmask) = settings.netmask {
+        wifi.set_wifi_netmask(TagWIFINetmask {
+            addr: netmask.octets(),
+        })?;
+    }
+    if let Some(gateway) = settings.gateway {
+        wifi.set_wifi_gateway(TagWIFIGateway {
+            addr: gateway.octets(),
+        })?;
+    }
+    if let Some(dns) = settings.dns {
+        wifi.set_wifi_dns(TagWIFIDNS { addr: dns.octets() })?;
+    }
+    Ok(())
+}
+
+fn is_json(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "json")
+}
+
+fn params<T: CommandSender>(
+    arm: &DobotHandle<T>,
+    command: ParamsCommand,
+) -> Result<Value, Box<dyn Error>> {
+    match command {
+        ParamsCommand::Dump { output: None } => {
+            Ok(serde_json::to_value(RobotProfile::read(arm.dobot())?)?)
+        }
+        ParamsCommand::Dump { output: Some(path) } => {
+            let profile = RobotProfile::read(arm.dobot())?;
+            let text = if is_json(&path) {
+                profile.to_json()?
+            } else {
+                profile.to_toml()?
+            };
+            fs::write(&path, text)?;
+            Ok(json!({ "written": path.display().to_string() }))
+        }
+        ParamsCommand::Load { file, dry_run } => {
+            let text = fs::read_to_string(&file)?;
+            let profile = if is_json(&file) {
+                RobotProfile::from_json(&text)?
+            } else {
+                RobotProfile::from_toml(&text)?
+            };
+            let changes: Vec<Value> = RobotProfile::read(arm.dobot())?
+                .diff(&profile)
+                .into_iter()
+                .map(
+                    |change| json!({ "field": change.field, "old": change.old, "new": change.new }),
+                )
+                .collect();
+            if !dry_run {
+                profile.apply(arm.dobot())?;
+            }
+            Ok(json!({ "applied": !dry_run, "changes": changes }))
+        }
+    }
+}
@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use clap::Parser;
+    use dobot_api_rs::dobot::{
+        dobot_core_serial::handle::DobotHandle,
+        dobot_trait::{
+            dobot_core::command_sender::simulated_command_sender::SimulatedCommandSender,
+            protocol::{
+                alarm::Alarm,
+                bodies::tag_jog_cmd::{JogCmd, JogMode},
+            },
+        },
+    };
+    use serde_json::{Value, json};
+
+    use crate::commands::{Command, parse_jog_axis, parse_seconds, run};
+
+    #[derive(Debug, Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        command: Command,
+    }
+
+    fn dobot(sim: &SimulatedCommandSender, args: &str) -> Value {
+        try_dobot(sim, args).unwrap()
+    }
+
+    fn try_dobot(sim: &SimulatedCommandSender, args: &str) -> Result<Value, String> {
+        let cli = TestCli::try_parse_from(std::iter::once("dobot").chain(args.split_whitespace()))
+            .map_err(|e| e.to_string())?;
+        run(&DobotHandle::new(sim.clone()), cli.command).map_err(|e| e.to_string())
+    }
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("dobot-cli-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_pose_reports_the_rail_only_when_fitted() {
+        let sim = SimulatedCommandSender::new();
+        assert_eq!(dobot(&sim, "pose")["rail"], Value::Null);
+
+        sim.with_state(|state| {
+            state.with_rail = true;
+            state.rail_position = 120.0;
+        });
+        let pose = dobot(&sim, "pose");
+        assert_eq!(pose["x"], json!(200.0));
+        assert_eq!(pose["rail"], json!(120.0));
+    }
+
+    #[test]
+    fn test_move_ptp_waits_for_the_queue() {
+        let sim = SimulatedCommandSender::new();
+
+        let moved = dobot(&sim, "move ptp --mode movl-xyz 210 -20 10 -5");
+
+        assert_eq!(moved["queue_index"], json!(1));
+        assert_eq!(moved["pose"]["y"], json!(-20.0));
+        assert_eq!(moved["pose"]["r"], json!(-5.0));
+    }
+
+    #[test]
+    fn test_move_times_out_on_a_stopped_queue() {
+        let sim = SimulatedCommandSender::new();
+        dobot(&sim, "queue stop");
+
+        assert_eq!(
+            dobot(&sim, "move arc 200 10 0 0 190 20 0 0 --no-wait"),
+            json!({ "queue_index": 1 })
+        );
+        let error = try_dobot(&sim, "home --timeout 0.1").unwrap_err();
+        assert!(error.contains("did not run in time"), "{}", error);
+
+        dobot(&sim, "queue start");
+        assert_eq!(dobot(&sim, "queue index"), json!({ "index": 2 }));
+    }
+
+    #[test]
+    fn test_timeouts_must_be_finite_and_not_negative() {
+        assert_eq!(parse_seconds("2.5"), Ok(Duration::from_millis(2500)));
+        assert!(parse_seconds("-1").is_err());
+        assert!(parse_seconds("inf").is_err());
+        assert!(parse_seconds("1e30").is_err());
+        assert!(parse_seconds("soon").is_err());
+
+        let sim = SimulatedCommandSender::new();
+        let error = try_dobot(&sim, "home --timeout nan").unwrap_err();
+        assert!(error.contains("--timeout"), "{}", error);
+        assert!(sim.commands().is_empty());
+    }
+
+    #[test]
+    fn test_io_set_configures_a_digital_output() {
+        let sim = SimulatedCommandSender::new();
+
+        dobot(&sim, "io set 4 high");
+
+        assert_eq!(
+            dobot(&sim, "io get 4"),
+            json!({ "address": 4, "function": "do", "value": "high" })
+        );
+        dobot(&sim, "io pwm 5 1000 25");
+        let pwm = dobot(&sim, "io get 5");
+        assert_eq!(pwm["function"], json!("pwm"));
+        assert_eq!(pwm["value"]["duty_cycle"], json!(25.0));
+    }
+
+    #[test]
+    fn test_effector_switches_and_reports_the_tool() {
+        let sim = SimulatedCommandSender::new();
+
+        assert_eq!(
+            dobot(&sim, "effector suction on"),
+            json!({ "enabled": true, "on": true })
+        );
+        assert_eq!(
+            dobot(&sim, "effector suction disable"),
+            json!({ "enabled": false, "on": false })
+        );
+        assert_eq!(
+            dobot(&sim, "effector laser"),
+            json!({ "enabled": false, "on": false })
+        );
+    }
+
+    #[test]
+    fn test_alarms_are_listed_and_cleared() {
+        let sim = SimulatedCommandSender::new();
+        sim.raise_alarm(Alarm::LimitAxis1Pos as u8);
+
+        let listed = dobot(&sim, "alarms list");
+        assert_eq!(listed["alarms"][0]["name"], json!("limit_axis1_pos"));
+        assert_eq!(listed["alarms"][0]["category"], json!("limit"));
+        assert_eq!(listed["alarms"][0]["severity"], json!("error"));
+
+        assert_eq!(dobot(&sim, "alarms clear"), json!({ "alarms": [] }));
+    }
+
+    #[test]
+    fn test_wifi_settings_are_written_and_shown() {
+        let sim = SimulatedCommandSender::new();
+
+        let wifi = dobot(
+            &sim,
+            "wifi set --ssid workshop --ip 192.168.1.40 --netmask 255.255.255.0",
+        );
+
+        assert_eq!(wifi["ssid"], json!("workshop"));
+        assert_eq!(wifi["dhcp"], json!(false));
+        assert_eq!(wifi["ip"], json!("192.168.1.40"));
+        assert_eq!(wifi["netmask"], json!("255.255.255.0"));
+    }
+
+    #[test]
+    fn test_params_round_trip_through_a_file() {
+        let path = temp_file("params.toml");
+        let original = SimulatedCommandSender::new();
+        let dumped = dobot(&original, &format!("params dump -o {}", path.display()));
+        assert_eq!(dumped["written"], json!(path.display().to_string()));
+
+        let other = SimulatedCommandSender::new();
+        dobot(&other, "io set 4 high");
+        let preview = dobot(&other, &format!("params load --dry-run {}", path.display()));
+        let applied = dobot(&other, &format!("params load {}", path.display()));
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(preview["applied"], json!(false));
+        assert_eq!(preview["changes"][0]["field"], json!("io_multiplexing[4]"));
+        assert_eq!(applied["applied"], json!(true));
+        assert_eq!(dobot(&other, "io get 4")["function"], json!("dummy"));
+    }
+
+    #[test]
+    fn test_jog_axes_are_parsed() {
+        assert_eq!(
+            parse_jog_axis("X+"),
+            Ok((JogMode::Coordinate, JogCmd::ApDown))
+        );
+        assert_eq!(parse_jog_axis("j3-"), Ok((JogMode::Joint, JogCmd::CnDown)));
+        assert_eq!(
+            parse_jog_axis("rail-"),
+            Ok((JogMode::Coordinate, JogCmd::LnDown))
+        );
+        assert!(parse_jog_axis("z").is_err());
+        assert!(parse_jog_axis("j5+").is_err());
+    }
+
+    #[test]
+    fn test_jog_needs_a_time_or_a_distance() {
+        let sim = SimulatedCommandSender::new();
+        assert!(try_dobot(&sim, "jog z-").is_err());
+
+        let jogged = dobot(&sim, "jog z- --distance 2");
+
+        assert!(jogged["travelled"].as_f64().unwrap() >= 2.0);
+        assert!(jogged["pose"]["z"].as_f64().unwrap() <= -2.0);
+    }
+}
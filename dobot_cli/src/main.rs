@@ -0,0 +1,98 @@
+//! Scriptable command-line access to a Dobot Magician.
+
+mod commands;
+mod output;
+
+use std::{error::Error, net::TcpStream, process::ExitCode, time::Duration};
+
+use clap::Parser;
+use dobot_api_rs::dobot::{
+    dobot_core_serial::{
+        command_sender_serial::DobotCommandSender,
+        discovery::{DobotLocation, discover_serial},
+        handle::DobotHandle,
+    },
+    dobot_trait::dobot_core::command_sender::{
+        CommandSender, simulated_command_sender::SimulatedCommandSender,
+    },
+};
+use serde_json::Value;
+
+use commands::Command;
+
+/// How long a read of a TCP connection blocks before the deadline of the
+/// command is checked again.
+const TCP_READ_TIMEOUT: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Parser)]
+#[command(name = "dobot", version, about)]
+struct Cli {
+    /// Serial port of the arm. Defaults to the first Magician found.
+    #[arg(long, global = true, conflicts_with_all = ["tcp", "sim"])]
+    port: Option<String>,
+    /// Reach the arm through a serial-to-TCP bridge at HOST:PORT.
+    #[arg(long, global = true, conflicts_with = "sim")]
+    tcp: Option<String>,
+    /// Drive a simulated arm instead of a real one.
+    #[arg(long, global = true)]
+    sim: bool,
+    /// Print results as JSON.
+    #[arg(long, global = true)]
+    json: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = if cli.sim {
+        execute(SimulatedCommandSender::new(), cli.command)
+    } else if let Some(address) = &cli.tcp {
+        connect_tcp(address).and_then(|sender| execute(sender, cli.command))
+    } else {
+        open_serial(cli.port.as_deref()).and_then(|sender| execute(sender, cli.command))
+    };
+
+    match result {
+        Ok(value) => {
+            if cli.json {
+                print!("{}", output::to_json(&value));
+            } else {
+                print!("{}", output::to_text(&value));
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("dobot: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute<T: CommandSender + 'static>(
+    sender: T,
+    command: Command,
+) -> Result<Value, Box<dyn Error>> {
+    commands::run(&DobotHandle::new(sender), command)
+}
+
+fn open_serial(port: Option<&str>) -> Result<DobotCommandSender, Box<dyn Error>> {
+    let port = match port {
+        Some(port) => port.to_string(),
+        None => discover_serial()?
+            .into_iter()
+            .find_map(|found| match found.location {
+                DobotLocation::Serial { port_name, .. } => Some(port_name),
+                DobotLocation::Udp(_) => None,
+            })
+            .ok_or("no Dobot found; pass --port, --tcp or --sim")?,
+    };
+    Ok(DobotCommandSender::new(&port)?)
+}
+
+fn connect_tcp(address: &str) -> Result<DobotCommandSender, Box<dyn Error>> {
+    let stream = TcpStream::connect(address)?;
+    stream.set_nodelay(true)?;
+    stream.set_read_timeout(Some(TCP_READ_TIMEOUT))?;
+    Ok(DobotCommandSender::builder().from_stream(stream))
+}
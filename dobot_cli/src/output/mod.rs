@@ -0,0 +1,99 @@
+mod test;
+
+use std::fmt::Write;
+
+use serde_json::{Number, Value};
+
+/// Formats the result of a command as pretty-printed JSON.
+pub fn to_json(value: &Value) -> String {
+    let mut text = serde_json::to_string_pretty(&tidy(value.clone())).unwrap_or_default();
+    text.push('\n');
+    text
+}
+
+/// Formats the result of a command as `key: value` lines, with nested objects
+/// indented below their key. `null` prints nothing.
+pub fn to_text(value: &Value) -> String {
+    let value = tidy(value.clone());
+    let mut text = String::new();
+    match &value {
+        Value::Null => {}
+        Value::Object(_) | Value::Array(_) => write_nested(&mut text, &value, 0),
+        scalar => {
+            let _ = writeln!(text, "{}", scalar_text(scalar));
+        }
+    }
+    text
+}
+
+/// Prints numbers read from `f32` fields in their shortest `f32` form, so
+/// 0.3 stays 0.3 instead of 0.30000001192092896.
+fn tidy(value: Value) -> Value {
+    match value {
+        Value::Number(number) => match number.as_f64() {
+            Some(float) if number.is_f64() && float as f32 as f64 == float => {
+                let shortest = (float as f32).to_string().parse().unwrap_or(float);
+                Value::Number(Number::from_f64(shortest).unwrap_or(number))
+            }
+            _ => Value::Number(number),
+        },
+        Value::Array(items) => Value::Array(items.into_iter().map(tidy).collect()),
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, field)| (key, tidy(field)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// Whether `value` fits on the line of its key.
+fn is_flat(value: &Value) -> bool {
+    match value {
+        Value::Object(_) => false,
+        Value::Array(items) => items
+            .iter()
+            .all(|item| !item.is_object() && !item.is_array()),
+        _ => true,
+    }
+}
+
+fn scalar_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::from("-"),
+        Value::String(text) => text.clone(),
+        Value::Array(items) if items.is_empty() => String::from("none"),
+        Value::Array(items) => items.iter().map(scalar_text).collect::<Vec<_>>().join(", "),
+        other => other.to_string(),
+    }
+}
+
+fn write_nested(text: &mut String, value: &Value, depth: usize) {
+    let indent = "  ".repeat(depth);
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                if is_flat(field) {
+                    let _ = writeln!(text, "{}{}: {}", indent, key, scalar_text(field));
+                } else {
+                    let _ = writeln!(text, "{}{}:", indent, key);
+                    write_nested(text, field, depth + 1);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                if is_flat(item) {
+                    let _ = writeln!(text, "{}- {}", indent, scalar_text(item));
+                } else {
+                    let _ = writeln!(text, "{}-", indent);
+                    write_nested(text, item, depth + 1);
+                }
+            }
+        }
+        scalar => {
+            let _ = writeln!(text, "{}{}", indent, scalar_text(scalar));
+        }
+    }
+}
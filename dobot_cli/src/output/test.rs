@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::output::{to_json, to_text};
+
+    #[test]
+    fn test_text_nests_objects_and_flattens_lists() {
+        let value = json!({
+            "pose": { "x": 200.0, "joint_angle": [0.0, 45.5] },
+            "alarms": [],
+            "changes": [{ "field": "ptp_common", "old": "a", "new": "b" }],
+        });
+
+        assert_eq!(
+            to_text(&value),
+            "pose:\n  x: 200.0\n  joint_angle: 0.0, 45.5\nalarms: none\nchanges:\n  -\n    field: ptp_common\n    old: a\n    new: b\n"
+        );
+    }
+
+    #[test]
+    fn test_f32_fields_print_in_their_shortest_form() {
+        let value = json!({ "velocity": 0.3f32 });
+
+        assert_eq!(to_text(&value), "velocity: 0.3\n");
+        assert_eq!(to_json(&value), "{\n  \"velocity\": 0.3\n}\n");
+    }
+
+    #[test]
+    fn test_null_prints_nothing() {
+        assert_eq!(to_text(&serde_json::Value::Null), "");
+        assert_eq!(to_text(&json!("done")), "done\n");
+    }
+}
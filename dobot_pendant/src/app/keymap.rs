@@ -0,0 +1,77 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use dobot_api_rs::dobot::dobot_trait::protocol::bodies::tag_jog_cmd::JogCmd;
+
+/// Something the operator asked the pendant to do.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    /// Jog one axis while the key is held.
+    Jog(JogCmd),
+    StopJog,
+    /// Switch between Cartesian and joint jogging.
+    ToggleMode,
+    SpeedUp,
+    SpeedDown,
+    ToggleGripper,
+    ToggleSuction,
+    ToggleLaser,
+    /// Start typing a name for a waypoint at the current pose.
+    NameWaypoint,
+    SelectPrevious,
+    SelectNext,
+    GoToWaypoint,
+    DeleteWaypoint,
+    Home,
+    ClearAlarms,
+    Quit,
+}
+
+/// Keys and what they do, in the order they are listed in the help line.
+pub const HELP: &[(&str, &str)] = &[
+    ("a/d w/s f/r q/e", "jog X/J1 Y/J2 Z/J3 R/J4"),
+    ("[ ]", "rail"),
+    ("space", "stop"),
+    ("m", "mode"),
+    ("-/+", "speed"),
+    ("g c l", "gripper suction laser"),
+    ("n", "save waypoint"),
+    ("↑/↓ enter del", "select go delete"),
+    ("h", "home"),
+    ("x", "clear alarms"),
+    ("esc", "quit"),
+];
+
+/// Maps a key to its action outside of text entry.
+pub fn action_for(key: &KeyEvent) -> Option<Action> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return (key.code == KeyCode::Char('c')).then_some(Action::Quit);
+    }
+    let action = match key.code {
+        KeyCode::Char('d') => Action::Jog(JogCmd::ApDown),
+        KeyCode::Char('a') => Action::Jog(JogCmd::AnDown),
+        KeyCode::Char('w') => Action::Jog(JogCmd::BpDown),
+        KeyCode::Char('s') => Action::Jog(JogCmd::BnDown),
+        KeyCode::Char('r') => Action::Jog(JogCmd::CpDown),
+        KeyCode::Char('f') => Action::Jog(JogCmd::CnDown),
+        KeyCode::Char('e') => Action::Jog(JogCmd::DpDown),
+        KeyCode::Char('q') => Action::Jog(JogCmd::DnDown),
+        KeyCode::Char(']') => Action::Jog(JogCmd::LpDown),
+        KeyCode::Char('[') => Action::Jog(JogCmd::LnDown),
+        KeyCode::Char(' ') => Action::StopJog,
+        KeyCode::Char('m') => Action::ToggleMode,
+        KeyCode::Char('+' | '=') => Action::SpeedUp,
+        KeyCode::Char('-') => Action::SpeedDown,
+        KeyCode::Char('g') => Action::ToggleGripper,
+        KeyCode::Char('c') => Action::ToggleSuction,
+        KeyCode::Char('l') => Action::ToggleLaser,
+        KeyCode::Char('n') => Action::NameWaypoint,
+        KeyCode::Up => Action::SelectPrevious,
+        KeyCode::Down => Action::SelectNext,
+        KeyCode::Enter => Action::GoToWaypoint,
+        KeyCode::Delete => Action::DeleteWaypoint,
+        KeyCode::Char('h') => Action::Home,
+        KeyCode::Char('x') => Action::ClearAlarms,
+        KeyCode::Esc => Action::Quit,
+        _ => return None,
+    };
+    Some(action)
+}
@@ -0,0 +1,497 @@
+mod keymap;
+mod test;
+
+use std::{
+    error::Error,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use dobot_api_rs::dobot::{
+    dobot_core_serial::{
+        handle::DobotHandle,
+        jog_session::{JogSession, JogSessionOptions},
+        profile::EIO_ADDRESSES,
+    },
+    dobot_trait::{
+        dobot_core::{
+            command_sender::CommandSender,
+            dobot_error::DobotError,
+            sub_command_dobot::{
+                alarm_control::AlarmControl, device_control::DeviceControl,
+                end_effector_control::EndEffectorControl, home_control::HomeControl,
+                io_control::IOControl, jog_control::JOGControl, ptp_control::PTPControl,
+                real_time_control::RealTimeControl,
+            },
+        },
+        protocol::{
+            alarm::AlarmSet,
+            bodies::{
+                level::Level,
+                tag_home_cmd::TagHomeCmd,
+                tag_io_multiplexing::IOFunction,
+                tag_jog_cmd::{JogCmd, JogMode},
+                tag_jog_common_params::TagJOGCommonParams,
+                tag_pose::TagPose,
+                tag_ptp_cmd::{PTPMode, TagPTPCmd},
+                tag_ptp_with_l_cmd::TagPTPWithLCmd,
+            },
+        },
+    },
+};
+
+use crate::waypoints::{Waypoint, Waypoints};
+
+pub use keymap::{Action, HELP, action_for};
+
+/// Step by which the JOG velocity ratio is changed, in percent.
+const SPEED_STEP: f32 = 10.0;
+
+/// Settings of the pendant.
+#[derive(Debug, Clone)]
+pub struct PendantOptions {
+    /// File the waypoints are read from and saved to.
+    pub waypoints_path: Option<PathBuf>,
+    pub jog: JogSessionOptions,
+    /// How often the pose, rail position and alarms are read.
+    pub pose_interval: Duration,
+    /// How often the EIO states are read.
+    pub io_interval: Duration,
+}
+
+impl Default for PendantOptions {
+    fn default() -> Self {
+        Self {
+            waypoints_path: None,
+            // Long enough to bridge the delay before a held key repeats.
+            jog: JogSessionOptions {
+                keepalive_timeout: Duration::from_millis(600),
+                ..JogSessionOptions::default()
+            },
+            pose_interval: Duration::from_millis(100),
+            io_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Whether each end effector is switched on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Effectors {
+    pub gripper: bool,
+    pub suction: bool,
+    pub laser: bool,
+}
+
+/// The last reading of one EIO address.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IoValue {
+    Level(Level),
+    Pwm { frequency: f32, duty_cycle: f32 },
+    Adc(u16),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IoState {
+    pub address: u8,
+    pub function: IOFunction,
+    pub value: Option<IoValue>,
+}
+
+/// What typed keys go to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Normal,
+    /// Typing the name of a new waypoint.
+    WaypointName(String),
+}
+
+/// The state of the pendant and everything it shows.
+///
+/// Keys are turned into commands through [`App::handle_key`]; the arm is
+/// read through [`App::tick`]. Jogging goes through a [`JogSession`], so a
+/// held key keeps the arm moving only while the terminal keeps repeating it
+/// and the arm stops if the pendant goes away.
+pub struct App<T: CommandSender + 'static> {
+    arm: DobotHandle<T>,
+    jog: JogSession<T>,
+    options: PendantOptions,
+    active_jog: Option<JogCmd>,
+    last_pose_refresh: Option<Instant>,
+    last_io_refresh: Option<Instant>,
+    quit: bool,
+    pub with_rail: bool,
+    pub speed: TagJOGCommonParams,
+    pub effectors: Effectors,
+    pub waypoints: Waypoints,
+    /// Index of the selected waypoint in name order.
+    pub selected: usize,
+    pub pose: Option<TagPose>,
+    pub rail: Option<f32>,
+    pub io: Vec<IoState>,
+    pub alarms: AlarmSet,
+    pub status: String,
+    pub input: Input,
+}
+
+impl<T: CommandSender + 'static> App<T> {
+    /// Reads the arm's settings and end effector states and loads the
+    /// waypoints.
+    pub fn new(arm: DobotHandle<T>, options: PendantOptions) -> Result<Self, Box<dyn Error>> {
+        let waypoints = match &options.waypoints_path {
+            Some(path) => Waypoints::load(path)?,
+            None => Waypoints::default(),
+        };
+
+        let mut controller = arm.controller();
+        let with_rail = controller.device_controller.get_device_rail_capability()?;
+        let speed = controller.jog_controller.get_jog_common_params()?;
+        let effectors = Effectors {
+            gripper: controller.end_effector_controller.get_gripper_state()?.1,
+            suction: controller
+                .end_effector_controller
+                .get_suction_cup_state()?
+                .1,
+            laser: controller.end_effector_controller.get_laser_state()?.1,
+        };
+        let mut io = Vec::new();
+        for address in EIO_ADDRESSES {
+            let function = controller
+                .io_controller
+                .get_io_multiplexing(address)?
+                .multiplex;
+            if function != IOFunction::Dummy {
+                io.push(IoState {
+                    address,
+                    function,
+                    value: None,
+                });
+            }
+        }
+
+        let jog = JogSession::start(arm.clone(), JogMode::Coordinate, options.jog.clone());
+        Ok(Self {
+            arm,
+            jog,
+            options,
+            active_jog: None,
+            last_pose_refresh: None,
+            last_io_refresh: None,
+            quit: false,
+            with_rail,
+            speed,
+            effectors,
+            waypoints,
+            selected: 0,
+            pose: None,
+            rail: None,
+            io,
+            alarms: AlarmSet::new(),
+            status: String::from("Ready"),
+            input: Input::Normal,
+        })
+    }
+
+    pub fn mode(&self) -> JogMode {
+        self.jog.mode()
+    }
+
+    /// The axis being jogged, if any.
+    pub fn active_jog(&self) -> Option<JogCmd> {
+        self.active_jog
+    }
+
+    pub fn should_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Handles one key event from the terminal.
+    ///
+    /// Terminals that report key releases stop a jog as soon as its key is
+    /// let go; on others the jog stops once the key stops repeating.
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if let Input::WaypointName(name) = &mut self.input {
+            if key.kind == KeyEventKind::Release {
+                return;
+            }
+            match key.code {
+                KeyCode::Char(c) if !c.is_whitespace() => name.push(c),
+                KeyCode::Backspace => {
+                    name.pop();
+                }
+                KeyCode::Enter => {
+                    let name = core::mem::take(name);
+                    self.input = Input::Normal;
+                    self.perform_with(Action::NameWaypoint, |app| app.save_waypoint(&name));
+                }
+                KeyCode::Esc => self.input = Input::Normal,
+                _ => {}
+            }
+            return;
+        }
+
+        let Some(action) = action_for(&key) else {
+            return;
+        };
+        if key.kind == KeyEventKind::Release {
+            if let Action::Jog(cmd) = action
+                && self.active_jog == Some(cmd)
+            {
+                self.perform(Action::StopJog);
+            }
+            return;
+        }
+        self.perform(action);
+    }
+
+    /// Carries out `action`, reporting a failure in the status line.
+    pub fn perform(&mut self, action: Action) {
+        self.perform_with(action, |app| app.try_perform(action));
+    }
+
+    fn perform_with(
+        &mut self,
+        action: Action,
+        f: impl FnOnce(&mut Self) -> Result<(), Box<dyn Error>>,
+    ) {
+        if let Err(e) = f(self) {
+            self.status = format!("{:?} failed: {}", action, e);
+        }
+    }
+
+    fn try_perform(&mut self, action: Action) -> Result<(), Box<dyn Error>> {
+        match action {
+            Action::Jog(JogCmd::LpDown | JogCmd::LnDown) if !self.with_rail => {
+                self.status = String::from("No rail fitted");
+            }
+            Action::Jog(cmd) => {
+                if self.active_jog == Some(cmd) && self.jog.is_moving() {
+                    self.jog.keepalive();
+                } else {
+                    self.active_jog = None;
+                    self.jog.jog(cmd)?;
+                    self.active_jog = Some(cmd);
+                    self.status = format!("Jogging {:?}", cmd);
+                }
+            }
+            Action::StopJog => {
+                self.stop_jog()?;
+                self.status = String::from("Stopped");
+            }
+            Action::ToggleMode => {
+                self.active_jog = None;
+                let mode = match self.jog.mode() {
+                    JogMode::Coordinate => JogMode::Joint,
+                    JogMode::Joint => JogMode::Coordinate,
+                };
+                self.jog.set_mode(mode)?;
+                self.status = format!("{:?} mode", mode);
+            }
+            Action::SpeedUp | Action::SpeedDown => {
+                let step = if action == Action::SpeedUp {
+                    SPEED_STEP
+                } else {
+                    -SPEED_STEP
+                };
+                let params = TagJOGCommonParams {
+                    velocity_ratio: (self.speed.velocity_ratio + step).clamp(SPEED_STEP, 100.0),
+                    acceleration_ratio: self.speed.acceleration_ratio,
+                };
+                self.arm
+                    .controller()
+                    .jog_controller
+                    .set_jog_common_params(params.clone(), false)?;
+                self.status = format!("Speed {:.0}%", params.velocity_ratio);
+                self.speed = params;
+            }
+            Action::ToggleGripper => {
+                let on = !self.effectors.gripper;
+                let mut controller = self.arm.controller().end_effector_controller;
+                controller.set_gripper_state(true, on, false)?;
+                self.effectors.gripper = on;
+            }
+            Action::ToggleSuction => {
+                let on = !self.effectors.suction;
+                let mut controller = self.arm.controller().end_effector_controller;
+                controller.set_suction_cup_state(true, on, false)?;
+                self.effectors.suction = on;
+            }
+            Action::ToggleLaser => {
+                let on = !self.effectors.laser;
+                let mut controller = self.arm.controller().end_effector_controller;
+                controller.set_laser_state(true, on, false)?;
+                self.effectors.laser = on;
+            }
+            Action::NameWaypoint => self.input = Input::WaypointName(String::new()),
+            Action::SelectPrevious => self.selected = self.selected.saturating_sub(1),
+            Action::SelectNext => {
+                self.selected = (self.selected + 1).min(self.waypoints.len().saturating_sub(1));
+            }
+            Action::GoToWaypoint => {
+                let Some((name, waypoint)) = self.waypoints.nth(self.selected) else {
+                    self.status = String::from("No waypoint selected");
+                    return Ok(());
+                };
+                let (name, waypoint) = (name.to_string(), waypoint.clone());
+                self.stop_jog()?;
+                self.move_to(&waypoint)?;
+                self.status = format!("Moving to {}", name);
+            }
+            Action::DeleteWaypoint => {
+                let Some((name, _)) = self.waypoints.nth(self.selected) else {
+                    return Ok(());
+                };
+                let name = name.to_string();
+                self.waypoints.remove(&name);
+                self.save_waypoints()?;
+                self.selected = self.selected.min(self.waypoints.len().saturating_sub(1));
+                self.status = format!("Deleted {}", name);
+            }
+            Action::Home => {
+                self.stop_jog()?;
+                self.arm
+                    .controller()
+                    .homing_controller
+                    .set_home_cmd(TagHomeCmd { reserved: 0 }, true)?;
+                self.status = String::from("Homing");
+            }
+            Action::ClearAlarms => {
+                self.arm
+                    .controller()
+                    .alarm_controller
+                    .clear_all_alarms_state()?;
+                self.refresh_alarms()?;
+                self.status = String::from("Alarms cleared");
+            }
+            Action::Quit => {
+                self.stop_jog()?;
+                self.quit = true;
+            }
+        }
+        Ok(())
+    }
+
+    /// Refreshes whatever readings are due. Call this regularly.
+    pub fn tick(&mut self) {
+        if self.active_jog.is_some() && self.jog.timed_out() {
+            self.active_jog = None;
+            self.status = String::from("Stopped: key released");
+        }
+
+        let now = Instant::now();
+        let due = |last: Option<Instant>, interval| last.is_none_or(|last| now - last >= interval);
+        if due(self.last_pose_refresh, self.options.pose_interval) {
+            self.last_pose_refresh = Some(now);
+            if let Err(e) = self.refresh_pose().and_then(|_| self.refresh_alarms()) {
+                self.status = format!("Reading the arm failed: {}", e);
+            }
+        }
+        if due(self.last_io_refresh, self.options.io_interval) {
+            self.last_io_refresh = Some(now);
+            if let Err(e) = self.refresh_io() {
+                self.status = format!("Reading EIO failed: {}", e);
+            }
+        }
+    }
+
+    fn stop_jog(&mut self) -> Result<(), DobotError> {
+        self.active_jog = None;
+        self.jog.stop()
+    }
+
+    fn refresh_pose(&mut self) -> Result<(), DobotError> {
+        let mut realtime = self.arm.controller().realtime_controller;
+        self.pose = Some(realtime.get_pose()?);
+        if self.with_rail {
+            self.rail = Some(realtime.get_pose_rail()?);
+        }
+        Ok(())
+    }
+
+    fn refresh_alarms(&mut self) -> Result<(), DobotError> {
+        self.alarms = self.arm.controller().alarm_controller.get_active_alarms()?;
+        Ok(())
+    }
+
+    fn refresh_io(&mut self) -> Result<(), DobotError> {
+        let mut controller = self.arm.controller().io_controller;
+        for state in &mut self.io {
+            state.value = Some(match state.function {
+                IOFunction::Do => IoValue::Level(controller.get_io_do(state.address)?),
+                IOFunction::Di | IOFunction::Dipu | IOFunction::Dipd => {
+                    IoValue::Level(controller.get_io_di(state.address)?)
+                }
+                IOFunction::Adc => IoValue::Adc(controller.get_io_adc(state.address)?),
+                IOFunction::Pwm => {
+                    let pwm = controller.get_io_pwm(state.address)?;
+                    IoValue::Pwm {
+                        frequency: pwm.frequency,
+                        duty_cycle: pwm.duty_cycle,
+                    }
+                }
+                IOFunction::Dummy => continue,
+            });
+        }
+        Ok(())
+    }
+
+    fn save_waypoint(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
+        if name.is_empty() {
+            self.status = String::from("Waypoint not saved: no name given");
+            return Ok(());
+        }
+        self.refresh_pose()?;
+        let Some(pose) = &self.pose else {
+            return Ok(());
+        };
+        self.waypoints
+            .insert(name, Waypoint::from_pose(pose, self.rail));
+        self.save_waypoints()?;
+        self.selected = self
+            .waypoints
+            .iter()
+            .position(|(saved, _)| saved == name)
+            .unwrap_or(0);
+        self.status = format!("Saved {}", name);
+        Ok(())
+    }
+
+    fn save_waypoints(&self) -> std::io::Result<()> {
+        match &self.options.waypoints_path {
+            Some(path) => self.waypoints.save(path),
+            None => Ok(()),
+        }
+    }
+
+    fn move_to(&mut self, waypoint: &Waypoint) -> Result<(), DobotError> {
+        let mut ptp = self.arm.controller().ptp_controller;
+        match waypoint.rail {
+            Some(l) if self.with_rail => {
+                ptp.set_ptp_with_rail_cmd(
+                    TagPTPWithLCmd {
+                        ptp_mode: PTPMode::MovjXyz,
+                        x: waypoint.x,
+                        y: waypoint.y,
+                        z: waypoint.z,
+                        r: waypoint.r,
+                        l,
+                    },
+                    true,
+                )?;
+            }
+            _ => {
+                ptp.set_ptp_cmd(
+                    TagPTPCmd {
+                        ptp_mode: PTPMode::MovjXyz,
+                        x: waypoint.x,
+                        y: waypoint.y,
+                        z: waypoint.z,
+                        r: waypoint.r,
+                    },
+                    true,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf, thread, time::Duration};
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+    use dobot_api_rs::dobot::{
+        dobot_core_serial::{handle::DobotHandle, jog_session::JogSessionOptions},
+        dobot_trait::{
+            dobot_core::{
+                command_sender::simulated_command_sender::SimulatedCommandSender,
+                sub_command_dobot::{io_control::IOControl, jog_control::JOGControl},
+            },
+            protocol::{
+                alarm::{Alarm, AlarmSet},
+                bodies::{
+                    level::Level,
+                    tag_io_do::TagIODO,
+                    tag_io_multiplexing::{IOFunction, TagIOMultiplexing},
+                    tag_jog_cmd::JogMode,
+                    tag_jog_common_params::TagJOGCommonParams,
+                },
+                command::{Command, SetEndEffectorGripper, SetJogCmd, SetJogCommonParams},
+            },
+        },
+    };
+
+    use crate::app::{App, Input, IoValue, PendantOptions};
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "dobot-pendant-app-{}-{}.toml",
+            name,
+            std::process::id()
+        ))
+    }
+
+    fn pendant(
+        sim: &SimulatedCommandSender,
+        waypoints_path: Option<PathBuf>,
+    ) -> App<SimulatedCommandSender> {
+        let arm = DobotHandle::new(sim.clone());
+        arm.controller()
+            .jog_controller
+            .set_jog_common_params(
+                TagJOGCommonParams {
+                    velocity_ratio: 50.0,
+                    acceleration_ratio: 50.0,
+                },
+                false,
+            )
+            .unwrap();
+        App::new(
+            arm,
+            PendantOptions {
+                waypoints_path,
+                jog: JogSessionOptions {
+                    keepalive_timeout: Duration::from_millis(80),
+                    check_interval: Duration::from_millis(5),
+                    ..JogSessionOptions::default()
+                },
+                pose_interval: Duration::ZERO,
+                io_interval: Duration::ZERO,
+            },
+        )
+        .unwrap()
+    }
+
+    fn key(code: KeyCode, kind: KeyEventKind) -> KeyEvent {
+        KeyEvent::new_with_kind(code, KeyModifiers::NONE, kind)
+    }
+
+    fn press(app: &mut App<SimulatedCommandSender>, c: char) {
+        app.handle_key(key(KeyCode::Char(c), KeyEventKind::Press));
+    }
+
+    fn sent(sim: &SimulatedCommandSender, id: u8) -> Vec<Vec<u8>> {
+        sim.commands()
+            .into_iter()
+            .filter(|c| c.id == id && c.is_write)
+            .map(|c| c.params)
+            .collect()
+    }
+
+    #[test]
+    fn test_held_key_jogs_until_released() {
+        let sim = SimulatedCommandSender::new();
+        let mut app = pendant(&sim, None);
+
+        press(&mut app, 'd');
+        for _ in 0..5 {
+            thread::sleep(Duration::from_millis(20));
+            app.handle_key(key(KeyCode::Char('d'), KeyEventKind::Repeat));
+        }
+        app.handle_key(key(KeyCode::Char('d'), KeyEventKind::Release));
+
+        assert_eq!(
+            sent(&sim, SetJogCmd::ID.into()),
+            vec![vec![0, 1], vec![0, 0]]
+        );
+        assert!(app.active_jog().is_none());
+        assert!(sim.pose().x > 200.0);
+    }
+
+    #[test]
+    fn test_jog_stops_when_key_stops_repeating() {
+        let sim = SimulatedCommandSender::new();
+        let mut app = pendant(&sim, None);
+
+        press(&mut app, 'w');
+        thread::sleep(Duration::from_millis(200));
+        app.tick();
+
+        assert!(app.active_jog().is_none());
+        assert_eq!(app.status, "Stopped: key released");
+        assert_eq!(
+            sent(&sim, SetJogCmd::ID.into()).last().unwrap(),
+            &vec![0, 0]
+        );
+    }
+
+    #[test]
+    fn test_mode_key_switches_to_joint_jogging() {
+        let sim = SimulatedCommandSender::new();
+        let mut app = pendant(&sim, None);
+
+        press(&mut app, 'm');
+        press(&mut app, 'q');
+
+        assert_eq!(app.mode(), JogMode::Joint);
+        assert_eq!(sent(&sim, SetJogCmd::ID.into()), vec![vec![1, 8]]);
+        app.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(app.should_quit());
+        assert_eq!(
+            sent(&sim, SetJogCmd::ID.into()).last().unwrap(),
+            &vec![1, 0]
+        );
+    }
+
+    #[test]
+    fn test_speed_keys_step_the_velocity_ratio() {
+        let sim = SimulatedCommandSender::new();
+        let mut app = pendant(&sim, None);
+
+        press(&mut app, '+');
+        press(&mut app, '+');
+        assert_eq!(app.speed.velocity_ratio, 70.0);
+        for _ in 0..10 {
+            press(&mut app, '-');
+        }
+
+        assert_eq!(app.speed.velocity_ratio, 10.0);
+        assert_eq!(app.speed.acceleration_ratio, 50.0);
+        assert_eq!(app.status, "Speed 10%");
+        let last = sent(&sim, SetJogCommonParams::ID.into()).pop().unwrap();
+        assert_eq!(&last[..4], &10.0f32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_effector_keys_toggle_the_gripper() {
+        let sim = SimulatedCommandSender::new();
+        let mut app = pendant(&sim, None);
+
+        press(&mut app, 'g');
+        assert!(app.effectors.gripper);
+        press(&mut app, 'g');
+        press(&mut app, 'l');
+
+        assert!(!app.effectors.gripper);
+        assert!(app.effectors.laser);
+        assert_eq!(
+            sent(&sim, SetEndEffectorGripper::ID.into()),
+            vec![vec![1, 1], vec![1, 0]]
+        );
+    }
+
+    #[test]
+    fn test_waypoint_is_taught_saved_and_revisited() {
+        let sim = SimulatedCommandSender::new();
+        let path = temp_file("teach");
+        let mut app = pendant(&sim, Some(path.clone()));
+        sim.with_state(|state| {
+            state.pose.x = 230.0;
+            state.pose.y = -15.0;
+            state.pose.z = 25.0;
+        });
+
+        press(&mut app, 'n');
+        for c in "pick".chars() {
+            press(&mut app, c);
+        }
+        assert_eq!(app.input, Input::WaypointName(String::from("pick")));
+        app.handle_key(key(KeyCode::Enter, KeyEventKind::Press));
+        assert_eq!(app.input, Input::Normal);
+        assert_eq!(app.status, "Saved pick");
+
+        press(&mut app, 'h');
+        assert_eq!(sim.pose().x, 200.0);
+        app.handle_key(key(KeyCode::Enter, KeyEventKind::Press));
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let pose = sim.pose();
+        assert_eq!((pose.x, pose.y, pose.z), (230.0, -15.0, 25.0));
+        assert_eq!(app.status, "Moving to pick");
+        assert!(text.contains("[waypoints.pick]"), "{}", text);
+    }
+
+    #[test]
+    fn test_alarms_are_shown_and_cleared() {
+        let sim = SimulatedCommandSender::new();
+        let mut app = pendant(&sim, None);
+        sim.raise_alarm(Alarm::LimitAxis1Pos as u8);
+
+        app.tick();
+        assert_eq!(app.alarms, AlarmSet::from_iter([Alarm::LimitAxis1Pos]));
+        press(&mut app, 'x');
+
+        assert!(app.alarms.is_empty());
+        assert_eq!(app.status, "Alarms cleared");
+    }
+
+    #[test]
+    fn test_rail_keys_need_a_rail() {
+        let sim = SimulatedCommandSender::new();
+        let mut app = pendant(&sim, None);
+        press(&mut app, ']');
+        assert_eq!(app.status, "No rail fitted");
+        assert!(sent(&sim, SetJogCmd::ID.into()).is_empty());
+
+        let sim = SimulatedCommandSender::new();
+        sim.with_state(|state| state.with_rail = true);
+        let mut app = pendant(&sim, None);
+        press(&mut app, ']');
+        thread::sleep(Duration::from_millis(20));
+        press(&mut app, ' ');
+        app.tick();
+
+        assert_eq!(sent(&sim, SetJogCmd::ID.into())[0], vec![0, 9]);
+        assert!(app.rail.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_io_readings_follow_the_multiplexing() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+        let mut io = arm.controller().io_controller;
+        io.set_io_multiplexing(
+            TagIOMultiplexing {
+                address: 4,
+                multiplex: IOFunction::Do,
+            },
+            false,
+        )
+        .unwrap();
+        io.set_io_do(
+            TagIODO {
+                address: 4,
+                level: Level::High,
+            },
+            false,
+        )
+        .unwrap();
+
+        let mut app = pendant(&sim, None);
+        app.tick();
+
+        assert_eq!(app.io.len(), 1, "{}", app.status);
+        assert_eq!(app.io[0].address, 4);
+        assert_eq!(
+            app.io[0].value,
+            Some(IoValue::Level(Level::High)),
+            "{}",
+            app.status
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! A terminal jog pendant and teach UI for the Dobot Magician.
+
+mod app;
+mod ui;
+mod waypoints;
+
+use std::{error::Error, path::PathBuf, time::Duration};
+
+use clap::Parser;
+use crossterm::{
+    event::{self, Event, KeyboardEnhancementFlags, PushKeyboardEnhancementFlags},
+    execute,
+    terminal::supports_keyboard_enhancement,
+};
+use dobot_api_rs::dobot::{
+    dobot_core_serial::{
+        command_sender_serial::DobotCommandSender,
+        discovery::{DobotLocation, discover_serial},
+        handle::DobotHandle,
+    },
+    dobot_trait::dobot_core::command_sender::{
+        CommandSender, simulated_command_sender::SimulatedCommandSender,
+    },
+};
+
+use app::{App, PendantOptions};
+
+/// How long to wait for a key before refreshing the screen.
+const FRAME_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Parser)]
+#[command(name = "dobot-pendant", version, about)]
+struct Args {
+    /// Serial port of the arm. Defaults to the first Magician found.
+    #[arg(long, conflicts_with = "sim")]
+    port: Option<String>,
+    /// Drive a simulated arm instead of a real one.
+    #[arg(long)]
+    sim: bool,
+    /// File the named waypoints are kept in.
+    #[arg(long, default_value = "waypoints.toml")]
+    waypoints: PathBuf,
+    /// Milliseconds a jog continues after its key stops repeating.
+    #[arg(long, default_value_t = 600)]
+    deadman_ms: u64,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    let mut options = PendantOptions {
+        waypoints_path: Some(args.waypoints.clone()),
+        ..PendantOptions::default()
+    };
+    options.jog.keepalive_timeout = Duration::from_millis(args.deadman_ms);
+
+    if args.sim {
+        return run(DobotHandle::new(SimulatedCommandSender::new()), options);
+    }
+    let port = match args.port {
+        Some(port) => port,
+        None => discover_serial()?
+            .into_iter()
+            .find_map(|found| match found.location {
+                DobotLocation::Serial { port_name, .. } => Some(port_name),
+                DobotLocation::Udp(_) => None,
+            })
+            .ok_or("no Dobot found; pass --port or --sim")?,
+    };
+    run(DobotHandle::new(DobotCommandSender::new(&port)?), options)
+}
+
+fn run<T: CommandSender + 'static>(
+    arm: DobotHandle<T>,
+    options: PendantOptions,
+) -> Result<(), Box<dyn Error>> {
+    let mut app = App::new(arm, options)?;
+
+    let mut terminal = ratatui::init();
+    // Lets a jog stop the moment its key is released where the terminal
+    // supports it.
+    let enhanced = supports_keyboard_enhancement().unwrap_or(false);
+    if enhanced {
+        execute!(
+            std::io::stdout(),
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)
+        )?;
+    }
+
+    let result = (|| -> Result<(), Box<dyn Error>> {
+        while !app.should_quit() {
+            app.tick();
+            terminal.draw(|frame| ui::render(frame, &app))?;
+            if event::poll(FRAME_INTERVAL)?
+                && let Event::Key(key) = event::read()?
+            {
+                app.handle_key(key);
+            }
+        }
+        Ok(())
+    })();
+
+    if enhanced {
+        let _ = execute!(
+            std::io::stdout(),
+            crossterm::event::PopKeyboardEnhancementFlags
+        );
+    }
+    ratatui::restore();
+    result
+}
@@ -0,0 +1,207 @@
+mod test;
+
+use dobot_api_rs::dobot::dobot_trait::{
+    dobot_core::command_sender::CommandSender,
+    protocol::{
+        alarm::AlarmSeverity,
+        bodies::{level::Level, tag_io_multiplexing::IOFunction},
+    },
+};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+};
+
+use crate::app::{App, HELP, Input, IoValue};
+
+/// Draws the whole pendant into `frame`.
+pub fn render<T: CommandSender + 'static>(frame: &mut Frame, app: &App<T>) {
+    let [header, body, status, help] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(8),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [motion, io, lists] = Layout::horizontal([
+        Constraint::Length(28),
+        Constraint::Length(30),
+        Constraint::Min(24),
+    ])
+    .areas(body);
+    let [waypoints, alarms] =
+        Layout::vertical([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(lists);
+
+    render_header(frame, app, header);
+    render_motion(frame, app, motion);
+    render_io(frame, app, io);
+    render_waypoints(frame, app, waypoints);
+    render_alarms(frame, app, alarms);
+
+    let status_line = match &app.input {
+        Input::Normal => Line::from(app.status.as_str()),
+        Input::WaypointName(name) => Line::from(vec![
+            Span::styled("Waypoint name: ", Style::new().add_modifier(Modifier::BOLD)),
+            Span::raw(format!("{}_", name)),
+        ]),
+    };
+    frame.render_widget(Paragraph::new(status_line), status);
+
+    let help_spans: Vec<Span> = HELP
+        .iter()
+        .flat_map(|(keys, what)| {
+            [
+                Span::styled(*keys, Style::new().fg(Color::Cyan)),
+                Span::raw(format!(" {}  ", what)),
+            ]
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(Line::from(help_spans)), help);
+}
+
+fn on_off(on: bool) -> &'static str {
+    if on { "on" } else { "off" }
+}
+
+fn render_header<T: CommandSender + 'static>(frame: &mut Frame, app: &App<T>, area: Rect) {
+    let jogging = match app.active_jog() {
+        Some(cmd) => format!("  jogging {:?}", cmd),
+        None => String::new(),
+    };
+    let line = Line::from(vec![
+        Span::styled("dobot-pendant", Style::new().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(
+            "  mode {:?}  speed {:.0}%  gripper {}  suction {}  laser {}{}",
+            app.mode(),
+            app.speed.velocity_ratio,
+            on_off(app.effectors.gripper),
+            on_off(app.effectors.suction),
+            on_off(app.effectors.laser),
+            jogging,
+        )),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn render_motion<T: CommandSender + 'static>(frame: &mut Frame, app: &App<T>, area: Rect) {
+    let mut lines = Vec::new();
+    match &app.pose {
+        Some(pose) => {
+            for (axis, value) in [("X", pose.x), ("Y", pose.y), ("Z", pose.z), ("R", pose.r)] {
+                lines.push(Line::from(format!("{} {:>9.2}", axis, value)));
+            }
+            lines.push(Line::from(""));
+            for (joint, angle) in pose.joint_angle.iter().enumerate() {
+                lines.push(Line::from(format!("J{} {:>8.2}°", joint + 1, angle)));
+            }
+        }
+        None => lines.push(Line::from("no pose yet")),
+    }
+    if app.with_rail {
+        lines.push(Line::from(""));
+        match app.rail {
+            Some(rail) => lines.push(Line::from(format!("Rail {:>8.2}", rail))),
+            None => lines.push(Line::from("Rail -")),
+        }
+    }
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(" Pose ")),
+        area,
+    );
+}
+
+fn render_io<T: CommandSender + 'static>(frame: &mut Frame, app: &App<T>, area: Rect) {
+    let lines: Vec<Line> = if app.io.is_empty() {
+        vec![Line::from("no EIO configured")]
+    } else {
+        app.io
+            .iter()
+            .map(|state| {
+                let function = match state.function {
+                    IOFunction::Dummy => "-",
+                    IOFunction::Do => "DO",
+                    IOFunction::Pwm => "PWM",
+                    IOFunction::Di => "DI",
+                    IOFunction::Adc => "ADC",
+                    IOFunction::Dipu => "DI pu",
+                    IOFunction::Dipd => "DI pd",
+                };
+                let value = match &state.value {
+                    None => String::from("?"),
+                    Some(IoValue::Level(Level::High)) => String::from("high"),
+                    Some(IoValue::Level(Level::Low)) => String::from("low"),
+                    Some(IoValue::Adc(value)) => value.to_string(),
+                    Some(IoValue::Pwm {
+                        frequency,
+                        duty_cycle,
+                    }) => format!("{:.0} Hz {:.0}%", frequency, duty_cycle),
+                };
+                Line::from(format!("EIO{:<2} {:<5} {}", state.address, function, value))
+            })
+            .collect()
+    };
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(" IO ")),
+        area,
+    );
+}
+
+fn render_waypoints<T: CommandSender + 'static>(frame: &mut Frame, app: &App<T>, area: Rect) {
+    if app.waypoints.is_empty() {
+        let hint = Paragraph::new("none, press n to save one")
+            .block(Block::bordered().title(" Waypoints "));
+        frame.render_widget(hint, area);
+        return;
+    }
+    let lines: Vec<Line> = app
+        .waypoints
+        .iter()
+        .enumerate()
+        .map(|(index, (name, waypoint))| {
+            let text = format!(
+                "{} ({:.1}, {:.1}, {:.1}, {:.1})",
+                name, waypoint.x, waypoint.y, waypoint.z, waypoint.r
+            );
+            if index == app.selected {
+                Line::styled(
+                    format!("> {}", text),
+                    Style::new().add_modifier(Modifier::REVERSED),
+                )
+            } else {
+                Line::from(format!("  {}", text))
+            }
+        })
+        .collect();
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(" Waypoints ")),
+        area,
+    );
+}
+
+fn render_alarms<T: CommandSender + 'static>(frame: &mut Frame, app: &App<T>, area: Rect) {
+    let lines: Vec<Line> = if app.alarms.is_empty() {
+        vec![Line::from("none")]
+    } else {
+        app.alarms
+            .iter()
+            .map(|alarm| {
+                let color = match alarm.severity() {
+                    AlarmSeverity::Info => Color::Cyan,
+                    AlarmSeverity::Warning => Color::Yellow,
+                    AlarmSeverity::Error | AlarmSeverity::Critical => Color::Red,
+                };
+                Line::styled(
+                    format!("{:#04x} {}", alarm.code(), alarm.description()),
+                    Style::new().fg(color),
+                )
+            })
+            .collect()
+    };
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(" Alarms ")),
+        area,
+    );
+}
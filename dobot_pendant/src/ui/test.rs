@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use dobot_api_rs::dobot::{
+        dobot_core_serial::handle::DobotHandle,
+        dobot_trait::{
+            dobot_core::command_sender::simulated_command_sender::SimulatedCommandSender,
+            protocol::alarm::Alarm,
+        },
+    };
+    use ratatui::{Terminal, backend::TestBackend};
+
+    use crate::{
+        app::{App, Input, PendantOptions},
+        ui::render,
+        waypoints::Waypoint,
+    };
+
+    fn screen(app: &App<SimulatedCommandSender>) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal.draw(|frame| render(frame, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_screen_shows_pose_alarms_and_waypoints() {
+        let sim = SimulatedCommandSender::new();
+        sim.raise_alarm(Alarm::LimitAxis2Neg as u8);
+        let mut app = App::new(DobotHandle::new(sim.clone()), PendantOptions::default()).unwrap();
+        app.waypoints.insert(
+            "pick",
+            Waypoint {
+                x: 210.0,
+                y: 5.0,
+                z: 1.0,
+                r: 0.0,
+                rail: None,
+            },
+        );
+        app.tick();
+
+        let text = screen(&app);
+
+        assert!(text.contains("X    200.00"), "{}", text);
+        assert!(text.contains("J1     0.00°"), "{}", text);
+        assert!(
+            text.contains("0x43 Joint 2 reached its negative limit"),
+            "{}",
+            text
+        );
+        assert!(text.contains("> pick (210.0, 5.0, 1.0, 0.0)"), "{}", text);
+        assert!(text.contains("mode Coordinate"), "{}", text);
+        assert!(!text.contains("Rail"), "{}", text);
+    }
+
+    #[test]
+    fn test_status_line_prompts_for_waypoint_name() {
+        let sim = SimulatedCommandSender::new();
+        let mut app = App::new(DobotHandle::new(sim), PendantOptions::default()).unwrap();
+        app.input = Input::WaypointName(String::from("dro"));
+
+        let text = screen(&app);
+
+        assert!(text.contains("Waypoint name: dro_"), "{}", text);
+        assert!(text.contains("no pose yet"), "{}", text);
+    }
+}
@@ -0,0 +1,89 @@
+mod test;
+
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use dobot_api_rs::dobot::dobot_trait::protocol::bodies::tag_pose::TagPose;
+use serde::{Deserialize, Serialize};
+
+/// A taught position, in the arm's Cartesian frame.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Waypoint {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub r: f32,
+    /// Rail position, for arms mounted on a sliding rail.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rail: Option<f32>,
+}
+
+impl Waypoint {
+    pub fn from_pose(pose: &TagPose, rail: Option<f32>) -> Self {
+        Self {
+            x: pose.x,
+            y: pose.y,
+            z: pose.z,
+            r: pose.r,
+            rail,
+        }
+    }
+}
+
+/// Named waypoints, kept sorted by name.
+///
+/// Stored as a TOML file with one `[waypoints.<name>]` table per waypoint.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Waypoints {
+    #[serde(default)]
+    waypoints: BTreeMap<String, Waypoint>,
+}
+
+impl Waypoints {
+    /// Reads waypoints from `path`; a missing file holds no waypoints.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                toml::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let text =
+            toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, text)
+    }
+
+    /// Adds `waypoint`, replacing any waypoint of the same name.
+    pub fn insert(&mut self, name: &str, waypoint: Waypoint) {
+        self.waypoints.insert(name.to_string(), waypoint);
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Waypoint> {
+        self.waypoints.remove(name)
+    }
+
+    /// Returns the waypoint at `index` in name order.
+    pub fn nth(&self, index: usize) -> Option<(&str, &Waypoint)> {
+        self.waypoints
+            .iter()
+            .nth(index)
+            .map(|(name, waypoint)| (name.as_str(), waypoint))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Waypoint)> {
+        self.waypoints
+            .iter()
+            .map(|(name, waypoint)| (name.as_str(), waypoint))
+    }
+
+    pub fn len(&self) -> usize {
+        self.waypoints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.waypoints.is_empty()
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::waypoints::{Waypoint, Waypoints};
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "dobot-pendant-{}-{}.toml",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_waypoints_round_trip_through_toml() {
+        let path = temp_file("round-trip");
+        let mut waypoints = Waypoints::default();
+        waypoints.insert(
+            "pick",
+            Waypoint {
+                x: 210.5,
+                y: -30.0,
+                z: 12.0,
+                r: 45.0,
+                rail: None,
+            },
+        );
+        waypoints.insert(
+            "drop",
+            Waypoint {
+                x: 180.0,
+                y: 90.0,
+                z: 40.0,
+                r: 0.0,
+                rail: Some(350.0),
+            },
+        );
+
+        waypoints.save(&path).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        let loaded = Waypoints::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(text.contains("[waypoints.pick]"), "{}", text);
+        assert_eq!(loaded, waypoints);
+        let names: Vec<_> = loaded.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, vec!["drop", "pick"]);
+        assert_eq!(loaded.nth(1).unwrap().1.x, 210.5);
+    }
+
+    #[test]
+    fn test_missing_file_holds_no_waypoints() {
+        let loaded = Waypoints::load(&temp_file("missing")).unwrap();
+        assert!(loaded.is_empty());
+    }
+
+    #[test]
+    fn test_malformed_file_is_rejected() {
+        let path = temp_file("malformed");
+        fs::write(&path, "[waypoints.pick]\nx = \"far\"\n").unwrap();
+        let result = Waypoints::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,116 @@
+//! Feeds arbitrary bytes to every `Body::deserialize` and to the response
+//! parser of every typed command. Run with
+//! `cargo +nightly fuzz run body_deserialize`.
+#![no_main]
+
+use dobot_api_rs::dobot::dobot_trait::protocol::{
+    Body,
+    bodies::{
+        fixed_response::FixedResponse, general_request::GeneralRequest,
+        general_response::GeneralResponse, hht_trig_mode::HHTTrigMode, level::Level,
+        tag_arc_cmd::*, tag_arc_params::*, tag_arm_orientation::*, tag_auto_leveling_params::*,
+        tag_calibration::*, tag_color::*, tag_cp_cmd::*, tag_cp_params::*, tag_device::*,
+        tag_emotor::*, tag_empty_body::EmptyBody, tag_enabled::*, tag_end_effector_params::*,
+        tag_end_effector_state::*, tag_home_cmd::*, tag_home_params::*, tag_io_adc::*,
+        tag_io_address::*, tag_io_di::*, tag_io_do::*, tag_io_multiplexing::*, tag_io_pwm::*,
+        tag_jog_cmd::*, tag_jog_common_params::*, tag_jog_coordinate_params::*,
+        tag_jog_joint_params::*, tag_jog_l_params::*, tag_lost_step_params::*, tag_po_cmd::*,
+        tag_pose::*, tag_ptp_cmd::*, tag_ptp_common_params::*, tag_ptp_coordinate_params::*,
+        tag_ptp_joint_params::*, tag_ptp_jump_params::*, tag_ptp_jump2_params::*,
+        tag_ptp_with_l_cmd::*, tag_ptpl_params::*, tag_queue::received::TagQueue,
+        tag_queued_cmd_download::*, tag_reset_pose::*, tag_trig_cmd::*, tag_wait_cmd::*,
+        tag_wifi_dns::*, tag_wifi_gateway::*, tag_wifi_ip_address::*, tag_wifi_netmask::*,
+        tag_with_l::*,
+    },
+    command::COMMANDS,
+};
+use libfuzzer_sys::fuzz_target;
+
+macro_rules! deserialize_all {
+    ($data:expr, $($body:ty),* $(,)?) => {
+        $(
+            let _ = <$body as Body>::deserialize($data);
+        )*
+    };
+}
+
+fuzz_target!(|data: &[u8]| {
+    deserialize_all!(
+        data,
+        EmptyBody,
+        GeneralRequest,
+        GeneralResponse,
+        FixedResponse<16>,
+        FixedResponse<64>,
+        HHTTrigMode,
+        Level,
+        Point,
+        TagARCCmd,
+        TagARCParams,
+        ArmOrientation,
+        TagAutoLevelingParams,
+        TagAngleSensorParams,
+        TagCalibrationValue,
+        TagColor,
+        CPMode,
+        TagCPCmd,
+        RealTimeTrack,
+        TagCPParams,
+        TagVersionColorSensorAndIR,
+        TagDevice,
+        EMotorIndex,
+        TagEMotor,
+        TagEMotorS,
+        TagEnabled,
+        TagEndEffectorParams,
+        TagEndEffectorState,
+        TagHomeCmd,
+        TagHomeParams,
+        TagIOADC,
+        TagIOAddress,
+        TagIODI,
+        TagIODO,
+        IOFunction,
+        TagIOMultiplexing,
+        TagIOPWM,
+        JogMode,
+        JogCmd,
+        TagJOGCmd,
+        TagJOGCommonParams,
+        TagJOGCoordinateParams,
+        TagJOGJointParams,
+        TagJOGLParams,
+        TagLostStepParams,
+        TagPOCmd,
+        TagPose,
+        PTPMode,
+        TagPTPCmd,
+        TagPTPCommonParams,
+        TagPTPCoordinateParams,
+        TagPTPJointParams,
+        TagPTPJump2Params,
+        TagPTPJumpParams,
+        TagPTPWithLCmd,
+        TagPTPLParams,
+        TagQueue,
+        TagQueuedCmdDownload,
+        TagResetPose,
+        TriggerMode,
+        TriggerCondition,
+        TagTRIGCmd,
+        TagWAITCmd,
+        TagWIFIDNS,
+        TagWIFIGateway,
+        TagWIFIIPAddress,
+        TagWIFINetmask,
+        TagVersionRail,
+        TagWithL,
+        TagWithLReturn,
+    );
+
+    // The typed commands decode their responses through the same bodies, but
+    // also enforce each command's maximum response size.
+    for descriptor in COMMANDS {
+        let _ = (descriptor.parse_response)(data);
+    }
+});
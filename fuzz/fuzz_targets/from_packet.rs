@@ -0,0 +1,36 @@
+//! Feeds arbitrary bytes to `Protocol::from_packet`, as a noisy serial line or
+//! a rogue peer would. Run with `cargo +nightly fuzz run from_packet`.
+#![no_main]
+
+use dobot_api_rs::dobot::dobot_trait::protocol::{
+    MAX_PACKET_SIZE, Protocol,
+    bodies::{general_request::GeneralRequest, general_response::GeneralResponse},
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(packet) = Protocol::<GeneralResponse>::from_packet(data) else {
+        return;
+    };
+
+    // Anything we accept must encode again and parse back to the same frame.
+    let request = Protocol::new(
+        packet.command_id,
+        packet.is_queued,
+        packet.is_read,
+        GeneralRequest {
+            params: packet.body.params,
+        },
+    );
+    let mut buffer = [0u8; MAX_PACKET_SIZE];
+    let len = request
+        .to_packet(&mut buffer)
+        .expect("an accepted frame must encode again");
+
+    let again = Protocol::<GeneralResponse>::from_packet(&buffer[..len])
+        .expect("an encoded frame must parse");
+    assert_eq!(again.command_id, packet.command_id);
+    assert_eq!(again.is_queued, packet.is_queued);
+    assert_eq!(again.is_read, packet.is_read);
+    assert_eq!(again.body.params, packet.body.params);
+});
@@ -0,0 +1,270 @@
+mod test;
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, Receiver, Sender},
+    },
+    time::{Duration, SystemTime},
+};
+#[cfg(feature = "serde")]
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::dobot::{
+    dobot_core_serial::{
+        handle::DobotHandle,
+        worker::{PeriodicWorker, lock},
+    },
+    dobot_trait::{
+        dobot_core::{
+            command_sender::CommandSender,
+            dobot_error::DobotError,
+            sub_command_dobot::{alarm_control::AlarmControl, real_time_control::RealTimeControl},
+        },
+        protocol::{
+            alarm::{ActiveAlarm, AlarmSet},
+            bodies::tag_pose::TagPose,
+        },
+    },
+};
+
+/// Whether an alarm became active or went away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AlarmEventKind {
+    Raised,
+    Cleared,
+}
+
+/// A change of one alarm seen by an [`AlarmMonitor`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlarmEvent {
+    pub kind: AlarmEventKind,
+    pub alarm: ActiveAlarm,
+    /// When the poll that saw the change was answered.
+    pub time: SystemTime,
+    /// The pose read right after the change was seen, or `None` if the arm
+    /// did not answer.
+    pub pose: Option<TagPose>,
+}
+
+/// Settings of an [`AlarmMonitor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlarmMonitorOptions {
+    /// How often the alarm state is read.
+    pub poll_interval: Duration,
+    /// Number of events kept in memory; older events are dropped first.
+    pub history_len: usize,
+    /// File every event is appended to as one line of JSON. Events already
+    /// in the file are loaded into the history when the monitor starts.
+    #[cfg(feature = "serde")]
+    pub history_file: Option<PathBuf>,
+}
+
+impl Default for AlarmMonitorOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_millis(500),
+            history_len: 1000,
+            #[cfg(feature = "serde")]
+            history_file: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MonitorState {
+    active: AlarmSet,
+    history: VecDeque<AlarmEvent>,
+    subscribers: Vec<Sender<AlarmEvent>>,
+    last_error: Option<DobotError>,
+}
+
+impl MonitorState {
+    fn record(&mut self, event: AlarmEvent, history_len: usize) {
+        self.subscribers
+            .retain(|subscriber| subscriber.send(event.clone()).is_ok());
+        if history_len == 0 {
+            return;
+        }
+        if self.history.len() == history_len {
+            self.history.pop_front();
+        }
+        self.history.push_back(event);
+    }
+}
+
+/// Polls the alarm state of an arm on its own thread and records every alarm
+/// that is raised or cleared.
+///
+/// Alarms already active on the first poll are reported as raised. A failed
+/// poll is kept as [`AlarmMonitor::last_error`] and the next poll compares
+/// against the last state that was read.
+pub struct AlarmMonitor {
+    state: Arc<Mutex<MonitorState>>,
+    worker: PeriodicWorker,
+}
+
+impl AlarmMonitor {
+    pub fn spawn<T: CommandSender + 'static>(
+        arm: DobotHandle<T>,
+        options: AlarmMonitorOptions,
+    ) -> Result<Self, DobotError> {
+        #[cfg(feature = "serde")]
+        let history = match &options.history_file {
+            Some(path) if path.exists() => {
+                let events = load_history(path)?;
+                let skip = events.len().saturating_sub(options.history_len);
+                events.into_iter().skip(skip).collect()
+            }
+            _ => VecDeque::new(),
+        };
+        #[cfg(not(feature = "serde"))]
+        let history = VecDeque::new();
+
+        let state = Arc::new(Mutex::new(MonitorState {
+            active: AlarmSet::new(),
+            history,
+            subscribers: Vec::new(),
+            last_error: None,
+        }));
+
+        let thread_state = Arc::clone(&state);
+        let worker = PeriodicWorker::spawn(options.poll_interval, move || {
+            poll(&arm, &thread_state, &options);
+        });
+
+        Ok(Self { state, worker })
+    }
+
+    /// Returns the alarms active at the last successful poll.
+    pub fn active(&self) -> AlarmSet {
+        lock(&self.state).active
+    }
+
+    /// Returns the recorded events, oldest first.
+    pub fn history(&self) -> Vec<AlarmEvent> {
+        lock(&self.state).history.iter().cloned().collect()
+    }
+
+    /// Returns a stream of the events recorded from now on. The stream ends
+    /// when the monitor is stopped.
+    pub fn subscribe(&self) -> Receiver<AlarmEvent> {
+        let (sender, receiver) = mpsc::channel();
+        lock(&self.state).subscribers.push(sender);
+        receiver
+    }
+
+    /// Returns the error of the last poll, or `None` if it succeeded.
+    pub fn last_error(&self) -> Option<DobotError> {
+        lock(&self.state).last_error.clone()
+    }
+
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.worker.stop();
+        lock(&self.state).subscribers.clear();
+    }
+}
+
+impl Drop for AlarmMonitor {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+fn poll<T: CommandSender + 'static>(
+    arm: &DobotHandle<T>,
+    state: &Mutex<MonitorState>,
+    options: &AlarmMonitorOptions,
+) {
+    let active = match arm.controller().alarm_controller.get_active_alarms() {
+        Ok(active) => active,
+        Err(e) => {
+            lock(state).last_error = Some(e);
+            return;
+        }
+    };
+    let time = SystemTime::now();
+
+    let diff = lock(state).active.diff(&active);
+    let pose = if diff.is_empty() {
+        None
+    } else {
+        arm.controller().realtime_controller.get_pose().ok()
+    };
+    let events = diff
+        .raised
+        .iter()
+        .map(|alarm| (AlarmEventKind::Raised, alarm))
+        .chain(
+            diff.cleared
+                .iter()
+                .map(|alarm| (AlarmEventKind::Cleared, alarm)),
+        )
+        .map(|(kind, alarm)| AlarmEvent {
+            kind,
+            alarm,
+            time,
+            pose: pose.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    #[cfg(feature = "serde")]
+    let last_error = options
+        .history_file
+        .as_ref()
+        .and_then(|path| append_history(path, &events).err());
+    #[cfg(not(feature = "serde"))]
+    let last_error = None;
+
+    let mut state = lock(state);
+    state.active = active;
+    state.last_error = last_error;
+    for event in events {
+        state.record(event, options.history_len);
+    }
+}
+
+#[cfg(feature = "serde")]
+fn history_error(error: impl core::fmt::Display) -> DobotError {
+    DobotError::AlarmHistory(error.to_string())
+}
+
+#[cfg(feature = "serde")]
+fn append_history(path: &Path, events: &[AlarmEvent]) -> Result<(), DobotError> {
+    if events.is_empty() {
+        return Ok(());
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(history_error)?;
+    for event in events {
+        let line = serde_json::to_string(event).map_err(history_error)?;
+        writeln!(file, "{}", line).map_err(history_error)?;
+    }
+    Ok(())
+}
+
+/// Reads the events an [`AlarmMonitor`] appended to `path`, oldest first.
+#[cfg(feature = "serde")]
+pub fn load_history(path: &Path) -> Result<Vec<AlarmEvent>, DobotError> {
+    let file = File::open(path).map_err(history_error)?;
+    BufReader::new(file)
+        .lines()
+        .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|line| serde_json::from_str(&line.map_err(history_error)?).map_err(history_error))
+        .collect()
+}
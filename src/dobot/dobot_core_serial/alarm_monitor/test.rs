@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        thread,
+        time::{Duration, Instant, SystemTime},
+    };
+
+    use crate::dobot::{
+        dobot_core_serial::{
+            alarm_monitor::{AlarmEvent, AlarmEventKind, AlarmMonitor, AlarmMonitorOptions},
+            handle::DobotHandle,
+        },
+        dobot_trait::{
+            dobot_core::{
+                command_sender::simulated_command_sender::SimulatedCommandSender,
+                dobot_error::DobotError, sub_command_dobot::alarm_control::AlarmControl,
+            },
+            protocol::alarm::{ActiveAlarm, Alarm, AlarmSet},
+        },
+    };
+
+    fn fast_monitor() -> AlarmMonitorOptions {
+        AlarmMonitorOptions {
+            poll_interval: Duration::from_millis(5),
+            ..AlarmMonitorOptions::default()
+        }
+    }
+
+    fn wait_until(what: &str, mut done: impl FnMut() -> bool) {
+        let started = Instant::now();
+        while !done() {
+            assert!(started.elapsed() < Duration::from_secs(5), "{}", what);
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_raised_and_cleared_alarms_are_recorded() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+        let before = SystemTime::now();
+        let monitor = AlarmMonitor::spawn(arm.clone(), fast_monitor()).unwrap();
+        let events = monitor.subscribe();
+
+        sim.with_state(|state| state.pose.x = 150.0);
+        sim.raise_alarm(Alarm::LimitAxis1Pos as u8);
+        sim.raise_alarm(0xF3);
+        wait_until("alarms never raised", || monitor.active().len() == 2);
+        arm.controller()
+            .alarm_controller
+            .clear_all_alarms_state()
+            .unwrap();
+        wait_until("alarms never cleared", || monitor.active().is_empty());
+
+        let history = monitor.history();
+        let seen: Vec<_> = history.iter().map(|e| (e.kind, e.alarm)).collect();
+        assert_eq!(
+            seen,
+            vec![
+                (
+                    AlarmEventKind::Raised,
+                    ActiveAlarm::Known(Alarm::LimitAxis1Pos)
+                ),
+                (AlarmEventKind::Raised, ActiveAlarm::Unknown(0xF3)),
+                (
+                    AlarmEventKind::Cleared,
+                    ActiveAlarm::Known(Alarm::LimitAxis1Pos)
+                ),
+                (AlarmEventKind::Cleared, ActiveAlarm::Unknown(0xF3)),
+            ]
+        );
+        assert!(history.iter().all(|e| e.time >= before));
+        assert_eq!(history[0].pose.as_ref().unwrap().x, 150.0);
+
+        monitor.stop();
+        let streamed: Vec<AlarmEvent> = events.iter().collect();
+        assert_eq!(streamed, history);
+    }
+
+    #[test]
+    fn test_alarms_active_at_start_are_raised() {
+        let sim = SimulatedCommandSender::new();
+        sim.raise_alarm(Alarm::PlanInvLimit as u8);
+
+        let monitor = AlarmMonitor::spawn(DobotHandle::new(sim.clone()), fast_monitor()).unwrap();
+        wait_until("alarm never seen", || !monitor.history().is_empty());
+
+        assert_eq!(monitor.active(), AlarmSet::from_iter([Alarm::PlanInvLimit]));
+        assert_eq!(monitor.history()[0].kind, AlarmEventKind::Raised);
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+        let monitor = AlarmMonitor::spawn(
+            arm.clone(),
+            AlarmMonitorOptions {
+                history_len: 3,
+                ..fast_monitor()
+            },
+        )
+        .unwrap();
+
+        for _ in 0..3 {
+            sim.raise_alarm(Alarm::LimitAxis4Neg as u8);
+            wait_until("alarm never raised", || !monitor.active().is_empty());
+            arm.controller()
+                .alarm_controller
+                .clear_all_alarms_state()
+                .unwrap();
+            wait_until("alarm never cleared", || monitor.active().is_empty());
+        }
+
+        let kinds: Vec<_> = monitor.history().iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                AlarmEventKind::Cleared,
+                AlarmEventKind::Raised,
+                AlarmEventKind::Cleared
+            ]
+        );
+    }
+
+    #[test]
+    fn test_failed_polls_keep_the_last_state() {
+        let sim = SimulatedCommandSender::new();
+        sim.raise_alarm(Alarm::LimitAxis1Pos as u8);
+        let monitor = AlarmMonitor::spawn(DobotHandle::new(sim.clone()), fast_monitor()).unwrap();
+        wait_until("alarm never seen", || !monitor.active().is_empty());
+
+        sim.set_connected(false);
+        wait_until("error never seen", || monitor.last_error().is_some());
+        assert!(matches!(monitor.last_error(), Some(DobotError::IO)));
+        sim.set_connected(true);
+        wait_until("error never cleared", || monitor.last_error().is_none());
+
+        assert_eq!(monitor.history().len(), 1);
+        assert!(monitor.active().contains(Alarm::LimitAxis1Pos));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_history_is_persisted_and_reloaded() {
+        use crate::dobot::dobot_core_serial::alarm_monitor::load_history;
+
+        let path =
+            std::env::temp_dir().join(format!("dobot-alarm-history-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let options = AlarmMonitorOptions {
+            history_file: Some(path.clone()),
+            ..fast_monitor()
+        };
+
+        let sim = SimulatedCommandSender::new();
+        let monitor = AlarmMonitor::spawn(DobotHandle::new(sim.clone()), options.clone()).unwrap();
+        sim.raise_alarm(Alarm::LoseStepAxis2 as u8);
+        wait_until("alarm never raised", || !monitor.history().is_empty());
+        let recorded = monitor.history();
+        monitor.stop();
+        // Read before the next monitor polls and records the alarm again.
+        let persisted = load_history(&path).unwrap();
+
+        let reloaded = AlarmMonitor::spawn(DobotHandle::new(sim.clone()), options).unwrap();
+        let history = reloaded.history();
+        reloaded.stop();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(persisted, recorded);
+        assert_eq!(history[0], recorded[0]);
+    }
+}
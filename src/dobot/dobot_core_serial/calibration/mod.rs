@@ -0,0 +1,239 @@
+mod test;
+
+use std::time::Duration;
+
+use crate::dobot::{
+    dobot_core_serial::handle::DobotHandle,
+    dobot_trait::{
+        dobot_core::{
+            command_sender::CommandSender,
+            dobot_error::DobotError,
+            sub_command_dobot::{
+                calibration_control::CalibrationControl, ptp_control::PTPControl,
+                real_time_control::RealTimeControl,
+            },
+        },
+        protocol::bodies::{
+            tag_pose::TagPose,
+            tag_ptp_cmd::{PTPMode, TagPTPCmd},
+        },
+    },
+};
+
+/// Every calibration value of an arm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalibrationValues {
+    /// Static errors of the (rear arm, front arm) angle sensors, in degrees.
+    pub angle_sensor_static_error: (f32, f32),
+    /// Gain coefficients of the (rear arm, front arm) angle sensors.
+    pub angle_sensor_coef: (f32, f32),
+    /// Static error of the base encoder, in degrees.
+    pub base_decoder_static_error: f32,
+    pub rhand_calibrate_value: f32,
+}
+
+impl CalibrationValues {
+    pub fn read<T: CommandSender + 'static>(arm: &DobotHandle<T>) -> Result<Self, DobotError> {
+        let mut calibration = arm.controller().calibration_controller;
+        Ok(Self {
+            angle_sensor_static_error: calibration.get_angle_sensor_static_error()?,
+            angle_sensor_coef: calibration.get_angle_sensor_coef()?,
+            base_decoder_static_error: calibration.get_base_decoder_static_error()?,
+            rhand_calibrate_value: calibration.get_rhand_calibrate_value()?,
+        })
+    }
+
+    pub fn write<T: CommandSender + 'static>(
+        &self,
+        arm: &DobotHandle<T>,
+    ) -> Result<(), DobotError> {
+        let mut calibration = arm.controller().calibration_controller;
+        let (rear, front) = self.angle_sensor_static_error;
+        calibration.set_angle_sensor_static_error(rear, front)?;
+        let (rear, front) = self.angle_sensor_coef;
+        calibration.set_angle_sensor_coef(rear, front)?;
+        calibration.set_base_decoder_static_error(self.base_decoder_static_error)?;
+        calibration.set_rhand_calibrate_value(self.rhand_calibrate_value)
+    }
+}
+
+/// Settings of [`calibrate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationOptions {
+    /// Joint angles `[j1, j2, j3, j4]` the arm is measured at.
+    pub reference_poses: Vec<[f32; 4]>,
+    /// How far in degrees a joint may be off once calibrated.
+    pub tolerance: f32,
+    /// How long a move to a reference pose may take.
+    pub move_timeout: Duration,
+}
+
+impl Default for CalibrationOptions {
+    fn default() -> Self {
+        Self {
+            reference_poses: vec![
+                [0.0, 0.0, 0.0, 0.0],
+                [-45.0, 20.0, 40.0, 0.0],
+                [45.0, 45.0, 10.0, 0.0],
+            ],
+            tolerance: 0.2,
+            move_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// The angles reported and measured at one reference pose.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    pub reference: [f32; 4],
+    /// The joint angles reported by the arm.
+    pub reported: [f32; 4],
+    /// The joint angles measured by the caller.
+    pub actual: [f32; 4],
+}
+
+impl Measurement {
+    /// How far the reported angle of each joint is off, in degrees.
+    pub fn deviation(&self) -> [f32; 4] {
+        let mut deviation = [0.0; 4];
+        for (joint, deviation) in deviation.iter_mut().enumerate() {
+            *deviation = self.reported[joint] - self.actual[joint];
+        }
+        deviation
+    }
+}
+
+/// The outcome of a successful [`calibrate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationReport {
+    /// The values the arm had before calibrating.
+    pub backup: CalibrationValues,
+    pub calibrated: CalibrationValues,
+    pub before: Vec<Measurement>,
+    /// The measurements taken to verify the new values.
+    pub after: Vec<Measurement>,
+}
+
+impl CalibrationReport {
+    /// Writes the values the arm had before calibrating back.
+    pub fn rollback<T: CommandSender + 'static>(
+        &self,
+        arm: &DobotHandle<T>,
+    ) -> Result<(), DobotError> {
+        self.backup.write(arm)
+    }
+}
+
+/// Corrects the static errors of the base encoder and the angle sensors.
+///
+/// The arm is moved to every reference pose, where `measure` is given the
+/// reference and the reported pose and returns the actual joint angles, as
+/// read from a jig or an inclinometer. The mean deviation of joints 1 to 3
+/// is taken off the base encoder, rear arm and front arm errors, and the
+/// reference poses are measured again. If a joint is still off by more than
+/// the tolerance, the previous values are written back and
+/// [`DobotError::Calibration`] is returned. They are also written back when
+/// writing the new values or measuring them fails.
+pub fn calibrate<T: CommandSender + 'static>(
+    arm: &DobotHandle<T>,
+    options: &CalibrationOptions,
+    mut measure: impl FnMut(&[f32; 4], &TagPose) -> Result<[f32; 4], DobotError>,
+) -> Result<CalibrationReport, DobotError> {
+    if options.reference_poses.is_empty() {
+        return Err(DobotError::Calibration(vec![
+            "no reference poses to measure".to_string(),
+        ]));
+    }
+
+    let backup = CalibrationValues::read(arm)?;
+    let before = measure_all(arm, options, &mut measure)?;
+    let mut mean = [0.0; 4];
+    for measurement in &before {
+        for (mean, deviation) in mean.iter_mut().zip(measurement.deviation()) {
+            *mean += deviation / before.len() as f32;
+        }
+    }
+
+    let (rear, front) = backup.angle_sensor_static_error;
+    let calibrated = CalibrationValues {
+        angle_sensor_static_error: (rear - mean[1], front - mean[2]),
+        base_decoder_static_error: backup.base_decoder_static_error - mean[0],
+        ..backup
+    };
+    if let Err(error) = calibrated.write(arm) {
+        backup.write(arm)?;
+        return Err(error);
+    }
+
+    let after = match measure_all(arm, options, &mut measure) {
+        Ok(after) => after,
+        Err(error) => {
+            backup.write(arm)?;
+            return Err(error);
+        }
+    };
+    let mut errors = Vec::new();
+    for measurement in &after {
+        for (joint, deviation) in measurement.deviation().iter().take(3).enumerate() {
+            if deviation.abs() > options.tolerance {
+                errors.push(format!(
+                    "joint {} at {:?} is off by {:.2}°",
+                    joint + 1,
+                    measurement.reference,
+                    deviation
+                ));
+            }
+        }
+    }
+    if !errors.is_empty() {
+        backup.write(arm)?;
+        return Err(DobotError::Calibration(errors));
+    }
+
+    Ok(CalibrationReport {
+        backup,
+        calibrated,
+        before,
+        after,
+    })
+}
+
+fn measure_all<T: CommandSender + 'static>(
+    arm: &DobotHandle<T>,
+    options: &CalibrationOptions,
+    measure: &mut impl FnMut(&[f32; 4], &TagPose) -> Result<[f32; 4], DobotError>,
+) -> Result<Vec<Measurement>, DobotError> {
+    let mut measurements = Vec::new();
+    for reference in &options.reference_poses {
+        let [x, y, z, r] = *reference;
+        let index = arm
+            .controller()
+            .ptp_controller
+            .set_ptp_cmd(
+                TagPTPCmd {
+                    ptp_mode: PTPMode::MovjAngle,
+                    x,
+                    y,
+                    z,
+                    r,
+                },
+                true,
+            )?
+            .ok_or(DobotError::NoResponse)?;
+        let waited = arm.wait_for_queue_index(index, options.move_timeout);
+        if matches!(waited, Err(DobotError::Timeout)) {
+            arm.cancel_queue()?;
+        }
+        waited?;
+
+        let pose = arm.controller().realtime_controller.get_pose()?;
+        let actual = measure(reference, &pose)?;
+        measurements.push(Measurement {
+            reference: *reference,
+            reported: pose.joint_angle,
+            actual,
+        });
+    }
+    Ok(measurements)
+}
@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    use crate::dobot::{
+        dobot_core_serial::{
+            calibration::{CalibrationOptions, CalibrationValues, calibrate},
+            handle::DobotHandle,
+        },
+        dobot_trait::{
+            dobot_core::{
+                command_sender::{CommandSender, simulated_command_sender::SimulatedCommandSender},
+                dobot_error::DobotError,
+            },
+            protocol::{
+                Protocol,
+                bodies::{general_response::GeneralResponse, tag_pose::TagPose},
+                command::{Command, SetAngleSensorCoef, SetPtpCmd},
+            },
+        },
+    };
+
+    /// Sensor errors of joints 1 to 3 before any correction.
+    const BIAS: [f32; 3] = [0.5, -0.8, 0.3];
+
+    fn values() -> CalibrationValues {
+        CalibrationValues {
+            angle_sensor_static_error: (0.1, -0.2),
+            angle_sensor_coef: (1.02, 0.98),
+            base_decoder_static_error: 0.25,
+            rhand_calibrate_value: 3.0,
+        }
+    }
+
+    /// Measures the angles of an arm whose reported angles are off by the
+    /// sensor error plus the static error currently written to it.
+    fn jig(
+        arm: &DobotHandle<SimulatedCommandSender>,
+    ) -> impl FnMut(&[f32; 4], &TagPose) -> Result<[f32; 4], DobotError> + '_ {
+        |_, pose| {
+            let values = CalibrationValues::read(arm)?;
+            let offsets = [
+                values.base_decoder_static_error,
+                values.angle_sensor_static_error.0,
+                values.angle_sensor_static_error.1,
+            ];
+            let mut actual = pose.joint_angle;
+            for joint in 0..3 {
+                actual[joint] -= BIAS[joint] + offsets[joint];
+            }
+            Ok(actual)
+        }
+    }
+
+    /// Fails the next `SetAngleSensorCoef` once `armed` is set.
+    #[derive(Debug)]
+    struct FailingCoef {
+        sim: SimulatedCommandSender,
+        armed: Arc<AtomicBool>,
+    }
+
+    impl CommandSender for FailingCoef {
+        fn send_raw_packet(
+            &mut self,
+            request_packet: &[u8],
+            response_buffer: &mut [u8],
+        ) -> Result<usize, DobotError> {
+            let request = Protocol::<GeneralResponse>::from_packet(request_packet)?;
+            // The rw flag of the control byte is set for writes.
+            if request.command_id == SetAngleSensorCoef::ID
+                && request.is_read
+                && self.armed.swap(false, Ordering::SeqCst)
+            {
+                return Err(DobotError::Timeout);
+            }
+            self.sim.send_raw_packet(request_packet, response_buffer)
+        }
+
+        fn get_status_str(&self, buffer: &mut [u8]) -> Result<usize, DobotError> {
+            self.sim.get_status_str(buffer)
+        }
+    }
+
+    #[test]
+    fn test_values_round_trip() {
+        let arm = DobotHandle::new(SimulatedCommandSender::new());
+
+        values().write(&arm).unwrap();
+
+        assert_eq!(CalibrationValues::read(&arm).unwrap(), values());
+    }
+
+    #[test]
+    fn test_calibrate_corrects_and_verifies_the_offsets() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+        values().write(&arm).unwrap();
+        let options = CalibrationOptions::default();
+
+        let report = calibrate(&arm, &options, jig(&arm)).unwrap();
+
+        assert_eq!(report.backup, values());
+        let calibrated = CalibrationValues::read(&arm).unwrap();
+        assert_eq!(calibrated, report.calibrated);
+        assert!((calibrated.base_decoder_static_error + 0.5).abs() < 1e-4);
+        assert!((calibrated.angle_sensor_static_error.0 - 0.8).abs() < 1e-4);
+        assert!((calibrated.angle_sensor_static_error.1 + 0.3).abs() < 1e-4);
+        assert_eq!(calibrated.angle_sensor_coef, values().angle_sensor_coef);
+        assert!(
+            report
+                .after
+                .iter()
+                .all(|m| m.deviation().iter().all(|d| d.abs() < 1e-4))
+        );
+        assert_eq!(report.before[1].reference, options.reference_poses[1]);
+        let moves = sim
+            .commands()
+            .iter()
+            .filter(|c| c.id == u8::from(SetPtpCmd::ID))
+            .count();
+        assert_eq!(moves, 2 * options.reference_poses.len());
+
+        report.rollback(&arm).unwrap();
+        assert_eq!(CalibrationValues::read(&arm).unwrap(), values());
+    }
+
+    #[test]
+    fn test_failed_verification_rolls_back() {
+        let arm = DobotHandle::new(SimulatedCommandSender::new());
+        values().write(&arm).unwrap();
+        let mut readings = 0;
+
+        // The front arm reads 1° high whatever is written to the arm.
+        let error = calibrate(&arm, &CalibrationOptions::default(), |_, pose| {
+            readings += 1;
+            let mut actual = pose.joint_angle;
+            actual[2] -= 1.0;
+            Ok(actual)
+        })
+        .unwrap_err();
+
+        match error {
+            DobotError::Calibration(errors) => {
+                assert_eq!(errors.len(), 3);
+                assert!(errors[0].starts_with("joint 3"), "{:?}", errors);
+            }
+            other => panic!("expected a calibration error, got {:?}", other),
+        }
+        assert_eq!(readings, 6);
+        assert_eq!(CalibrationValues::read(&arm).unwrap(), values());
+    }
+
+    #[test]
+    fn test_partly_written_values_are_rolled_back() {
+        let armed = Arc::new(AtomicBool::new(false));
+        let arm = DobotHandle::new(FailingCoef {
+            sim: SimulatedCommandSender::new(),
+            armed: Arc::clone(&armed),
+        });
+        values().write(&arm).unwrap();
+        armed.store(true, Ordering::SeqCst);
+
+        // The static errors are written before the coefficients fail.
+        let result = calibrate(&arm, &CalibrationOptions::default(), |_, pose| {
+            let mut actual = pose.joint_angle;
+            actual[1] -= 1.0;
+            Ok(actual)
+        });
+
+        assert!(matches!(result, Err(DobotError::Timeout)));
+        assert!(!armed.load(Ordering::SeqCst));
+        assert_eq!(CalibrationValues::read(&arm).unwrap(), values());
+    }
+
+    #[test]
+    fn test_calibrate_needs_reference_poses() {
+        let sim = SimulatedCommandSender::new();
+        let options = CalibrationOptions {
+            reference_poses: Vec::new(),
+            ..CalibrationOptions::default()
+        };
+
+        let result = calibrate(&DobotHandle::new(sim.clone()), &options, |_, pose| {
+            Ok(pose.joint_angle)
+        });
+
+        assert!(matches!(result, Err(DobotError::Calibration(_))));
+        assert!(sim.commands().is_empty());
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use serialport::{
+    ClearBuffer, DataBits, FlowControl, Parity, SerialPort, SerialPortBuilder, StopBits,
+};
+
+use crate::dobot::{
+    dobot_core_serial::command_sender_serial::{
+        CommandClass, CommandTimeouts, DEFAULT_COMMAND_TIMEOUT, DobotCommandSender, SerialStream,
+        Transport,
+    },
+    dobot_trait::dobot_core::dobot_error::DobotError,
+};
+
+/// How long DTR and RTS are held low to reset the controller.
+const RESET_PULSE: Duration = Duration::from_millis(100);
+
+/// Pause between attempts to open a port that is not available yet.
+const CONNECT_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Configures and opens a [`DobotCommandSender`].
+///
+/// The defaults match the USB bridge of the Magician: 115200 baud, 8N1, no
+/// flow control, an exclusive lock on the port and both port buffers cleared
+/// after opening.
+#[derive(Debug, Clone)]
+pub struct DobotCommandSenderBuilder {
+    baud_rate: u32,
+    data_bits: DataBits,
+    parity: Parity,
+    stop_bits: StopBits,
+    flow_control: FlowControl,
+    connect_timeout: Duration,
+    read_timeout: Duration,
+    timeouts: CommandTimeouts,
+    reset_settle: Option<Duration>,
+    exclusive: bool,
+    clear_on_open: bool,
+}
+
+impl Default for DobotCommandSenderBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DobotCommandSenderBuilder {
+    pub fn new() -> Self {
+        Self {
+            baud_rate: 115200,
+            data_bits: DataBits::Eight,
+            parity: Parity::None,
+            stop_bits: StopBits::One,
+            flow_control: FlowControl::None,
+            connect_timeout: Duration::ZERO,
+            read_timeout: DEFAULT_COMMAND_TIMEOUT,
+            timeouts: CommandTimeouts::default(),
+            reset_settle: None,
+            exclusive: true,
+            clear_on_open: true,
+        }
+    }
+
+    pub fn baud_rate(mut self, baud_rate: u32) -> Self {
+        self.baud_rate = baud_rate;
+        self
+    }
+
+    pub fn data_bits(mut self, data_bits: DataBits) -> Self {
+        self.data_bits = data_bits;
+        self
+    }
+
+    pub fn parity(mut self, parity: Parity) -> Self {
+        self.parity = parity;
+        self
+    }
+
+    pub fn stop_bits(mut self, stop_bits: StopBits) -> Self {
+        self.stop_bits = stop_bits;
+        self
+    }
+
+    pub fn flow_control(mut self, flow_control: FlowControl) -> Self {
+        self.flow_control = flow_control;
+        self
+    }
+
+    /// Keeps retrying to open the port for up to `timeout`, e.g. while a
+    /// freshly plugged adapter is still being enumerated. Zero tries once.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// The timeout of a single read on a serial port.
+    ///
+    /// A command keeps reading until its own timeout expires, so this only
+    /// bounds how late an expired command notices. Streams passed to
+    /// [`DobotCommandSenderBuilder::from_stream`] keep their own timeouts.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = timeout;
+        self
+    }
+
+    /// Sets how long commands of `class` may take to be answered.
+    pub fn command_timeout(mut self, class: CommandClass, timeout: Duration) -> Self {
+        self.timeouts.set(class, timeout);
+        self
+    }
+
+    /// Sets the timeouts of every command class at once.
+    pub fn command_timeouts(mut self, timeouts: CommandTimeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Resets the controller after opening by pulling DTR and RTS low, then
+    /// waits `settle` for it to boot before the port buffers are cleared.
+    pub fn reset_on_open(mut self, settle: Duration) -> Self {
+        self.reset_settle = Some(settle);
+        self
+    }
+
+    /// Whether other processes are locked out of the port while it is open.
+    ///
+    /// Only has an effect on Unix; Windows always opens ports exclusively.
+    pub fn exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    /// Whether both port buffers are cleared after opening.
+    pub fn clear_on_open(mut self, clear: bool) -> Self {
+        self.clear_on_open = clear;
+        self
+    }
+
+    /// Opens `port_name` with the configured line settings.
+    pub fn open(self, port_name: &str) -> Result<DobotCommandSender, DobotError> {
+        let deadline = Instant::now() + self.connect_timeout;
+        let port = loop {
+            let builder = serialport::new(port_name, self.baud_rate)
+                .data_bits(self.data_bits)
+                .parity(self.parity)
+                .stop_bits(self.stop_bits)
+                .flow_control(self.flow_control)
+                .timeout(self.read_timeout);
+            match open_port(builder, self.exclusive) {
+                Ok(port) => break port,
+                Err(_e) if Instant::now() < deadline => thread::sleep(CONNECT_RETRY_INTERVAL),
+                Err(_e) => return Err(DobotError::Serial),
+            }
+        };
+        self.from_port(port)
+    }
+
+    /// Wraps a port that was already opened, e.g. an RS-485 converter that
+    /// needs settings this builder does not cover.
+    ///
+    /// The line settings of `port` are left alone; the read timeout, reset
+    /// and clearing options still apply.
+    pub fn from_port(
+        self,
+        mut port: Box<dyn SerialPort>,
+    ) -> Result<DobotCommandSender, DobotError> {
+        port.set_timeout(self.read_timeout)?;
+        if let Some(settle) = self.reset_settle {
+            port.write_data_terminal_ready(false)?;
+            port.write_request_to_send(false)?;
+            thread::sleep(RESET_PULSE);
+            port.write_data_terminal_ready(true)?;
+            port.write_request_to_send(true)?;
+            thread::sleep(settle);
+        }
+        if self.clear_on_open {
+            port.clear(ClearBuffer::All)?;
+        }
+        Ok(DobotCommandSender::from_transport(
+            Transport::Serial(port),
+            self.timeouts,
+        ))
+    }
+
+    /// Talks over any byte stream, such as a pty, a TCP bridge or a test rig.
+    ///
+    /// Only the command timeouts apply; line settings, resetting and
+    /// clearing are up to whoever set up the stream.
+    pub fn from_stream(self, stream: impl SerialStream + 'static) -> DobotCommandSender {
+        DobotCommandSender::from_transport(Transport::Stream(Box::new(stream)), self.timeouts)
+    }
+}
+
+#[cfg(unix)]
+fn open_port(
+    builder: SerialPortBuilder,
+    exclusive: bool,
+) -> serialport::Result<Box<dyn SerialPort>> {
+    let mut port = builder.open_native()?;
+    port.set_exclusive(exclusive)?;
+    Ok(Box::new(port))
+}
+
+#[cfg(not(unix))]
+fn open_port(
+    builder: SerialPortBuilder,
+    _exclusive: bool,
+) -> serialport::Result<Box<dyn SerialPort>> {
+    builder.open()
+}
@@ -0,0 +1,284 @@
+mod builder;
+mod test;
+
+pub use builder::DobotCommandSenderBuilder;
+
+use core::fmt::Debug;
+use std::{
+    io::{ErrorKind, Read, Write},
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use serialport::SerialPort;
+
+use crate::dobot::dobot_trait::{
+    dobot_core::{
+        command_sender::{CommandSender, pipeline::PipelinedSender},
+        dobot_error::DobotError,
+    },
+    protocol::{COMMAND_ID_OFFSET, ProtocolError},
+};
+
+/// Timeout used for every command class unless the builder overrides it.
+pub const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Start bytes and length byte that precede the content of a frame.
+const FRAME_HEADER_SIZE: usize = 3;
+
+/// Pause between reads of a stream that reports it has no data yet.
+const WOULD_BLOCK_BACKOFF: Duration = Duration::from_millis(1);
+
+/// Any byte stream a [`DobotCommandSender`] can talk over.
+pub trait SerialStream: Read + Write + Send {}
+
+impl<T: Read + Write + Send> SerialStream for T {}
+
+/// The kind of command carried by a request, read from its control byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandClass {
+    /// An immediate command that only reads from the arm.
+    Query,
+    /// An immediate command that writes a setting.
+    Setting,
+    /// A command placed in the command queue.
+    Queued,
+}
+
+impl CommandClass {
+    /// Classifies an encoded request packet.
+    pub fn of_packet(packet: &[u8]) -> Self {
+        let ctrl_byte = packet.get(4).copied().unwrap_or(0);
+        if ctrl_byte & 0x01 != 0 {
+            CommandClass::Queued
+        } else if ctrl_byte & 0x02 != 0 {
+            CommandClass::Setting
+        } else {
+            CommandClass::Query
+        }
+    }
+}
+
+/// How long a whole command may take, from writing the request until the
+/// last byte of the response arrived, for each [`CommandClass`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandTimeouts {
+    pub query: Duration,
+    pub setting: Duration,
+    pub queued: Duration,
+}
+
+impl CommandTimeouts {
+    pub fn get(&self, class: CommandClass) -> Duration {
+        match class {
+            CommandClass::Query => self.query,
+            CommandClass::Setting => self.setting,
+            CommandClass::Queued => self.queued,
+        }
+    }
+
+    pub fn set(&mut self, class: CommandClass, timeout: Duration) {
+        match class {
+            CommandClass::Query => self.query = timeout,
+            CommandClass::Setting => self.setting = timeout,
+            CommandClass::Queued => self.queued = timeout,
+        }
+    }
+}
+
+impl Default for CommandTimeouts {
+    fn default() -> Self {
+        Self {
+            query: DEFAULT_COMMAND_TIMEOUT,
+            setting: DEFAULT_COMMAND_TIMEOUT,
+            queued: DEFAULT_COMMAND_TIMEOUT,
+        }
+    }
+}
+
+enum Transport {
+    Serial(Box<dyn SerialPort>),
+    Stream(Box<dyn SerialStream>),
+}
+
+impl Transport {
+    fn io(&mut self) -> &mut dyn SerialStream {
+        match self {
+            Transport::Serial(port) => port,
+            Transport::Stream(stream) => stream,
+        }
+    }
+}
+
+impl Debug for Transport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Transport::Serial(port) => port.fmt(f),
+            Transport::Stream(_) => f.write_str("Stream"),
+        }
+    }
+}
+
+/// Sends commands over a serial port or any other byte stream.
+///
+/// Use [`DobotCommandSender::builder`] to configure the port; [`DobotCommandSender::new`]
+/// opens it with the defaults of the arm's USB bridge.
+pub struct DobotCommandSender {
+    port: Arc<Mutex<Transport>>,
+    timeouts: CommandTimeouts,
+}
+
+impl DobotCommandSender {
+    /// Opens `port_name` at 115200 baud, 8N1, and discards anything left in
+    /// the port buffers.
+    pub fn new(port_name: &str) -> Result<Self, DobotError> {
+        Self::builder().open(port_name)
+    }
+
+    pub fn builder() -> DobotCommandSenderBuilder {
+        DobotCommandSenderBuilder::new()
+    }
+
+    pub fn timeouts(&self) -> CommandTimeouts {
+        self.timeouts
+    }
+
+    fn from_transport(transport: Transport, timeouts: CommandTimeouts) -> Self {
+        Self {
+            port: Arc::new(Mutex::new(transport)),
+            timeouts,
+        }
+    }
+}
+
+impl Debug for DobotCommandSender {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DobotCommandSender")
+            .field("port", &self.port)
+            .field("timeouts", &self.timeouts)
+            .finish()
+    }
+}
+
+/// Reads one frame into `buffer`, skipping bytes that precede the start
+/// bytes, and returns its length.
+///
+/// Only the bytes of a single frame are consumed, so a late reply to an
+/// earlier command stays in the stream instead of being merged.
+fn read_frame(
+    stream: &mut dyn SerialStream,
+    buffer: &mut [u8],
+    deadline: Instant,
+) -> Result<usize, DobotError> {
+    if buffer.len() < FRAME_HEADER_SIZE {
+        return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
+    }
+
+    let mut filled = 0;
+    loop {
+        // Drop leading bytes that cannot begin a frame.
+        let start = match buffer[..filled].windows(2).position(|w| w == [0xAA, 0xAA]) {
+            Some(start) => start,
+            None if filled > 0 && buffer[filled - 1] == 0xAA => filled - 1,
+            None => filled,
+        };
+        if start > 0 {
+            buffer.copy_within(start..filled, 0);
+            filled -= start;
+        }
+
+        let wanted = if filled < FRAME_HEADER_SIZE {
+            FRAME_HEADER_SIZE
+        } else {
+            FRAME_HEADER_SIZE + buffer[2] as usize + 1
+        };
+        if wanted > buffer.len() {
+            return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
+        }
+        if filled == wanted && filled > FRAME_HEADER_SIZE {
+            return Ok(filled);
+        }
+
+        if Instant::now() >= deadline {
+            return Err(DobotError::Timeout);
+        }
+        match stream.read(&mut buffer[filled..wanted]) {
+            Ok(0) => return Err(DobotError::NoResponse),
+            Ok(read) => filled += read,
+            Err(e) if e.kind() == ErrorKind::WouldBlock => thread::sleep(WOULD_BLOCK_BACKOFF),
+            Err(e) if matches!(e.kind(), ErrorKind::TimedOut | ErrorKind::Interrupted) => {}
+            Err(_e) => return Err(DobotError::IO),
+        }
+    }
+}
+
+impl CommandSender for DobotCommandSender {
+    fn send_raw_packet(
+        &mut self,
+        request_packet: &[u8],
+        response_buffer: &mut [u8],
+    ) -> Result<usize, DobotError> {
+        let mut transport = match self.port.lock() {
+            Ok(x) => x,
+            Err(_) => return Err(DobotError::SenderPoisoned),
+        };
+        let deadline = Instant::now() + self.timeouts.get(CommandClass::of_packet(request_packet));
+
+        let stream = transport.io();
+        stream
+            .write_all(request_packet)
+            .and_then(|_| stream.flush())
+            .map_err(|_e| DobotError::IO)?;
+
+        // A reply that arrived after an earlier command timed out is still in
+        // the stream; skip it and keep waiting for the reply to this request.
+        let expected = request_packet.get(COMMAND_ID_OFFSET);
+        loop {
+            let len = read_frame(stream, response_buffer, deadline)?;
+            if response_buffer.get(COMMAND_ID_OFFSET) == expected {
+                return Ok(len);
+            }
+        }
+    }
+
+    fn get_status_str(&self, buffer: &mut [u8]) -> Result<usize, DobotError>
+    where
+        Self: core::fmt::Debug,
+    {
+        let message = format!("Command serial sender: {:?}", self);
+        if buffer.len() < message.len() {
+            return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
+        }
+        buffer[..message.len()].copy_from_slice(message.as_bytes());
+
+        Ok(message.len())
+    }
+}
+
+impl PipelinedSender for DobotCommandSender {
+    fn send_packet(&mut self, request_packet: &[u8]) -> Result<(), DobotError> {
+        let mut transport = match self.port.lock() {
+            Ok(x) => x,
+            Err(_) => return Err(DobotError::SenderPoisoned),
+        };
+        let stream = transport.io();
+        stream
+            .write_all(request_packet)
+            .and_then(|_| stream.flush())
+            .map_err(|_e| DobotError::IO)
+    }
+
+    fn receive_packet(
+        &mut self,
+        request_packet: &[u8],
+        response_buffer: &mut [u8],
+    ) -> Result<usize, DobotError> {
+        let mut transport = match self.port.lock() {
+            Ok(x) => x,
+            Err(_) => return Err(DobotError::SenderPoisoned),
+        };
+        let deadline = Instant::now() + self.timeouts.get(CommandClass::of_packet(request_packet));
+        read_frame(transport.io(), response_buffer, deadline)
+    }
+}
@@ -0,0 +1,229 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        io::{self, Read, Write},
+        time::{Duration, Instant},
+    };
+
+    use crate::dobot::{
+        dobot_core_serial::command_sender_serial::{
+            CommandClass, CommandTimeouts, DobotCommandSender,
+        },
+        dobot_trait::{
+            dobot_core::{
+                command_sender::{
+                    CommandSender, Dobot, simulated_command_sender::SimulatedCommandSender,
+                },
+                dobot_error::DobotError,
+            },
+            protocol::{
+                MAX_PACKET_SIZE,
+                bodies::{
+                    tag_empty_body::EmptyBody,
+                    tag_home_cmd::TagHomeCmd,
+                    tag_ptp_cmd::{PTPMode, TagPTPCmd},
+                },
+                command::{GetDeviceVersion, GetPose, SetHomeCmd, SetPtpCmd},
+            },
+        },
+    };
+
+    /// A stand-in for a pty: requests written to it are answered by a
+    /// simulated arm, with `noise` in front of every reply and at most
+    /// `chunk` bytes handed out per read. With `late` set, the next reply is
+    /// held back until the following request is written.
+    struct Rig {
+        sim: SimulatedCommandSender,
+        noise: Vec<u8>,
+        chunk: usize,
+        silent: bool,
+        closed: bool,
+        late: bool,
+        held: Vec<u8>,
+        pending: VecDeque<u8>,
+    }
+
+    impl Rig {
+        fn new(sim: &SimulatedCommandSender) -> Self {
+            Self {
+                sim: sim.clone(),
+                noise: Vec::new(),
+                chunk: usize::MAX,
+                silent: false,
+                closed: false,
+                late: false,
+                held: Vec::new(),
+                pending: VecDeque::new(),
+            }
+        }
+    }
+
+    impl Read for Rig {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.pending.is_empty() {
+                if self.closed {
+                    return Ok(0);
+                }
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+            let count = buf.len().min(self.chunk).min(self.pending.len());
+            for byte in &mut buf[..count] {
+                *byte = self.pending.pop_front().unwrap();
+            }
+            Ok(count)
+        }
+    }
+
+    impl Write for Rig {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if !self.silent {
+                let mut response = [0u8; MAX_PACKET_SIZE];
+                let len = self.sim.send_raw_packet(buf, &mut response).unwrap();
+                self.pending.extend(self.held.drain(..));
+                if self.late {
+                    self.late = false;
+                    self.held.extend(&response[..len]);
+                } else {
+                    self.pending.extend(&self.noise);
+                    self.pending.extend(&response[..len]);
+                }
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn target(x: f32) -> TagPTPCmd {
+        TagPTPCmd {
+            ptp_mode: PTPMode::MovlXyz,
+            x,
+            y: 0.0,
+            z: 20.0,
+            r: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_stream_replies_are_reassembled_and_resynchronised() {
+        let sim = SimulatedCommandSender::new();
+        let mut rig = Rig::new(&sim);
+        rig.noise = vec![0x00, 0xAA, 0x13, 0xAA, 0x55];
+        rig.chunk = 1;
+        let mut dobot = Dobot::new(DobotCommandSender::builder().from_stream(rig));
+
+        let first = dobot.execute_queued::<SetPtpCmd>(target(180.0)).unwrap();
+        let second = dobot.execute_queued::<SetPtpCmd>(target(190.0)).unwrap();
+        assert_eq!(second, first + 1);
+        assert_eq!(dobot.execute::<GetPose>(EmptyBody {}).unwrap(), sim.pose());
+    }
+
+    #[test]
+    fn test_command_timeout_depends_on_class() {
+        let sim = SimulatedCommandSender::new();
+        let mut rig = Rig::new(&sim);
+        rig.silent = true;
+        let mut dobot = Dobot::new(
+            DobotCommandSender::builder()
+                .command_timeout(CommandClass::Query, Duration::from_millis(20))
+                .command_timeout(CommandClass::Queued, Duration::from_millis(200))
+                .from_stream(rig),
+        );
+
+        let started = Instant::now();
+        assert!(matches!(
+            dobot.execute::<GetPose>(EmptyBody {}),
+            Err(DobotError::Timeout)
+        ));
+        assert!(started.elapsed() < Duration::from_millis(200));
+
+        let started = Instant::now();
+        assert!(matches!(
+            dobot.execute_queued::<SetHomeCmd>(TagHomeCmd { reserved: 0 }),
+            Err(DobotError::Timeout)
+        ));
+        assert!(started.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_late_reply_is_skipped_by_the_next_command() {
+        let sim = SimulatedCommandSender::new();
+        let mut rig = Rig::new(&sim);
+        rig.late = true;
+        let mut dobot = Dobot::new(
+            DobotCommandSender::builder()
+                .command_timeout(CommandClass::Query, Duration::from_millis(20))
+                .from_stream(rig),
+        );
+
+        assert!(matches!(
+            dobot.execute::<GetPose>(EmptyBody {}),
+            Err(DobotError::Timeout)
+        ));
+
+        let version = dobot.execute::<GetDeviceVersion>(EmptyBody {}).unwrap();
+        assert_eq!(version.params(), [3, 7, 0]);
+        assert_eq!(dobot.execute::<GetPose>(EmptyBody {}).unwrap(), sim.pose());
+    }
+
+    #[test]
+    fn test_closed_stream_reports_no_response() {
+        let sim = SimulatedCommandSender::new();
+        let mut rig = Rig::new(&sim);
+        rig.silent = true;
+        rig.closed = true;
+        let mut dobot = Dobot::new(DobotCommandSender::builder().from_stream(rig));
+
+        assert!(matches!(
+            dobot.execute::<GetPose>(EmptyBody {}),
+            Err(DobotError::NoResponse)
+        ));
+    }
+
+    #[test]
+    fn test_builder_keeps_default_timeouts() {
+        let sender = DobotCommandSender::builder()
+            .command_timeout(CommandClass::Setting, Duration::from_secs(5))
+            .from_stream(io::empty());
+        let expected = CommandTimeouts {
+            setting: Duration::from_secs(5),
+            ..CommandTimeouts::default()
+        };
+
+        assert_eq!(sender.timeouts(), expected);
+        assert_eq!(expected.get(CommandClass::Query), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_open_retries_until_connect_timeout() {
+        let started = Instant::now();
+        let result = DobotCommandSender::builder()
+            .connect_timeout(Duration::from_millis(250))
+            .open("/dev/does-not-exist-dobot");
+
+        assert!(matches!(result, Err(DobotError::Serial)));
+        assert!(started.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_dropped_pipeline_leaves_the_stream_in_step() {
+        let sim = SimulatedCommandSender::new();
+        let mut rig = Rig::new(&sim);
+        rig.chunk = 3;
+        let mut dobot = Dobot::new(DobotCommandSender::builder().from_stream(rig));
+
+        let mut pipeline = dobot.pipeline::<4>();
+        let first = pipeline.send::<SetPtpCmd>(target(150.0)).unwrap();
+        pipeline.send::<SetPtpCmd>(target(160.0)).unwrap();
+        pipeline.send::<SetPtpCmd>(target(170.0)).unwrap();
+        assert_eq!(first, None);
+        assert_eq!(pipeline.in_flight(), 3);
+        drop(pipeline);
+
+        assert_eq!(dobot.execute::<GetPose>(EmptyBody {}).unwrap(), sim.pose());
+        assert_eq!(sim.pose().x, 170.0);
+    }
+}
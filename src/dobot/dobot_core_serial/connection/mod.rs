@@ -0,0 +1,295 @@
+mod test;
+
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use crate::dobot::{
+    dobot_core_serial::{
+        DobotApiSerialController,
+        command_sender_serial::DobotCommandSender,
+        discovery::{DeviceIdentity, DobotLocation, MAGICIAN_USB_IDS, candidate_ports, probe},
+        profile::RobotProfile,
+        worker::{PeriodicWorker, lock},
+    },
+    dobot_trait::{
+        dobot_core::{
+            command_sender::{CommandSender, Dobot},
+            dobot_error::DobotError,
+            sub_command_dobot::{
+                alarm_control::AlarmControl, device_control::DeviceControl,
+                queue_control::QueueControl,
+            },
+        },
+        protocol::alarm::AlarmSet,
+        rwlock::RwLock,
+    },
+};
+
+/// Creates a fresh command sender each time the arm has to be (re)opened.
+pub trait Connector<T: CommandSender>: Send {
+    fn connect(&mut self) -> Result<T, DobotError>;
+}
+
+impl<T, F> Connector<T> for F
+where
+    T: CommandSender,
+    F: FnMut() -> Result<T, DobotError> + Send,
+{
+    fn connect(&mut self) -> Result<T, DobotError> {
+        self()
+    }
+}
+
+/// Opens the serial port of the arm with a given serial number.
+///
+/// The port path is looked up again on every connect, so the arm is found
+/// even if the operating system assigns it a different path after a replug.
+#[derive(Debug, Clone)]
+pub struct SerialConnector {
+    serial_number: String,
+}
+
+impl SerialConnector {
+    pub fn new(serial_number: &str) -> Self {
+        Self {
+            serial_number: serial_number.to_string(),
+        }
+    }
+}
+
+impl Connector<DobotCommandSender> for SerialConnector {
+    fn connect(&mut self) -> Result<DobotCommandSender, DobotError> {
+        for location in candidate_ports(MAGICIAN_USB_IDS)? {
+            let DobotLocation::Serial { port_name, .. } = location else {
+                continue;
+            };
+            let Ok(sender) = DobotCommandSender::new(&port_name) else {
+                continue;
+            };
+            let dobot = RwLock::new(Dobot::new(sender));
+            let mut buffer = [0u8; 64];
+            let matches = DobotApiSerialController::new(&dobot)
+                .device_controller
+                .get_device_sn(&mut buffer)
+                .is_ok_and(|len| {
+                    String::from_utf8_lossy(&buffer[..len]).trim() == self.serial_number
+                });
+            if matches {
+                // The probe above used the same port, so reopen it for the caller.
+                drop(dobot);
+                return DobotCommandSender::new(&port_name);
+            }
+        }
+        Err(DobotError::DeviceNotFound(self.serial_number.clone()))
+    }
+}
+
+/// What to do with alarms that are already active when the arm is opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlarmPolicy {
+    /// Leave the alarms raised; they are still reported in the handshake.
+    #[default]
+    Keep,
+    /// Clear every active alarm.
+    Clear,
+}
+
+/// Settings applied during the handshake performed on every (re)connect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HandshakeOptions {
+    pub alarm_policy: AlarmPolicy,
+    /// Start the command queue if it was stopped.
+    pub start_queue: bool,
+}
+
+/// The state of the arm as found during a handshake.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandshakeReport {
+    pub identity: DeviceIdentity,
+    /// Index of the last executed queued command.
+    pub queue_index: u64,
+    /// Alarms that were active before the alarm policy was applied.
+    pub alarms: AlarmSet,
+}
+
+/// The outcome of a successful [`ManagedConnection::keepalive`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeepaliveStatus {
+    /// The arm answered with its current tick.
+    Alive(u32),
+    /// The arm had stopped answering and was reopened.
+    Reconnected(HandshakeReport),
+}
+
+/// A connection to one arm that survives the arm being unplugged.
+///
+/// Every (re)connect runs a handshake. After a reconnect the arm must report
+/// the serial number seen on the first connect, and the profile captured with
+/// [`ManagedConnection::capture_profile`] is applied again.
+pub struct ManagedConnection<T: CommandSender, C: Connector<T>> {
+    dobot: RwLock<Dobot<T>>,
+    connector: Mutex<C>,
+    options: HandshakeOptions,
+    serial_number: String,
+    report: Mutex<HandshakeReport>,
+    profile: Mutex<Option<RobotProfile>>,
+    connected: AtomicBool,
+}
+
+impl<T: CommandSender, C: Connector<T>> ManagedConnection<T, C> {
+    /// Connects to the arm, performs the handshake and captures its current
+    /// profile.
+    pub fn open(mut connector: C, options: HandshakeOptions) -> Result<Self, DobotError> {
+        let dobot = RwLock::new(Dobot::new(connector.connect()?));
+        let identity = probe(&dobot)?;
+        let report = handshake(&dobot, identity, &options)?;
+        let profile = RobotProfile::read(&dobot)?;
+
+        Ok(Self {
+            dobot,
+            connector: Mutex::new(connector),
+            options,
+            serial_number: report.identity.serial_number.clone(),
+            report: Mutex::new(report),
+            profile: Mutex::new(Some(profile)),
+            connected: AtomicBool::new(true),
+        })
+    }
+
+    /// Returns the lock shared by every controller of this arm.
+    pub fn dobot(&self) -> &RwLock<Dobot<T>> {
+        &self.dobot
+    }
+
+    /// Creates a controller borrowing this connection.
+    ///
+    /// Controllers stay valid across reconnects.
+    pub fn controller(&self) -> DobotApiSerialController<'_, T> {
+        DobotApiSerialController::new(&self.dobot)
+    }
+
+    pub fn serial_number(&self) -> &str {
+        &self.serial_number
+    }
+
+    /// Returns `false` after a keepalive failed and no reconnect succeeded yet.
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    /// Returns the report of the most recent handshake.
+    pub fn handshake_report(&self) -> HandshakeReport {
+        lock(&self.report).clone()
+    }
+
+    /// Returns the profile that will be applied after a reconnect.
+    pub fn profile(&self) -> Option<RobotProfile> {
+        lock(&self.profile).clone()
+    }
+
+    /// Reads the current profile from the arm and remembers it for the next
+    /// reconnect.
+    pub fn capture_profile(&self) -> Result<(), DobotError> {
+        let profile = RobotProfile::read(&self.dobot)?;
+        *lock(&self.profile) = Some(profile);
+        Ok(())
+    }
+
+    /// Replaces the profile applied after a reconnect without touching the
+    /// arm. `None` disables restoring.
+    pub fn set_profile(&self, profile: Option<RobotProfile>) {
+        *lock(&self.profile) = profile;
+    }
+
+    /// Checks that the arm still answers and reopens it if it does not.
+    pub fn keepalive(&self) -> Result<KeepaliveStatus, DobotError> {
+        if self.is_connected() {
+            match self.controller().device_controller.get_device_time() {
+                Ok(tick) => return Ok(KeepaliveStatus::Alive(tick)),
+                Err(_) => self.connected.store(false, Ordering::SeqCst),
+            }
+        }
+        self.reconnect().map(KeepaliveStatus::Reconnected)
+    }
+
+    /// Opens a new sender through the connector, repeats the handshake and
+    /// applies the remembered profile.
+    ///
+    /// The serial number is checked before the new sender replaces the old
+    /// one, so a different arm is never handshaken or written to.
+    pub fn reconnect(&self) -> Result<HandshakeReport, DobotError> {
+        self.connected.store(false, Ordering::SeqCst);
+        let dobot = RwLock::new(Dobot::new(lock(&self.connector).connect()?));
+        let identity = probe(&dobot)?;
+        if identity.serial_number != self.serial_number {
+            return Err(DobotError::DeviceNotFound(self.serial_number.clone()));
+        }
+        let dobot = dobot.into_inner().map_err(|_| DobotError::SenderPoisoned)?;
+        *self.dobot.write().map_err(|_| DobotError::SenderPoisoned)? = dobot;
+
+        let report = handshake(&self.dobot, identity, &self.options)?;
+        if let Some(profile) = lock(&self.profile).as_ref() {
+            profile.apply(&self.dobot)?;
+        }
+
+        *lock(&self.report) = report.clone();
+        self.connected.store(true, Ordering::SeqCst);
+        Ok(report)
+    }
+}
+
+impl<T, C> ManagedConnection<T, C>
+where
+    T: CommandSender + 'static,
+    C: Connector<T> + 'static,
+{
+    /// Runs [`ManagedConnection::keepalive`] every `interval` on a background
+    /// thread until the returned handle is stopped or dropped.
+    pub fn spawn_keepalive(self: &Arc<Self>, interval: Duration) -> KeepaliveHandle {
+        let connection = Arc::clone(self);
+        KeepaliveHandle {
+            worker: PeriodicWorker::spawn(interval, move || {
+                let _ = connection.keepalive();
+            }),
+        }
+    }
+}
+
+/// Stops the keepalive thread when dropped.
+pub struct KeepaliveHandle {
+    worker: PeriodicWorker,
+}
+
+impl KeepaliveHandle {
+    pub fn stop(mut self) {
+        self.worker.stop();
+    }
+}
+
+fn handshake<T: CommandSender>(
+    dobot: &RwLock<Dobot<T>>,
+    identity: DeviceIdentity,
+    options: &HandshakeOptions,
+) -> Result<HandshakeReport, DobotError> {
+    let mut controller = DobotApiSerialController::new(dobot);
+    let queue_index = controller.queue_controller.get_queued_cmd_current_index()?;
+    let alarms = controller.alarm_controller.get_active_alarms()?;
+
+    if options.alarm_policy == AlarmPolicy::Clear && !alarms.is_empty() {
+        controller.alarm_controller.clear_all_alarms_state()?;
+    }
+    if options.start_queue {
+        controller.queue_controller.set_queued_cmd_start_exec()?;
+    }
+
+    Ok(HandshakeReport {
+        identity,
+        queue_index,
+        alarms,
+    })
+}
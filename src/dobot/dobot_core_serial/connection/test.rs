@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        sync::{Arc, Mutex},
+        thread,
+        time::Duration,
+    };
+
+    use crate::dobot::{
+        dobot_core_serial::connection::{
+            AlarmPolicy, HandshakeOptions, KeepaliveStatus, ManagedConnection,
+        },
+        dobot_trait::{
+            dobot_core::{
+                command_sender::simulated_command_sender::SimulatedCommandSender,
+                dobot_error::DobotError,
+                sub_command_dobot::{jog_control::JOGControl, ptp_control::PTPControl},
+            },
+            protocol::{
+                CommunicationProtocolIDs,
+                alarm::{Alarm, AlarmSet},
+                bodies::{
+                    tag_jog_common_params::TagJOGCommonParams,
+                    tag_ptp_common_params::TagPTPCommonParams,
+                },
+                command_id::DeviceInfoIDs,
+            },
+        },
+    };
+
+    type SimConnector = Box<dyn FnMut() -> Result<SimulatedCommandSender, DobotError> + Send>;
+
+    /// Hands out the given simulators one per connect, then fails.
+    fn connector(sims: Vec<SimulatedCommandSender>) -> SimConnector {
+        let sims = Arc::new(Mutex::new(VecDeque::from(sims)));
+        Box::new(move || sims.lock().unwrap().pop_front().ok_or(DobotError::Serial))
+    }
+
+    fn open(
+        sims: Vec<SimulatedCommandSender>,
+        options: HandshakeOptions,
+    ) -> ManagedConnection<SimulatedCommandSender, SimConnector> {
+        ManagedConnection::open(connector(sims), options).unwrap()
+    }
+
+    #[test]
+    fn test_open_performs_handshake() {
+        let sim = SimulatedCommandSender::with_identity("cell", "SN01");
+        sim.raise_alarm(Alarm::LimitAxis1Pos as u8);
+
+        let connection = open(vec![sim.clone()], HandshakeOptions::default());
+
+        let report = connection.handshake_report();
+        assert_eq!(report.identity.serial_number, "SN01");
+        assert_eq!(report.identity.firmware_version, (3, 7, 0));
+        assert_eq!(report.alarms, AlarmSet::from_iter([Alarm::LimitAxis1Pos]));
+        assert_ne!(sim.with_state(|state| state.alarms), [0u8; 32]);
+        assert!(connection.is_connected());
+        assert!(connection.profile().is_some());
+    }
+
+    #[test]
+    fn test_open_clears_alarms_and_starts_queue() {
+        let sim = SimulatedCommandSender::with_identity("cell", "SN01");
+        sim.raise_alarm(Alarm::LimitAxis1Pos as u8);
+        sim.with_state(|state| state.queue_running = false);
+
+        let options = HandshakeOptions {
+            alarm_policy: AlarmPolicy::Clear,
+            start_queue: true,
+        };
+        let connection = open(vec![sim.clone()], options);
+
+        assert_eq!(
+            connection.handshake_report().alarms,
+            AlarmSet::from_iter([Alarm::LimitAxis1Pos])
+        );
+        assert_eq!(sim.with_state(|state| state.alarms), [0u8; 32]);
+        assert!(sim.with_state(|state| state.queue_running));
+    }
+
+    #[test]
+    fn test_keepalive_alive() {
+        let connection = open(
+            vec![SimulatedCommandSender::with_identity("cell", "SN01")],
+            HandshakeOptions::default(),
+        );
+
+        assert!(matches!(
+            connection.keepalive(),
+            Ok(KeepaliveStatus::Alive(_))
+        ));
+    }
+
+    #[test]
+    fn test_keepalive_reconnects_and_restores_profile() {
+        let first = SimulatedCommandSender::with_identity("cell", "SN01");
+        let replugged = SimulatedCommandSender::with_identity("cell", "SN01");
+        let connection = open(
+            vec![first.clone(), replugged.clone()],
+            HandshakeOptions::default(),
+        );
+
+        let common = TagPTPCommonParams {
+            velocity_ratio: 40.0,
+            acceleration_ratio: 60.0,
+        };
+        let jog = TagJOGCommonParams {
+            velocity_ratio: 25.0,
+            acceleration_ratio: 35.0,
+        };
+        let mut controller = connection.controller();
+        controller
+            .ptp_controller
+            .set_ptp_common_params(common.clone(), false)
+            .unwrap();
+        controller
+            .jog_controller
+            .set_jog_common_params(jog.clone(), false)
+            .unwrap();
+        connection.capture_profile().unwrap();
+
+        first.set_connected(false);
+        let status = connection.keepalive().unwrap();
+
+        assert!(matches!(status, KeepaliveStatus::Reconnected(_)));
+        assert!(connection.is_connected());
+        assert_eq!(
+            controller.ptp_controller.get_ptp_common_params().unwrap(),
+            common
+        );
+        assert_eq!(
+            controller.jog_controller.get_jog_common_params().unwrap(),
+            jog
+        );
+        assert!(!replugged.commands().is_empty());
+    }
+
+    #[test]
+    fn test_reconnect_rejects_other_arm() {
+        let other = SimulatedCommandSender::with_identity("cell", "SN02");
+        other.raise_alarm(Alarm::LimitAxis1Pos as u8);
+        let connection = open(
+            vec![
+                SimulatedCommandSender::with_identity("cell", "SN01"),
+                other.clone(),
+            ],
+            HandshakeOptions {
+                alarm_policy: AlarmPolicy::Clear,
+                start_queue: true,
+            },
+        );
+
+        let result = connection.reconnect();
+
+        assert!(matches!(result, Err(DobotError::DeviceNotFound(sn)) if sn == "SN01"));
+        assert!(!connection.is_connected());
+        assert!(other.commands().iter().all(|command| !command.is_write));
+        assert_ne!(other.with_state(|state| state.alarms), [0u8; 32]);
+    }
+
+    #[test]
+    fn test_keepalive_reconnects_after_protocol_error() {
+        let sim = SimulatedCommandSender::with_identity("cell", "SN01");
+        let connection = open(
+            vec![
+                sim.clone(),
+                SimulatedCommandSender::with_identity("cell", "SN01"),
+            ],
+            HandshakeOptions::default(),
+        );
+
+        sim.pin_reply(
+            CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Time),
+            &[0u8],
+        );
+
+        assert!(matches!(
+            connection.keepalive(),
+            Ok(KeepaliveStatus::Reconnected(_))
+        ));
+        assert!(connection.is_connected());
+    }
+
+    #[test]
+    fn test_keepalive_reports_failed_reconnect() {
+        let sim = SimulatedCommandSender::with_identity("cell", "SN01");
+        let connection = open(vec![sim.clone()], HandshakeOptions::default());
+
+        sim.set_connected(false);
+
+        assert!(matches!(connection.keepalive(), Err(DobotError::Serial)));
+        assert!(!connection.is_connected());
+    }
+
+    #[test]
+    fn test_spawn_keepalive_reconnects_in_background() {
+        let first = SimulatedCommandSender::with_identity("cell", "SN01");
+        let replugged = SimulatedCommandSender::with_identity("cell", "SN01");
+        let connection = Arc::new(open(
+            vec![first.clone(), replugged.clone()],
+            HandshakeOptions::default(),
+        ));
+
+        let handle = connection.spawn_keepalive(Duration::from_millis(5));
+        first.set_connected(false);
+        for _ in 0..200 {
+            if !replugged.commands().is_empty() && connection.is_connected() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        handle.stop();
+
+        assert!(connection.is_connected());
+        assert!(!replugged.commands().is_empty());
+    }
+}
@@ -0,0 +1,242 @@
+mod test;
+
+use std::{
+    net::{SocketAddr, UdpSocket},
+    time::{Duration, Instant},
+};
+
+use serialport::SerialPortType;
+
+use crate::dobot::{
+    dobot_core_serial::{DobotApiSerialController, command_sender_serial::DobotCommandSender},
+    dobot_trait::{
+        dobot_core::{
+            command_sender::{CommandSender, Dobot},
+            dobot_error::DobotError,
+            sub_command_dobot::device_control::DeviceControl,
+        },
+        protocol::{
+            CommunicationProtocolIDs, Protocol, ProtocolError,
+            bodies::{general_response::GeneralResponse, tag_empty_body::EmptyBody},
+            command_id::DeviceInfoIDs,
+        },
+        rwlock::RwLock,
+    },
+};
+
+/// USB vendor and product IDs of the USB-to-serial bridges fitted to the
+/// Dobot Magician: the WCH CH340 and the Silicon Labs CP210x.
+pub const MAGICIAN_USB_IDS: &[(u16, u16)] = &[(0x1A86, 0x7523), (0x10C4, 0xEA60)];
+
+/// UDP port the Magician WiFi module forwards protocol packets on.
+pub const MAGICIAN_WIFI_PORT: u16 = 8899;
+
+/// Where a discovered arm can be reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DobotLocation {
+    /// A serial port, with the USB vendor and product ID of its bridge if known.
+    Serial {
+        port_name: String,
+        usb_id: Option<(u16, u16)>,
+    },
+    /// An arm attached through the WiFi module.
+    Udp(SocketAddr),
+}
+
+/// What an arm reports about itself when probed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceIdentity {
+    pub serial_number: String,
+    pub name: String,
+    pub firmware_version: (u8, u8, u8),
+    pub with_rail: bool,
+}
+
+/// An arm found by one of the discovery functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredDobot {
+    pub location: DobotLocation,
+    pub identity: DeviceIdentity,
+}
+
+/// Queries the serial number, name, firmware version and rail capability of
+/// the arm behind `dobot`.
+pub fn probe<T: CommandSender>(dobot: &RwLock<Dobot<T>>) -> Result<DeviceIdentity, DobotError> {
+    let mut device_control = DobotApiSerialController::new(dobot).device_controller;
+    let mut buffer = [0u8; 64];
+
+    let len = device_control.get_device_sn(&mut buffer)?;
+    let serial_number = String::from_utf8_lossy(&buffer[..len]).trim().to_string();
+    let len = device_control.get_device_name(&mut buffer)?;
+    let name = String::from_utf8_lossy(&buffer[..len]).trim().to_string();
+    let firmware_version = device_control.get_device_version()?;
+    let with_rail = device_control.get_device_rail_capability()?;
+
+    Ok(DeviceIdentity {
+        serial_number,
+        name,
+        firmware_version,
+        with_rail,
+    })
+}
+
+/// Lists the serial ports whose USB vendor and product ID appear in `usb_ids`.
+///
+/// Ports that are not USB devices are never returned.
+pub fn candidate_ports(usb_ids: &[(u16, u16)]) -> Result<Vec<DobotLocation>, DobotError> {
+    let ports = serialport::available_ports()?;
+    Ok(ports
+        .into_iter()
+        .filter_map(|port| match port.port_type {
+            SerialPortType::UsbPort(info) if usb_ids.contains(&(info.vid, info.pid)) => {
+                Some(DobotLocation::Serial {
+                    port_name: port.port_name,
+                    usb_id: Some((info.vid, info.pid)),
+                })
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+/// Opens `port_name` and probes the arm behind it.
+pub fn probe_port(port_name: &str) -> Result<DeviceIdentity, DobotError> {
+    let dobot = RwLock::new(Dobot::new(DobotCommandSender::new(port_name)?));
+    probe(&dobot)
+}
+
+/// Probes every serial port with a Magician USB bridge and returns the arms
+/// that answered.
+///
+/// Ports that cannot be opened or do not answer are skipped, since another
+/// device may use the same bridge chip.
+pub fn discover_serial() -> Result<Vec<DiscoveredDobot>, DobotError> {
+    let candidates = candidate_ports(MAGICIAN_USB_IDS)?;
+    Ok(candidates
+        .into_iter()
+        .filter_map(|location| {
+            let DobotLocation::Serial { port_name, .. } = &location else {
+                return None;
+            };
+            let identity = probe_port(port_name).ok()?;
+            Some(DiscoveredDobot { location, identity })
+        })
+        .collect())
+}
+
+/// Broadcasts a serial number query to `target` and probes every arm that
+/// answers within `timeout`.
+///
+/// `target` is usually the broadcast address of the local network on
+/// [`MAGICIAN_WIFI_PORT`], e.g. `255.255.255.255:8899`.
+pub fn discover_udp(
+    target: SocketAddr,
+    timeout: Duration,
+) -> Result<Vec<DiscoveredDobot>, DobotError> {
+    let socket = UdpSocket::bind(unspecified_addr(&target)).map_err(|_e| DobotError::IO)?;
+    socket.set_broadcast(true).map_err(|_e| DobotError::IO)?;
+
+    let mut request = [0u8; 16];
+    let len = Protocol::new(
+        CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Sn),
+        false,
+        false,
+        EmptyBody {},
+    )
+    .to_packet(&mut request)?;
+    socket
+        .send_to(&request[..len], target)
+        .map_err(|_e| DobotError::IO)?;
+
+    let mut responders: Vec<SocketAddr> = Vec::new();
+    let deadline = Instant::now() + timeout;
+    let mut buffer = [0u8; 256];
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        socket
+            .set_read_timeout(Some(remaining))
+            .map_err(|_e| DobotError::IO)?;
+        let Ok((len, addr)) = socket.recv_from(&mut buffer) else {
+            break;
+        };
+        let is_sn_reply =
+            Protocol::<GeneralResponse>::from_packet(&buffer[..len]).is_ok_and(|packet| {
+                packet.command_id == CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Sn)
+            });
+        if is_sn_reply && !responders.contains(&addr) {
+            responders.push(addr);
+        }
+    }
+
+    Ok(responders
+        .into_iter()
+        .filter_map(|addr| {
+            let sender = UdpCommandSender::new(addr, timeout).ok()?;
+            let identity = probe(&RwLock::new(Dobot::new(sender))).ok()?;
+            Some(DiscoveredDobot {
+                location: DobotLocation::Udp(addr),
+                identity,
+            })
+        })
+        .collect())
+}
+
+fn unspecified_addr(target: &SocketAddr) -> SocketAddr {
+    match target {
+        SocketAddr::V4(_) => SocketAddr::from(([0, 0, 0, 0], 0)),
+        SocketAddr::V6(_) => SocketAddr::from(([0u16; 8], 0)),
+    }
+}
+
+/// Sends each packet as one datagram to a single arm and waits for its reply.
+#[derive(Debug)]
+struct UdpCommandSender {
+    socket: UdpSocket,
+    target: SocketAddr,
+}
+
+impl UdpCommandSender {
+    fn new(target: SocketAddr, timeout: Duration) -> Result<Self, DobotError> {
+        let socket = UdpSocket::bind(unspecified_addr(&target)).map_err(|_e| DobotError::IO)?;
+        socket
+            .set_read_timeout(Some(timeout))
+            .map_err(|_e| DobotError::IO)?;
+        Ok(Self { socket, target })
+    }
+}
+
+impl CommandSender for UdpCommandSender {
+    fn send_raw_packet(
+        &mut self,
+        request_packet: &[u8],
+        response_buffer: &mut [u8],
+    ) -> Result<usize, DobotError> {
+        self.socket
+            .send_to(request_packet, self.target)
+            .map_err(|_e| DobotError::IO)?;
+        loop {
+            let (len, addr) = self
+                .socket
+                .recv_from(response_buffer)
+                .map_err(|_e| DobotError::Timeout)?;
+            if addr == self.target {
+                return Ok(len);
+            }
+        }
+    }
+
+    fn get_status_str(&self, buffer: &mut [u8]) -> Result<usize, DobotError>
+    where
+        Self: core::fmt::Debug,
+    {
+        let message = format!("Command UDP sender: {:?}", self);
+        if buffer.len() < message.len() {
+            return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
+        }
+        buffer[..message.len()].copy_from_slice(message.as_bytes());
+
+        Ok(message.len())
+    }
+}
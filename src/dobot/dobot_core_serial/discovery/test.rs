@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use std::{net::UdpSocket, thread, time::Duration};
+
+    use crate::dobot::{
+        dobot_core_serial::discovery::{
+            DeviceIdentity, DobotLocation, MAGICIAN_USB_IDS, discover_udp, probe,
+        },
+        dobot_trait::{
+            dobot_core::{
+                command_sender::{
+                    CommandSender, Dobot, simulated_command_sender::SimulatedCommandSender,
+                },
+                dobot_error::DobotError,
+            },
+            rwlock::RwLock,
+        },
+    };
+
+    /// Answers UDP datagrams from a simulated arm until `requests` have been served.
+    fn spawn_udp_arm(sim: SimulatedCommandSender, requests: usize) -> std::net::SocketAddr {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = socket.local_addr().unwrap();
+        thread::spawn(move || {
+            let mut sim = sim;
+            let mut request = [0u8; 256];
+            let mut response = [0u8; 256];
+            for _ in 0..requests {
+                let (len, from) = socket.recv_from(&mut request).unwrap();
+                let len = sim.send_raw_packet(&request[..len], &mut response).unwrap();
+                socket.send_to(&response[..len], from).unwrap();
+            }
+        });
+        addr
+    }
+
+    #[test]
+    fn test_magician_usb_ids() {
+        assert!(MAGICIAN_USB_IDS.contains(&(0x1A86, 0x7523)));
+        assert!(MAGICIAN_USB_IDS.contains(&(0x10C4, 0xEA60)));
+    }
+
+    #[test]
+    fn test_probe_reports_identity() {
+        let sim = SimulatedCommandSender::with_identity("bench", "DM1234");
+        sim.with_state(|state| {
+            state.version = (3, 8, 1);
+            state.with_rail = true;
+        });
+        let dobot = RwLock::new(Dobot::new(sim));
+
+        let identity = probe(&dobot).unwrap();
+
+        assert_eq!(
+            identity,
+            DeviceIdentity {
+                serial_number: "DM1234".to_string(),
+                name: "bench".to_string(),
+                firmware_version: (3, 8, 1),
+                with_rail: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_probe_unresponsive_device() {
+        let sim = SimulatedCommandSender::new();
+        sim.set_connected(false);
+        let dobot = RwLock::new(Dobot::new(sim));
+
+        assert!(matches!(probe(&dobot), Err(DobotError::IO)));
+    }
+
+    #[test]
+    fn test_discover_udp_finds_arm() {
+        let sim = SimulatedCommandSender::with_identity("wifi-arm", "DMW001");
+        // One broadcast query followed by the four probe queries.
+        let addr = spawn_udp_arm(sim, 5);
+
+        let found = discover_udp(addr, Duration::from_millis(300)).unwrap();
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].location, DobotLocation::Udp(addr));
+        assert_eq!(found[0].identity.name, "wifi-arm");
+        assert_eq!(found[0].identity.serial_number, "DMW001");
+        assert_eq!(found[0].identity.firmware_version, (3, 7, 0));
+        assert!(!found[0].identity.with_rail);
+    }
+
+    #[test]
+    fn test_discover_udp_without_answer() {
+        let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = silent.local_addr().unwrap();
+
+        let found = discover_udp(addr, Duration::from_millis(50)).unwrap();
+
+        assert!(found.is_empty());
+    }
+}
@@ -0,0 +1,258 @@
+mod test;
+
+use crate::dobot::{
+    dobot_core_serial::handle::DobotHandle,
+    dobot_trait::{
+        dobot_core::{
+            command_sender::CommandSender,
+            dobot_error::DobotError,
+            sub_command_dobot::{
+                arc_control::ArcControl, cp_control::CPControl, ptp_control::PTPControl,
+                real_time_control::RealTimeControl,
+            },
+        },
+        protocol::bodies::{
+            tag_arc_cmd::{Point, TagARCCmd},
+            tag_cp_cmd::{CPMode, TagCPCmd},
+            tag_pose::TagPose,
+            tag_ptp_cmd::{PTPMode, TagPTPCmd},
+        },
+    },
+};
+
+/// A rigid transform: a rotation of `r` degrees about Z, the axis the `r`
+/// of a pose turns about, followed by a translation.
+///
+/// As a user frame it places a work object in the base frame; as a tool
+/// frame it places the tool tip relative to the flange.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frame {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub r: f32,
+}
+
+impl Default for Frame {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Frame {
+    pub const IDENTITY: Frame = Frame {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        r: 0.0,
+    };
+
+    pub fn new(x: f32, y: f32, z: f32, r: f32) -> Self {
+        Self { x, y, z, r }
+    }
+
+    /// Teaches a frame from three points in its parent frame: its origin, a
+    /// point along its X axis and a point on the positive Y side of it.
+    ///
+    /// The frame can only turn about Z, so the three points must be level
+    /// within `tolerance` mm.
+    pub fn from_points(
+        origin: [f32; 3],
+        on_x: [f32; 3],
+        on_xy: [f32; 3],
+        tolerance: f32,
+    ) -> Result<Self, DobotError> {
+        let (dx, dy) = (on_x[0] - origin[0], on_x[1] - origin[1]);
+        let length = dx.hypot(dy);
+        if length <= tolerance {
+            return Err(DobotError::FrameTeaching(
+                "the X axis point is too close to the origin".to_string(),
+            ));
+        }
+        for (name, point) in [("X axis", on_x), ("XY plane", on_xy)] {
+            if (point[2] - origin[2]).abs() > tolerance {
+                return Err(DobotError::FrameTeaching(format!(
+                    "the {} point is {:.2} mm off the level of the origin",
+                    name,
+                    point[2] - origin[2]
+                )));
+            }
+        }
+        // Distance of the third point from the X axis, positive on its Y side.
+        let side = (dx * (on_xy[1] - origin[1]) - dy * (on_xy[0] - origin[0])) / length;
+        if side <= tolerance {
+            return Err(DobotError::FrameTeaching(
+                "the XY plane point is not on the positive Y side of the X axis".to_string(),
+            ));
+        }
+        Ok(Self::new(
+            origin[0],
+            origin[1],
+            origin[2],
+            dy.atan2(dx).to_degrees(),
+        ))
+    }
+
+    /// Maps `[x, y, z, r]` from this frame to its parent.
+    pub fn to_parent(&self, [x, y, z, r]: [f32; 4]) -> [f32; 4] {
+        let [x, y] = rotate([x, y], self.r);
+        [self.x + x, self.y + y, self.z + z, self.r + r]
+    }
+
+    /// Maps `[x, y, z, r]` from the parent of this frame to the frame.
+    pub fn from_parent(&self, [x, y, z, r]: [f32; 4]) -> [f32; 4] {
+        let [x, y] = rotate([x - self.x, y - self.y], -self.r);
+        [x, y, z - self.z, r - self.r]
+    }
+
+    /// Maps a displacement, which the translation does not apply to, from
+    /// this frame to its parent.
+    pub fn delta_to_parent(&self, [x, y, z, r]: [f32; 4]) -> [f32; 4] {
+        let [x, y] = rotate([x, y], self.r);
+        [x, y, z, r]
+    }
+}
+
+/// Turns `[x, y]` by `degrees` about the origin.
+pub(crate) fn rotate([x, y]: [f32; 2], degrees: f32) -> [f32; 2] {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    [x * cos - y * sin, x * sin + y * cos]
+}
+
+/// An arm driven in a user frame with a tool frame.
+///
+/// Cartesian targets are given for the tool tip in the user frame and are
+/// converted to the flange in the base frame before they are sent; reported
+/// poses are converted back. The tool frame comes on top of the end effector
+/// bias set in the firmware. Points kept in a user frame stay valid when
+/// the frame is taught again after the work object has moved.
+pub struct FramedArm<T: CommandSender + 'static> {
+    arm: DobotHandle<T>,
+    pub user: Frame,
+    pub tool: Frame,
+}
+
+impl<T: CommandSender + 'static> FramedArm<T> {
+    /// Drives `arm` in the base frame without a tool until frames are set.
+    pub fn new(arm: DobotHandle<T>) -> Self {
+        Self::with_frames(arm, Frame::IDENTITY, Frame::IDENTITY)
+    }
+
+    pub fn with_frames(arm: DobotHandle<T>, user: Frame, tool: Frame) -> Self {
+        Self { arm, user, tool }
+    }
+
+    /// The flange pose in the base frame that puts the tool tip at `pose`
+    /// in the user frame.
+    pub fn to_base(&self, pose: [f32; 4]) -> [f32; 4] {
+        let [x, y, z, r] = self.user.to_parent(pose);
+        let flange_r = r - self.tool.r;
+        let [tool_x, tool_y] = rotate([self.tool.x, self.tool.y], flange_r);
+        [x - tool_x, y - tool_y, z - self.tool.z, flange_r]
+    }
+
+    /// The tool tip pose in the user frame of the flange at `pose` in the
+    /// base frame.
+    pub fn from_base(&self, pose: [f32; 4]) -> [f32; 4] {
+        self.user.from_parent(self.tip_in_base(pose))
+    }
+
+    /// The tool tip pose in the base frame of the flange at `pose`.
+    fn tip_in_base(&self, [x, y, z, r]: [f32; 4]) -> [f32; 4] {
+        Frame::new(x, y, z, r).to_parent([self.tool.x, self.tool.y, self.tool.z, self.tool.r])
+    }
+
+    /// Converts a PTP command to the base frame.
+    ///
+    /// Joint moves are left alone. Incremental Cartesian moves are turned
+    /// into the base frame but the tool is not applied to them, so turning
+    /// `r` with one pivots about the flange.
+    pub fn ptp_to_base(&self, cmd: &TagPTPCmd) -> TagPTPCmd {
+        let target = [cmd.x, cmd.y, cmd.z, cmd.r];
+        let [x, y, z, r] = match cmd.ptp_mode {
+            PTPMode::JumpXyz | PTPMode::MovjXyz | PTPMode::MovlXyz | PTPMode::JumpMovlXyz => {
+                self.to_base(target)
+            }
+            PTPMode::MovlInc | PTPMode::MovjXyzInc => self.user.delta_to_parent(target),
+            PTPMode::JumpAngle | PTPMode::MovjAngle | PTPMode::MovlAngle | PTPMode::MovjInc => {
+                target
+            }
+        };
+        TagPTPCmd {
+            ptp_mode: cmd.ptp_mode,
+            x,
+            y,
+            z,
+            r,
+        }
+    }
+
+    /// Converts a CP command to the base frame. CP commands carry no `r`,
+    /// so the rotation `r` of the tool in the user frame during the move
+    /// has to be given.
+    pub fn cp_to_base(&self, cmd: &TagCPCmd, r: f32) -> TagCPCmd {
+        let [x, y, z, _] = match cmd.cp_mode {
+            CPMode::Absolute => self.to_base([cmd.x, cmd.y, cmd.z, r]),
+            CPMode::Relative => self.user.delta_to_parent([cmd.x, cmd.y, cmd.z, 0.0]),
+        };
+        TagCPCmd { x, y, z, ..*cmd }
+    }
+
+    /// Converts both points of an ARC command to the base frame.
+    pub fn arc_to_base(&self, cmd: &TagARCCmd) -> TagARCCmd {
+        let point = |p: &Point| {
+            let [x, y, z, r] = self.to_base([p.x, p.y, p.z, p.r]);
+            Point { x, y, z, r }
+        };
+        TagARCCmd {
+            circ_point: point(&cmd.circ_point),
+            to_point: point(&cmd.to_point),
+        }
+    }
+
+    /// Reads the pose of the tool tip in the user frame. The joint angles
+    /// are the reported ones.
+    pub fn pose(&self) -> Result<TagPose, DobotError> {
+        let pose = self.arm.controller().realtime_controller.get_pose()?;
+        let [x, y, z, r] = self.from_base([pose.x, pose.y, pose.z, pose.r]);
+        Ok(TagPose {
+            x,
+            y,
+            z,
+            r,
+            joint_angle: pose.joint_angle,
+        })
+    }
+
+    /// Reads where the tool tip is in the base frame, for teaching a user
+    /// frame with [`Frame::from_points`].
+    pub fn tip(&self) -> Result<[f32; 3], DobotError> {
+        let pose = self.arm.controller().realtime_controller.get_pose()?;
+        let [x, y, z, _] = self.tip_in_base([pose.x, pose.y, pose.z, pose.r]);
+        Ok([x, y, z])
+    }
+
+    pub fn ptp(&self, cmd: &TagPTPCmd, is_queued: bool) -> Result<Option<u64>, DobotError> {
+        self.arm
+            .controller()
+            .ptp_controller
+            .set_ptp_cmd(self.ptp_to_base(cmd), is_queued)
+    }
+
+    /// Sends a CP command with the tool held at rotation `r` in the user
+    /// frame.
+    pub fn cp(&self, cmd: &TagCPCmd, r: f32, is_queued: bool) -> Result<Option<u64>, DobotError> {
+        self.arm
+            .controller()
+            .cp_controller
+            .set_cp_cmd(self.cp_to_base(cmd, r), is_queued)
+    }
+
+    pub fn arc(&self, cmd: &TagARCCmd, is_queued: bool) -> Result<Option<u64>, DobotError> {
+        self.arm
+            .controller()
+            .arc_controller
+            .set_arc_cmd(self.arc_to_base(cmd), is_queued)
+    }
+}
@@ -0,0 +1,216 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::{
+        dobot_core_serial::{
+            frames::{Frame, FramedArm},
+            handle::DobotHandle,
+        },
+        dobot_trait::{
+            dobot_core::{
+                command_sender::simulated_command_sender::SimulatedCommandSender,
+                dobot_error::DobotError,
+            },
+            protocol::{
+                bodies::{
+                    tag_arc_cmd::{Point, TagARCCmd},
+                    tag_cp_cmd::{CPMode, TagCPCmd},
+                    tag_ptp_cmd::{PTPMode, TagPTPCmd},
+                },
+                command::{Command, SetArcCmd, SetCpCmd},
+            },
+        },
+    };
+
+    fn assert_close(actual: [f32; 4], expected: [f32; 4]) {
+        assert!(
+            actual
+                .iter()
+                .zip(expected)
+                .all(|(a, e)| (a - e).abs() < 1e-3),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    fn floats(params: &[u8]) -> Vec<f32> {
+        params
+            .chunks_exact(4)
+            .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+            .collect()
+    }
+
+    fn movl(x: f32, y: f32, z: f32, r: f32) -> TagPTPCmd {
+        TagPTPCmd {
+            ptp_mode: PTPMode::MovlXyz,
+            x,
+            y,
+            z,
+            r,
+        }
+    }
+
+    #[test]
+    fn test_frame_taught_from_three_points() {
+        let frame = Frame::from_points(
+            [200.0, 0.0, -40.0],
+            [200.0, 50.0, -40.0],
+            [150.0, 20.0, -40.2],
+            0.5,
+        )
+        .unwrap();
+
+        assert_close(
+            [frame.x, frame.y, frame.z, frame.r],
+            [200.0, 0.0, -40.0, 90.0],
+        );
+        assert_close(
+            frame.to_parent([10.0, 0.0, 0.0, 0.0]),
+            [200.0, 10.0, -40.0, 90.0],
+        );
+        assert_close(
+            frame.from_parent(frame.to_parent([3.0, -4.0, 5.0, 6.0])),
+            [3.0, -4.0, 5.0, 6.0],
+        );
+    }
+
+    #[test]
+    fn test_bad_teaching_points_are_refused() {
+        let origin = [200.0, 0.0, 0.0];
+        let cases = [
+            ([200.1, 0.0, 0.0], [200.0, 30.0, 0.0], "too close"),
+            ([250.0, 0.0, 3.0], [200.0, 30.0, 0.0], "X axis point"),
+            ([250.0, 0.0, 0.0], [200.0, -30.0, 0.0], "positive Y side"),
+        ];
+
+        for (on_x, on_xy, reason) in cases {
+            match Frame::from_points(origin, on_x, on_xy, 0.5) {
+                Err(DobotError::FrameTeaching(message)) => {
+                    assert!(message.contains(reason), "{}", message)
+                }
+                other => panic!("expected a teaching error, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_moves_follow_a_retaught_fixture() {
+        let sim = SimulatedCommandSender::new();
+        let mut arm = FramedArm::with_frames(
+            DobotHandle::new(sim.clone()),
+            Frame::new(200.0, -50.0, -20.0, 0.0),
+            Frame::new(30.0, 0.0, -10.0, 0.0),
+        );
+
+        arm.ptp(&movl(10.0, 20.0, 0.0, 0.0), false).unwrap();
+        let pose = sim.pose();
+        assert_close([pose.x, pose.y, pose.z, pose.r], [180.0, -30.0, -10.0, 0.0]);
+        let [x, y, z] = arm.tip().unwrap();
+        assert_close([x, y, z, 0.0], [210.0, -30.0, -20.0, 0.0]);
+
+        // The fixture was turned by 90° about its origin.
+        arm.user.r = 90.0;
+        arm.ptp(&movl(10.0, 20.0, 0.0, 0.0), false).unwrap();
+        let pose = sim.pose();
+        assert_close(
+            [pose.x, pose.y, pose.z, pose.r],
+            [180.0, -70.0, -10.0, 90.0],
+        );
+
+        let reported = arm.pose().unwrap();
+        assert_close(
+            [reported.x, reported.y, reported.z, reported.r],
+            [10.0, 20.0, 0.0, 0.0],
+        );
+    }
+
+    #[test]
+    fn test_tool_rotation_is_kept_off_the_flange() {
+        let arm = FramedArm::with_frames(
+            DobotHandle::new(SimulatedCommandSender::new()),
+            Frame::IDENTITY,
+            Frame::new(0.0, 40.0, 0.0, 15.0),
+        );
+
+        let flange = arm.to_base([250.0, 0.0, 0.0, 105.0]);
+
+        assert_close(flange, [290.0, 0.0, 0.0, 90.0]);
+        assert_close(arm.from_base(flange), [250.0, 0.0, 0.0, 105.0]);
+    }
+
+    #[test]
+    fn test_joint_and_incremental_moves() {
+        let arm = FramedArm::with_frames(
+            DobotHandle::new(SimulatedCommandSender::new()),
+            Frame::new(100.0, 100.0, 0.0, 90.0),
+            Frame::new(20.0, 0.0, 0.0, 0.0),
+        );
+        let joint = TagPTPCmd {
+            ptp_mode: PTPMode::MovjAngle,
+            ..movl(10.0, 20.0, 30.0, 40.0)
+        };
+        let step = TagPTPCmd {
+            ptp_mode: PTPMode::MovlInc,
+            ..movl(5.0, 0.0, -1.0, 0.0)
+        };
+
+        assert_eq!(arm.ptp_to_base(&joint), joint);
+        let step = arm.ptp_to_base(&step);
+        assert_close([step.x, step.y, step.z, step.r], [0.0, 5.0, -1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_cp_and_arc_points_are_converted() {
+        let sim = SimulatedCommandSender::new();
+        let arm = FramedArm::with_frames(
+            DobotHandle::new(sim.clone()),
+            Frame::new(200.0, 0.0, 10.0, 90.0),
+            Frame::IDENTITY,
+        );
+
+        arm.cp(
+            &TagCPCmd {
+                cp_mode: CPMode::Absolute,
+                x: 10.0,
+                y: 0.0,
+                z: 0.0,
+                velocity_or_power: 50.0,
+            },
+            0.0,
+            false,
+        )
+        .unwrap();
+        let point = |x, y| Point {
+            x,
+            y,
+            z: 0.0,
+            r: 0.0,
+        };
+        arm.arc(
+            &TagARCCmd {
+                circ_point: point(10.0, 10.0),
+                to_point: point(0.0, 20.0),
+            },
+            false,
+        )
+        .unwrap();
+
+        let commands = sim.commands();
+        let cp = commands
+            .iter()
+            .find(|c| c.id == u8::from(SetCpCmd::ID))
+            .unwrap();
+        assert_eq!(cp.params[0], CPMode::Absolute as u8);
+        assert_close(
+            floats(&cp.params[1..]).try_into().unwrap(),
+            [200.0, 10.0, 10.0, 50.0],
+        );
+        let arc = commands
+            .iter()
+            .find(|c| c.id == u8::from(SetArcCmd::ID))
+            .unwrap();
+        let arc = floats(&arc.params);
+        assert_close(arc[..4].try_into().unwrap(), [190.0, 10.0, 10.0, 90.0]);
+        assert_close(arc[4..].try_into().unwrap(), [180.0, 0.0, 10.0, 90.0]);
+    }
+}
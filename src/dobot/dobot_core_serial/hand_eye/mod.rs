@@ -0,0 +1,241 @@
+mod test;
+
+use crate::dobot::dobot_trait::{
+    dobot_core::dobot_error::DobotError,
+    protocol::bodies::{
+        tag_pose::TagPose,
+        tag_ptp_cmd::{PTPMode, TagPTPCmd},
+    },
+};
+
+type Matrix = [[f64; 3]; 3];
+
+const IDENTITY: Matrix = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// A marker seen by the camera and touched with the arm.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandEyeSample {
+    /// Image coordinates `[u, v]` of the marker.
+    pub pixel: [f32; 2],
+    /// The pose of the arm touching the marker.
+    pub pose: TagPose,
+}
+
+/// How image coordinates map onto the table plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PlaneModel {
+    /// Rotation, scale and shear; enough for a camera looking straight
+    /// down. Needs three samples.
+    Affine,
+    /// Also corrects the perspective of a tilted camera. Needs four
+    /// samples.
+    Homography,
+}
+
+impl PlaneModel {
+    fn unknowns(self) -> usize {
+        match self {
+            PlaneModel::Affine => 6,
+            PlaneModel::Homography => 8,
+        }
+    }
+}
+
+/// A fixed camera calibrated against the table plane of an arm.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandEyeCalibration {
+    pub model: PlaneModel,
+    /// Maps homogeneous image coordinates to homogeneous `[x, y, 1]` on the
+    /// table.
+    pub matrix: [[f64; 3]; 3],
+    /// Height of the table in the base frame, the mean z of the samples.
+    pub table_z: f32,
+    /// Distance in mm between where each sample was touched and where its
+    /// pixel maps to.
+    pub errors: Vec<f32>,
+    /// Root mean square of the errors, in mm.
+    pub rms_error: f32,
+}
+
+impl HandEyeCalibration {
+    /// Fits `model` to the samples by least squares.
+    ///
+    /// Returns [`DobotError::Calibration`] if there are too few samples or
+    /// they do not spread over the plane, such as when they lie on a line.
+    pub fn fit(samples: &[HandEyeSample], model: PlaneModel) -> Result<Self, DobotError> {
+        let needed = model.unknowns() / 2;
+        if samples.len() < needed {
+            return Err(DobotError::Calibration(vec![format!(
+                "{:?} needs at least {} samples, got {}",
+                model,
+                needed,
+                samples.len()
+            )]));
+        }
+
+        let pixels: Vec<[f64; 2]> = samples
+            .iter()
+            .map(|s| [s.pixel[0] as f64, s.pixel[1] as f64])
+            .collect();
+        let points: Vec<[f64; 2]> = samples
+            .iter()
+            .map(|s| [s.pose.x as f64, s.pose.y as f64])
+            .collect();
+        // Both sides are centered and scaled first to keep the equations
+        // well conditioned.
+        let (from_pixels, _) = normalizer(&pixels);
+        let (from_points, to_points) = normalizer(&points);
+
+        let mut rows = Vec::new();
+        let mut values = Vec::new();
+        for (pixel, point) in pixels.iter().zip(&points) {
+            let [u, v] = apply(&from_pixels, *pixel).unwrap_or_default();
+            let [x, y] = apply(&from_points, *point).unwrap_or_default();
+            let mut row_x = vec![u, v, 1.0, 0.0, 0.0, 0.0];
+            let mut row_y = vec![0.0, 0.0, 0.0, u, v, 1.0];
+            if model == PlaneModel::Homography {
+                row_x.extend([-u * x, -v * x]);
+                row_y.extend([-u * y, -v * y]);
+            }
+            rows.extend([row_x, row_y]);
+            values.extend([x, y]);
+        }
+        let h = least_squares(&rows, &values).ok_or_else(|| {
+            DobotError::Calibration(vec!["the samples do not spread over the plane".to_string()])
+        })?;
+        let (h31, h32) = match model {
+            PlaneModel::Affine => (0.0, 0.0),
+            PlaneModel::Homography => (h[6], h[7]),
+        };
+        let fitted = [[h[0], h[1], h[2]], [h[3], h[4], h[5]], [h31, h32, 1.0]];
+
+        let mut calibration = Self {
+            model,
+            matrix: multiply(&multiply(&to_points, &fitted), &from_pixels),
+            table_z: samples.iter().map(|s| s.pose.z).sum::<f32>() / samples.len() as f32,
+            errors: Vec::new(),
+            rms_error: 0.0,
+        };
+        calibration.errors = samples
+            .iter()
+            .map(|s| match calibration.pixel_to_xy(s.pixel) {
+                Some([x, y]) => (x - s.pose.x).hypot(y - s.pose.y),
+                None => f32::INFINITY,
+            })
+            .collect();
+        calibration.rms_error =
+            (calibration.errors.iter().map(|e| e * e).sum::<f32>() / samples.len() as f32).sqrt();
+        Ok(calibration)
+    }
+
+    /// Maps image coordinates to `[x, y]` on the table, or `None` for a
+    /// pixel on the horizon of the plane.
+    pub fn pixel_to_xy(&self, [u, v]: [f32; 2]) -> Option<[f32; 2]> {
+        apply(&self.matrix, [u as f64, v as f64]).map(|[x, y]| [x as f32, y as f32])
+    }
+
+    /// A `JumpXyz` move to the object at `pixel`, `z_offset` mm above the
+    /// table with the end effector at rotation `r`.
+    ///
+    /// The target is not checked for reach; see
+    /// [`Workspace::check`](crate::dobot::dobot_core_serial::homing::Workspace::check).
+    pub fn pick_target(&self, pixel: [f32; 2], z_offset: f32, r: f32) -> Option<TagPTPCmd> {
+        let [x, y] = self.pixel_to_xy(pixel)?;
+        Some(TagPTPCmd {
+            ptp_mode: PTPMode::JumpXyz,
+            x,
+            y,
+            z: self.table_z + z_offset,
+            r,
+        })
+    }
+}
+
+/// A transform centering `points` at the origin with a mean distance of
+/// √2 from it, and its inverse.
+fn normalizer(points: &[[f64; 2]]) -> (Matrix, Matrix) {
+    let count = points.len() as f64;
+    let cx = points.iter().map(|p| p[0]).sum::<f64>() / count;
+    let cy = points.iter().map(|p| p[1]).sum::<f64>() / count;
+    let spread = points
+        .iter()
+        .map(|p| (p[0] - cx).hypot(p[1] - cy))
+        .sum::<f64>()
+        / count;
+    if spread < 1e-9 {
+        return (IDENTITY, IDENTITY);
+    }
+    let scale = 2f64.sqrt() / spread;
+    (
+        [
+            [scale, 0.0, -scale * cx],
+            [0.0, scale, -scale * cy],
+            [0.0, 0.0, 1.0],
+        ],
+        [
+            [1.0 / scale, 0.0, cx],
+            [0.0, 1.0 / scale, cy],
+            [0.0, 0.0, 1.0],
+        ],
+    )
+}
+
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut product = [[0.0; 3]; 3];
+    for (i, row) in product.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    product
+}
+
+/// Applies `matrix` to the point `[x, y]`, or returns `None` if it maps it
+/// to infinity.
+fn apply(matrix: &Matrix, [x, y]: [f64; 2]) -> Option<[f64; 2]> {
+    let [a, b, w] = matrix.map(|row| row[0] * x + row[1] * y + row[2]);
+    (w.abs() > 1e-9).then(|| [a / w, b / w])
+}
+
+/// Solves `rows · x = values` in the least squares sense through the
+/// normal equations, or returns `None` if they are singular.
+fn least_squares(rows: &[Vec<f64>], values: &[f64]) -> Option<Vec<f64>> {
+    let n = rows[0].len();
+    // The augmented normal equations [AᵀA | Aᵀb].
+    let mut system = vec![vec![0.0; n + 1]; n];
+    for (row, value) in rows.iter().zip(values) {
+        for i in 0..n {
+            for j in 0..n {
+                system[i][j] += row[i] * row[j];
+            }
+            system[i][n] += row[i] * value;
+        }
+    }
+
+    for column in 0..n {
+        let pivot = (column..n)
+            .max_by(|&a, &b| system[a][column].abs().total_cmp(&system[b][column].abs()))?;
+        if system[pivot][column].abs() < 1e-9 {
+            return None;
+        }
+        system.swap(column, pivot);
+        let (above, below) = system.split_at_mut(column + 1);
+        let pivot_row = &above[column];
+        for row in below {
+            let factor = row[column] / pivot_row[column];
+            for (cell, pivot) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *cell -= factor * pivot;
+            }
+        }
+    }
+
+    let mut solution = vec![0.0; n];
+    for row in (0..n).rev() {
+        let known: f64 = (row + 1..n).map(|k| system[row][k] * solution[k]).sum();
+        solution[row] = (system[row][n] - known) / system[row][row];
+    }
+    Some(solution)
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::{
+        dobot_core_serial::hand_eye::{HandEyeCalibration, HandEyeSample, PlaneModel},
+        dobot_trait::{
+            dobot_core::dobot_error::DobotError,
+            protocol::bodies::{tag_pose::TagPose, tag_ptp_cmd::PTPMode},
+        },
+    };
+
+    const PIXELS: [[f32; 2]; 6] = [
+        [100.0, 80.0],
+        [520.0, 90.0],
+        [540.0, 400.0],
+        [90.0, 420.0],
+        [300.0, 240.0],
+        [420.0, 150.0],
+    ];
+
+    /// A camera looking straight down: 0.4 mm per pixel, turned by 90°.
+    fn overhead([u, v]: [f32; 2]) -> [f32; 2] {
+        [300.0 - 0.4 * v, -100.0 + 0.4 * u]
+    }
+
+    /// A tilted camera, with the far side of the table foreshortened.
+    fn tilted([u, v]: [f32; 2]) -> [f32; 2] {
+        let w = 1.0 + 0.0008 * v;
+        [(150.0 + 0.5 * v) / w, (-120.0 + 0.45 * u) / w]
+    }
+
+    fn samples(map: fn([f32; 2]) -> [f32; 2]) -> Vec<HandEyeSample> {
+        PIXELS
+            .iter()
+            .map(|&pixel| {
+                let [x, y] = map(pixel);
+                HandEyeSample {
+                    pixel,
+                    pose: TagPose {
+                        x,
+                        y,
+                        z: -42.0,
+                        r: 0.0,
+                        joint_angle: [0.0; 4],
+                    },
+                }
+            })
+            .collect()
+    }
+
+    fn assert_near(actual: [f32; 2], expected: [f32; 2], tolerance: f32) {
+        assert!(
+            (actual[0] - expected[0]).hypot(actual[1] - expected[1]) < tolerance,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_affine_fit_of_an_overhead_camera() {
+        let calibration = HandEyeCalibration::fit(&samples(overhead), PlaneModel::Affine).unwrap();
+
+        assert!(calibration.rms_error < 1e-3, "{}", calibration.rms_error);
+        assert_eq!(calibration.errors.len(), PIXELS.len());
+        assert_eq!(calibration.table_z, -42.0);
+        assert_near(
+            calibration.pixel_to_xy([250.0, 300.0]).unwrap(),
+            overhead([250.0, 300.0]),
+            1e-2,
+        );
+    }
+
+    #[test]
+    fn test_homography_corrects_the_perspective_of_a_tilted_camera() {
+        let affine = HandEyeCalibration::fit(&samples(tilted), PlaneModel::Affine).unwrap();
+        let homography = HandEyeCalibration::fit(&samples(tilted), PlaneModel::Homography).unwrap();
+
+        assert!(affine.rms_error > 1.0, "{}", affine.rms_error);
+        assert!(homography.rms_error < 1e-2, "{}", homography.rms_error);
+        assert_near(
+            homography.pixel_to_xy([330.0, 260.0]).unwrap(),
+            tilted([330.0, 260.0]),
+            1e-2,
+        );
+    }
+
+    #[test]
+    fn test_reprojection_error_shows_a_bad_sample() {
+        let mut samples = samples(overhead);
+        samples[4].pose.x += 4.0;
+
+        let calibration = HandEyeCalibration::fit(&samples, PlaneModel::Affine).unwrap();
+
+        let worst = calibration
+            .errors
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .unwrap();
+        assert_eq!(worst.0, 4);
+        assert!(calibration.rms_error > 0.1);
+    }
+
+    #[test]
+    fn test_unusable_samples_are_refused() {
+        let few = &samples(overhead)[..3];
+        let collinear: Vec<HandEyeSample> = samples(overhead)
+            .into_iter()
+            .enumerate()
+            .map(|(i, mut sample)| {
+                sample.pixel = [100.0 * i as f32, 50.0 * i as f32];
+                sample.pose.x = 200.0 + 10.0 * i as f32;
+                sample.pose.y = 5.0 * i as f32;
+                sample
+            })
+            .collect();
+
+        match HandEyeCalibration::fit(few, PlaneModel::Homography) {
+            Err(DobotError::Calibration(errors)) => {
+                assert!(errors[0].contains("at least 4"), "{:?}", errors)
+            }
+            other => panic!("expected a calibration error, got {:?}", other),
+        }
+        assert!(HandEyeCalibration::fit(few, PlaneModel::Affine).is_ok());
+        for model in [PlaneModel::Affine, PlaneModel::Homography] {
+            assert!(matches!(
+                HandEyeCalibration::fit(&collinear, model),
+                Err(DobotError::Calibration(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_pick_target_jumps_above_the_table() {
+        let calibration = HandEyeCalibration::fit(&samples(overhead), PlaneModel::Affine).unwrap();
+
+        let target = calibration.pick_target([200.0, 100.0], 15.0, 30.0).unwrap();
+
+        assert_eq!(target.ptp_mode, PTPMode::JumpXyz);
+        assert_near([target.x, target.y], overhead([200.0, 100.0]), 1e-2);
+        assert_eq!((target.z, target.r), (-27.0, 30.0));
+    }
+}
@@ -0,0 +1,181 @@
+mod test;
+
+#[cfg(feature = "std")]
+use std::{
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::DobotApiSerialController;
+use crate::dobot::dobot_trait::{
+    dobot_core::{
+        command_sender::{CommandSender, Dobot},
+        dobot_error::DobotError,
+    },
+    protocol::command::{Command, Queueable},
+    rwlock::{Priority, RwLock},
+};
+
+#[cfg(feature = "std")]
+use super::safety::{self, SafeStateOptions, SafeStateReport};
+#[cfg(feature = "std")]
+use crate::dobot::dobot_trait::dobot_core::{
+    command_sender::pipeline::PipelinedSender, sub_command_dobot::queue_control::QueueControl,
+};
+
+/// How often the queue index is read while waiting for a queued command.
+#[cfg(feature = "std")]
+const QUEUE_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// An owned handle to one arm.
+///
+/// Unlike [`DobotApiSerialController`], which borrows the lock around the arm,
+/// a handle owns its share of it: it can be stored in a struct, cloned cheaply
+/// and moved to another thread or task. Every clone talks to the same
+/// transport through a single lock, held only for the duration of a command.
+///
+/// With `std` the lock lives in an [`Arc`]. Without it the application provides
+/// the lock as a `static`.
+pub struct DobotHandle<T: CommandSender + 'static> {
+    #[cfg(feature = "std")]
+    dobot: Arc<RwLock<Dobot<T>>>,
+    #[cfg(not(feature = "std"))]
+    dobot: &'static RwLock<Dobot<T>>,
+}
+
+impl<T: CommandSender + 'static> Clone for DobotHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            dobot: Arc::clone(&self.dobot),
+            #[cfg(not(feature = "std"))]
+            dobot: self.dobot,
+        }
+    }
+}
+
+impl<T: CommandSender + 'static> DobotHandle<T> {
+    /// Creates a handle owning a new arm driven by `sender`.
+    #[cfg(feature = "std")]
+    pub fn new(sender: T) -> Self {
+        Self {
+            dobot: Arc::new(RwLock::new(Dobot::new(sender))),
+        }
+    }
+
+    /// Creates a handle to an arm stored in a `static`.
+    #[cfg(not(feature = "std"))]
+    pub const fn new(dobot: &'static RwLock<Dobot<T>>) -> Self {
+        Self { dobot }
+    }
+
+    /// Returns the lock shared by every clone of this handle.
+    pub fn dobot(&self) -> &RwLock<Dobot<T>> {
+        &self.dobot
+    }
+
+    /// Creates a controller exposing every control trait of the arm.
+    pub fn controller(&self) -> DobotApiSerialController<'_, T> {
+        DobotApiSerialController::new(self.dobot())
+    }
+
+    /// Sends an immediate command and returns its decoded response.
+    pub fn execute<C: Command>(&self, request: C::Request<'_>) -> Result<C::Response, DobotError> {
+        let mut dobot = create_sender!(self.dobot())?;
+        dobot.execute::<C>(request)
+    }
+
+    /// Places a command in the queue of the arm and returns its queue index.
+    pub fn execute_queued<C: Queueable>(&self, request: C::Request<'_>) -> Result<u64, DobotError> {
+        let mut dobot = create_sender!(self.dobot())?;
+        dobot.execute_queued::<C>(request)
+    }
+
+    /// Sends an immediate command once every caller with a higher `priority`
+    /// was served, e.g. [`Priority::Background`] for telemetry polling.
+    pub fn execute_with_priority<C: Command>(
+        &self,
+        priority: Priority,
+        request: C::Request<'_>,
+    ) -> Result<C::Response, DobotError> {
+        let mut dobot = create_sender!(self.dobot(), priority)?;
+        dobot.execute::<C>(request)
+    }
+
+    /// Streams queued commands with up to `N` in flight, passing the queue
+    /// indices to `on_index` in order.
+    ///
+    /// Whenever a caller with a higher priority than `priority` is waiting,
+    /// the replies in flight are read and the arm is handed over before the
+    /// stream continues, so a stop command waits for at most `N` replies.
+    #[cfg(feature = "std")]
+    pub fn stream<'r, C: Queueable, const N: usize>(
+        &self,
+        priority: Priority,
+        requests: impl IntoIterator<Item = C::Request<'r>>,
+        mut on_index: impl FnMut(u64),
+    ) -> Result<(), DobotError>
+    where
+        T: PipelinedSender,
+    {
+        let mut requests = requests.into_iter().peekable();
+        while requests.peek().is_some() {
+            let mut dobot = create_sender!(self.dobot(), priority)?;
+            let mut pipeline = dobot.pipeline::<N>();
+            while !self.dobot().has_waiters_above(priority)
+                && let Some(request) = requests.next()
+            {
+                if let Some(index) = pipeline.send::<C>(request)? {
+                    on_index(index);
+                }
+            }
+            pipeline.flush(&mut on_index)?;
+        }
+        Ok(())
+    }
+
+    /// Waits until the queued command `index` has run.
+    ///
+    /// Returns [`DobotError::Timeout`] if it has not run within `timeout`.
+    /// The command stays queued; see [`DobotHandle::cancel_queue`].
+    #[cfg(feature = "std")]
+    pub fn wait_for_queue_index(&self, index: u64, timeout: Duration) -> Result<(), DobotError> {
+        self.wait_for_queue_index_with(index, timeout, || Ok(()))
+    }
+
+    /// Like [`DobotHandle::wait_for_queue_index`], calling `on_poll` every
+    /// time the queue is found not to have reached `index` yet.
+    #[cfg(feature = "std")]
+    pub fn wait_for_queue_index_with(
+        &self,
+        index: u64,
+        timeout: Duration,
+        mut on_poll: impl FnMut() -> Result<(), DobotError>,
+    ) -> Result<(), DobotError> {
+        let deadline = Instant::now() + timeout;
+        let mut queue = self.controller().queue_controller;
+        while queue.get_queued_cmd_current_index()? < index {
+            if Instant::now() >= deadline {
+                return Err(DobotError::Timeout);
+            }
+            on_poll()?;
+            thread::sleep(QUEUE_POLL_INTERVAL);
+        }
+        Ok(())
+    }
+
+    /// Stops the command queue and clears it, so nothing queued runs later.
+    #[cfg(feature = "std")]
+    pub fn cancel_queue(&self) -> Result<(), DobotError> {
+        let mut queue = self.controller().queue_controller;
+        queue.set_queued_cmd_force_stop_exec()?;
+        queue.set_queued_cmd_clear()
+    }
+
+    /// Brings the arm into a safe state, see [`safety::emergency_stop`].
+    #[cfg(feature = "std")]
+    pub fn emergency_stop(&self) -> SafeStateReport {
+        safety::emergency_stop(self.dobot(), &SafeStateOptions::default())
+    }
+}
@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        },
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use crate::dobot::{
+        dobot_core_serial::{command_sender_serial::DobotCommandSender, handle::DobotHandle},
+        dobot_trait::{
+            dobot_core::{
+                command_sender::simulated_command_sender::SimulatedCommandSender,
+                dobot_error::DobotError,
+                sub_command_dobot::{
+                    ptp_control::PTPControl, queue_control::QueueControl,
+                    real_time_control::RealTimeControl,
+                },
+            },
+            protocol::{
+                bodies::{
+                    tag_cp_cmd::{CPMode, TagCPCmd},
+                    tag_empty_body::EmptyBody,
+                    tag_ptp_cmd::{PTPMode, TagPTPCmd},
+                },
+                command::{Command, GetPose, SetCpCmd, SetPtpCmd, SetQueuedCmdForceStopExec},
+            },
+            rwlock::Priority,
+        },
+    };
+
+    fn assert_shareable<T: Send + Sync + Clone + 'static>() {}
+
+    fn target(x: f32) -> TagPTPCmd {
+        TagPTPCmd {
+            ptp_mode: PTPMode::MovlXyz,
+            x,
+            y: 0.0,
+            z: 20.0,
+            r: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_handle_is_send_sync_and_clone() {
+        assert_shareable::<DobotHandle<SimulatedCommandSender>>();
+        assert_shareable::<DobotHandle<DobotCommandSender>>();
+    }
+
+    #[test]
+    fn test_handle_can_be_stored_and_moved_to_threads() {
+        struct Cell {
+            arm: DobotHandle<SimulatedCommandSender>,
+        }
+
+        let sim = SimulatedCommandSender::new();
+        let cell = Cell {
+            arm: DobotHandle::new(sim.clone()),
+        };
+
+        let workers: Vec<_> = (0..4)
+            .map(|i| {
+                let arm = cell.arm.clone();
+                thread::spawn(move || {
+                    arm.controller()
+                        .ptp_controller
+                        .set_ptp_cmd(target(200.0 + i as f32), true)
+                })
+            })
+            .collect();
+        for worker in workers {
+            assert!(worker.join().unwrap().unwrap().is_some());
+        }
+
+        let queued = sim.commands().iter().filter(|c| c.is_queued).count();
+        assert_eq!(queued, 4);
+        assert_eq!(
+            cell.arm
+                .controller()
+                .realtime_controller
+                .get_pose()
+                .unwrap(),
+            sim.pose()
+        );
+    }
+
+    #[test]
+    fn test_handle_executes_typed_commands() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+
+        let first = arm.execute_queued::<SetPtpCmd>(target(150.0)).unwrap();
+        let second = arm.execute_queued::<SetPtpCmd>(target(160.0)).unwrap();
+        assert_eq!(second, first + 1);
+
+        let pose = arm.execute::<GetPose>(EmptyBody {}).unwrap();
+        assert_eq!(pose, sim.pose());
+        assert_eq!(pose.x, 160.0);
+    }
+
+    #[test]
+    fn test_force_stop_preempts_streaming_and_polling() {
+        const LATENCY: Duration = Duration::from_millis(10);
+        let sim = SimulatedCommandSender::new();
+        sim.set_latency(LATENCY);
+        let arm = DobotHandle::new(sim.clone());
+        let running = Arc::new(AtomicBool::new(true));
+
+        let poller = {
+            let arm = arm.clone();
+            let running = Arc::clone(&running);
+            thread::spawn(move || {
+                while running.load(Ordering::Relaxed) {
+                    arm.execute_with_priority::<GetPose>(Priority::Background, EmptyBody {})
+                        .unwrap();
+                }
+            })
+        };
+        let upload = {
+            let arm = arm.clone();
+            thread::spawn(move || {
+                let points = (0..200).map(|i| TagCPCmd {
+                    cp_mode: CPMode::Absolute,
+                    x: 200.0 + i as f32 * 0.1,
+                    y: 0.0,
+                    z: 10.0,
+                    velocity_or_power: 50.0,
+                });
+                let mut completed = 0;
+                arm.stream::<SetCpCmd, 4>(Priority::Normal, points, |_| completed += 1)
+                    .unwrap();
+                completed
+            })
+        };
+        thread::sleep(LATENCY * 5);
+
+        let started = Instant::now();
+        arm.controller()
+            .queue_controller
+            .set_queued_cmd_force_stop_exec()
+            .unwrap();
+        let waited = started.elapsed();
+
+        running.store(false, Ordering::Relaxed);
+        assert_eq!(upload.join().unwrap(), 200);
+        poller.join().unwrap();
+        // At most the four replies in flight plus the stop command itself.
+        assert!(waited < LATENCY * 8, "force stop waited {:?}", waited);
+        let commands = sim.commands();
+        let stop_id = u8::from(SetQueuedCmdForceStopExec::ID);
+        let stop = commands.iter().position(|c| c.id == stop_id).unwrap();
+        assert!(commands[stop..].iter().any(|c| c.is_queued));
+    }
+
+    #[test]
+    fn test_wait_for_queue_index_times_out_and_leaves_the_queue() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+        let mut queue = arm.controller().queue_controller;
+        queue.set_queued_cmd_stop_exec().unwrap();
+
+        let index = arm.execute_queued::<SetPtpCmd>(target(210.0)).unwrap();
+        let mut polls = 0;
+        assert!(matches!(
+            arm.wait_for_queue_index_with(index, Duration::from_millis(50), || {
+                polls += 1;
+                Ok(())
+            }),
+            Err(DobotError::Timeout)
+        ));
+        assert!(polls > 0);
+
+        queue.set_queued_cmd_start_exec().unwrap();
+        arm.wait_for_queue_index(index, Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(sim.pose().x, 210.0);
+    }
+
+    #[test]
+    fn test_cancel_queue_drops_commands_not_run_yet() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+        let mut queue = arm.controller().queue_controller;
+        queue.set_queued_cmd_stop_exec().unwrap();
+        let x = sim.pose().x;
+
+        let index = arm.execute_queued::<SetPtpCmd>(target(x + 10.0)).unwrap();
+        arm.cancel_queue().unwrap();
+        queue.set_queued_cmd_start_exec().unwrap();
+
+        assert!(matches!(
+            arm.wait_for_queue_index(index, Duration::from_millis(50)),
+            Err(DobotError::Timeout)
+        ));
+        assert_eq!(sim.pose().x, x);
+    }
+}
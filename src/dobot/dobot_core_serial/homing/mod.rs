@@ -0,0 +1,234 @@
+mod test;
+
+use std::time::{Duration, Instant};
+
+use crate::dobot::{
+    dobot_core_serial::handle::DobotHandle,
+    dobot_trait::{
+        dobot_core::{
+            command_sender::CommandSender,
+            dobot_error::DobotError,
+            sub_command_dobot::{
+                device_control::DeviceControl, home_control::HomeControl,
+                real_time_control::RealTimeControl,
+            },
+        },
+        protocol::bodies::{
+            tag_auto_leveling_params::TagAutoLevelingParams, tag_home_cmd::TagHomeCmd,
+            tag_home_params::TagHomeParams, tag_pose::TagPose,
+        },
+    },
+};
+
+/// The Cartesian region an arm can reach, as a ring around the base.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Workspace {
+    /// Distance from the base axis in the XY plane, in mm.
+    pub min_radius: f32,
+    pub max_radius: f32,
+    pub min_z: f32,
+    pub max_z: f32,
+    /// End effector rotation, in degrees.
+    pub min_r: f32,
+    pub max_r: f32,
+}
+
+impl Workspace {
+    /// An approximation of the workspace of a Dobot Magician.
+    pub const MAGICIAN: Workspace = Workspace {
+        min_radius: 135.0,
+        max_radius: 320.0,
+        min_z: -135.0,
+        max_z: 160.0,
+        min_r: -150.0,
+        max_r: 150.0,
+    };
+
+    /// Returns why `(x, y, z, r)` is out of reach, or `None` if it is not.
+    pub fn check(&self, x: f32, y: f32, z: f32, r: f32) -> Option<String> {
+        let radius = x.hypot(y);
+        if !(self.min_radius..=self.max_radius).contains(&radius) {
+            Some(format!(
+                "radius {:.1} mm is outside {:.1}..={:.1} mm",
+                radius, self.min_radius, self.max_radius
+            ))
+        } else if !(self.min_z..=self.max_z).contains(&z) {
+            Some(format!(
+                "z {:.1} mm is outside {:.1}..={:.1} mm",
+                z, self.min_z, self.max_z
+            ))
+        } else if !(self.min_r..=self.max_r).contains(&r) {
+            Some(format!(
+                "r {:.1}° is outside {:.1}..={:.1}°",
+                r, self.min_r, self.max_r
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Settings of [`home`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct HomeOptions {
+    /// Home position written before homing. `None` homes to the position the
+    /// arm already has.
+    pub params: Option<TagHomeParams>,
+    pub workspace: Workspace,
+    /// How far in mm and degrees the arm may end up from its home position.
+    pub tolerance: f32,
+    pub timeout: Duration,
+}
+
+impl Default for HomeOptions {
+    fn default() -> Self {
+        Self {
+            params: None,
+            workspace: Workspace::MAGICIAN,
+            tolerance: 1.0,
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+/// The outcome of a successful [`home`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct HomeReport {
+    pub home: TagHomeParams,
+    pub pose: TagPose,
+    /// The rail position, if a rail is fitted.
+    pub rail: Option<f32>,
+    pub queue_index: u64,
+}
+
+/// Homes the arm and checks it ended up at its home position.
+///
+/// The home position is checked against the workspace before anything is
+/// sent. Homing is queued behind any commands already in the queue; if it
+/// does not finish within the timeout the queue is stopped and cleared.
+/// A fitted rail must end up at zero.
+pub fn home<T: CommandSender + 'static>(
+    arm: &DobotHandle<T>,
+    options: &HomeOptions,
+) -> Result<HomeReport, DobotError> {
+    let mut controller = arm.controller();
+    let home = match &options.params {
+        Some(params) => params.clone(),
+        None => controller.homing_controller.get_home_params()?,
+    };
+    if let Some(reason) = options.workspace.check(home.x, home.y, home.z, home.r) {
+        return Err(DobotError::OutOfReach(format!("home position: {}", reason)));
+    }
+
+    if let Some(params) = &options.params {
+        controller
+            .homing_controller
+            .set_home_params(params.clone(), false)?;
+    }
+    let queue_index = controller
+        .homing_controller
+        .set_home_cmd(TagHomeCmd { reserved: 0 }, true)?
+        .ok_or(DobotError::NoResponse)?;
+    let waited = arm.wait_for_queue_index(queue_index, options.timeout);
+    if matches!(waited, Err(DobotError::Timeout)) {
+        arm.cancel_queue()?;
+    }
+    waited?;
+
+    let pose = controller.realtime_controller.get_pose()?;
+    let rail = if controller.device_controller.get_device_rail_capability()? {
+        Some(controller.realtime_controller.get_pose_rail()?)
+    } else {
+        None
+    };
+
+    let mut mismatches = Vec::new();
+    let mut verify = |name: &str, expected: f32, actual: f32| {
+        if (expected - actual).abs() > options.tolerance {
+            mismatches.push(format!(
+                "{}: expected {:.2}, got {:.2}",
+                name, expected, actual
+            ));
+        }
+    };
+    verify("x", home.x, pose.x);
+    verify("y", home.y, pose.y);
+    verify("z", home.z, pose.z);
+    verify("r", home.r, pose.r);
+    if let Some(rail) = rail {
+        verify("rail", 0.0, rail);
+    }
+    if !mismatches.is_empty() {
+        return Err(DobotError::HomeVerification(mismatches));
+    }
+
+    Ok(HomeReport {
+        home,
+        pose,
+        rail,
+        queue_index,
+    })
+}
+
+/// Settings of [`auto_level`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoLevelOptions {
+    /// Leveling accuracy requested from the firmware.
+    pub accuracy: f32,
+    pub timeout: Duration,
+}
+
+impl Default for AutoLevelOptions {
+    fn default() -> Self {
+        Self {
+            accuracy: 0.5,
+            timeout: Duration::from_secs(120),
+        }
+    }
+}
+
+/// A reading taken while [`auto_level`] waits for the leveling to finish.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoLevelProgress {
+    pub elapsed: Duration,
+    /// The value reported by `get_autoleveling`.
+    pub result: f32,
+}
+
+/// Runs the auto-leveling and returns the result reported by the firmware.
+///
+/// `on_progress` is called with every reading taken while the leveling
+/// runs. If it does not finish within the timeout the queue is stopped and
+/// cleared and [`DobotError::Timeout`] is returned.
+pub fn auto_level<T: CommandSender + 'static>(
+    arm: &DobotHandle<T>,
+    options: &AutoLevelOptions,
+    mut on_progress: impl FnMut(AutoLevelProgress),
+) -> Result<f32, DobotError> {
+    let mut controller = arm.controller();
+    let index = controller
+        .homing_controller
+        .set_autoleveling(
+            TagAutoLevelingParams {
+                is_auto_leveling: true,
+                accuracy: options.accuracy,
+            },
+            true,
+        )?
+        .ok_or(DobotError::NoResponse)?;
+
+    let started = Instant::now();
+    let waited = arm.wait_for_queue_index_with(index, options.timeout, || {
+        let result = arm.controller().homing_controller.get_autoleveling()?;
+        on_progress(AutoLevelProgress {
+            elapsed: started.elapsed(),
+            result,
+        });
+        Ok(())
+    });
+    if matches!(waited, Err(DobotError::Timeout)) {
+        arm.cancel_queue()?;
+    }
+    waited?;
+    controller.homing_controller.get_autoleveling()
+}
@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::dobot::{
+        dobot_core_serial::{
+            handle::DobotHandle,
+            homing::{AutoLevelOptions, HomeOptions, Workspace, auto_level, home},
+        },
+        dobot_trait::{
+            dobot_core::{
+                command_sender::simulated_command_sender::SimulatedCommandSender,
+                dobot_error::DobotError, sub_command_dobot::queue_control::QueueControl,
+            },
+            protocol::{
+                bodies::tag_home_params::TagHomeParams,
+                command::{Command, SetHomeCmd},
+                command_id::{CommunicationProtocolIDs, DevicePoseIDs},
+            },
+        },
+    };
+
+    fn home_at(x: f32, y: f32, z: f32) -> HomeOptions {
+        HomeOptions {
+            params: Some(TagHomeParams { x, y, z, r: 0.0 }),
+            ..HomeOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_home_moves_to_and_verifies_the_home_position() {
+        let sim = SimulatedCommandSender::new();
+        sim.with_state(|state| {
+            state.with_rail = true;
+            state.rail_position = 340.0;
+            state.pose.x = 150.0;
+        });
+        let arm = DobotHandle::new(sim.clone());
+
+        let report = home(&arm, &home_at(250.0, 0.0, 50.0)).unwrap();
+
+        assert_eq!(report.pose.x, 250.0);
+        assert_eq!(report.pose.z, 50.0);
+        assert_eq!(report.rail, Some(0.0));
+        assert_eq!(report.queue_index, 1);
+        assert!(home(&arm, &HomeOptions::default()).is_ok());
+    }
+
+    #[test]
+    fn test_unreachable_home_is_refused() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+
+        let error = home(&arm, &home_at(400.0, 0.0, 0.0)).unwrap_err();
+
+        assert!(
+            matches!(&error, DobotError::OutOfReach(reason) if reason.contains("radius")),
+            "{:?}",
+            error
+        );
+        assert!(sim.commands().iter().all(|c| !c.is_write));
+        assert!(Workspace::MAGICIAN.check(200.0, 0.0, 200.0, 0.0).is_some());
+        assert!(Workspace::MAGICIAN.check(0.0, -200.0, 0.0, 90.0).is_none());
+    }
+
+    #[test]
+    fn test_home_reports_where_the_arm_ended_up() {
+        let sim = SimulatedCommandSender::new();
+        let mut stalled = [0u8; 32];
+        stalled[..4].copy_from_slice(&180.0f32.to_le_bytes());
+        sim.pin_reply(
+            CommunicationProtocolIDs::DevicePose(DevicePoseIDs::GetPose),
+            &stalled,
+        );
+
+        let error = home(&DobotHandle::new(sim.clone()), &HomeOptions::default()).unwrap_err();
+
+        match error {
+            DobotError::HomeVerification(mismatches) => {
+                assert_eq!(mismatches, vec!["x: expected 200.00, got 180.00"]);
+            }
+            other => panic!("expected a verification error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_home_times_out_and_clears_the_queue() {
+        let sim = SimulatedCommandSender::new();
+        sim.with_state(|state| state.queue_running = false);
+        let options = HomeOptions {
+            timeout: Duration::from_millis(50),
+            ..HomeOptions::default()
+        };
+
+        let arm = DobotHandle::new(sim.clone());
+
+        let error = home(&arm, &options).unwrap_err();
+        arm.controller()
+            .queue_controller
+            .set_queued_cmd_start_exec()
+            .unwrap();
+
+        assert!(matches!(error, DobotError::Timeout));
+        let homes = sim
+            .commands()
+            .iter()
+            .filter(|c| c.id == u8::from(SetHomeCmd::ID))
+            .count();
+        assert_eq!(homes, 1);
+        assert_eq!(sim.with_state(|state| state.executed_index), 0);
+    }
+
+    #[test]
+    fn test_auto_level_reports_progress_and_result() {
+        let sim = SimulatedCommandSender::new();
+        sim.with_state(|state| state.queue_running = false);
+        let arm = DobotHandle::new(sim.clone());
+        let mut readings = Vec::new();
+
+        let result = auto_level(&arm, &AutoLevelOptions::default(), |progress| {
+            readings.push(progress);
+            if readings.len() == 3 {
+                arm.controller()
+                    .queue_controller
+                    .set_queued_cmd_start_exec()
+                    .unwrap();
+            }
+        })
+        .unwrap();
+
+        assert_eq!(result, 0.5);
+        assert_eq!(readings.len(), 3);
+        assert!(readings.windows(2).all(|w| w[0].elapsed <= w[1].elapsed));
+    }
+
+    #[test]
+    fn test_auto_level_times_out() {
+        let sim = SimulatedCommandSender::new();
+        sim.with_state(|state| state.queue_running = false);
+        let options = AutoLevelOptions {
+            timeout: Duration::from_millis(50),
+            ..AutoLevelOptions::default()
+        };
+
+        let result = auto_level(&DobotHandle::new(sim.clone()), &options, |_| {});
+
+        assert!(matches!(result, Err(DobotError::Timeout)));
+        assert!(!sim.with_state(|state| state.queue_running));
+    }
+}
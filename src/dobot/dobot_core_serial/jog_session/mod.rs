@@ -0,0 +1,269 @@
+mod test;
+
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::dobot::{
+    dobot_core_serial::{
+        handle::DobotHandle,
+        worker::{PeriodicWorker, lock},
+    },
+    dobot_trait::{
+        dobot_core::{
+            command_sender::CommandSender, dobot_error::DobotError,
+            sub_command_dobot::real_time_control::RealTimeControl,
+        },
+        protocol::{
+            bodies::{
+                tag_empty_body::EmptyBody,
+                tag_jog_cmd::{JogCmd, JogMode, TagJOGCmd},
+                tag_jog_l_params::TagJOGLParams,
+            },
+            command::{GetJogLParams, SetJogCmd, SetJogLParams},
+        },
+        rwlock::Priority,
+    },
+};
+
+/// Settings of a [`JogSession`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JogSessionOptions {
+    /// How long a motion may go on without a keepalive before it is stopped.
+    pub keepalive_timeout: Duration,
+    /// How often the keepalive is checked.
+    pub check_interval: Duration,
+    /// How often the pose is read during [`JogSession::nudge_by`].
+    pub poll_interval: Duration,
+    /// How long [`JogSession::nudge_by`] may take before it gives up.
+    pub nudge_timeout: Duration,
+}
+
+impl Default for JogSessionOptions {
+    fn default() -> Self {
+        Self {
+            keepalive_timeout: Duration::from_millis(300),
+            check_interval: Duration::from_millis(20),
+            poll_interval: Duration::from_millis(10),
+            nudge_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SessionState {
+    /// The motion the arm may be making; set before it is sent, so a failed
+    /// send still counts as moving.
+    moving: Option<TagJOGCmd>,
+    last_keepalive: Instant,
+    timed_out: bool,
+}
+
+/// Sends Idle if a motion may be in progress.
+fn stop_motion<T: CommandSender + 'static>(
+    arm: &DobotHandle<T>,
+    state: &mut SessionState,
+    priority: Priority,
+) -> Result<(), DobotError> {
+    let Some(moving) = &state.moving else {
+        return Ok(());
+    };
+    arm.execute_with_priority::<SetJogCmd>(
+        priority,
+        TagJOGCmd {
+            is_joint: moving.is_joint,
+            cmd: JogCmd::Idle,
+        },
+    )?;
+    state.moving = None;
+    Ok(())
+}
+
+/// A deadman-protected JOG session.
+///
+/// A JOG command keeps the arm moving until [`JogCmd::Idle`] is sent. The
+/// session sends it for the caller: when a motion goes without a
+/// [`JogSession::keepalive`] for longer than the keepalive timeout, and when
+/// the session is dropped, so a crashed or stalled UI cannot leave the arm
+/// running.
+///
+/// Motions are sent with [`Priority::Interactive`]; the stops sent by the
+/// session on its own use [`Priority::Emergency`].
+pub struct JogSession<T: CommandSender + 'static> {
+    arm: DobotHandle<T>,
+    mode: JogMode,
+    options: JogSessionOptions,
+    state: Arc<Mutex<SessionState>>,
+    worker: PeriodicWorker,
+}
+
+impl<T: CommandSender + 'static> JogSession<T> {
+    /// Starts a session jogging in `mode`. Nothing is sent to the arm yet.
+    pub fn start(arm: DobotHandle<T>, mode: JogMode, options: JogSessionOptions) -> Self {
+        let state = Arc::new(Mutex::new(SessionState {
+            moving: None,
+            last_keepalive: Instant::now(),
+            timed_out: false,
+        }));
+
+        let thread_arm = arm.clone();
+        let thread_state = Arc::clone(&state);
+        let keepalive_timeout = options.keepalive_timeout;
+        let worker = PeriodicWorker::spawn(options.check_interval, move || {
+            let mut state = lock(&thread_state);
+            if state.moving.is_none() || state.last_keepalive.elapsed() <= keepalive_timeout {
+                return;
+            }
+            if stop_motion(&thread_arm, &mut state, Priority::Emergency).is_ok() {
+                state.timed_out = true;
+            }
+        });
+
+        Self {
+            arm,
+            mode,
+            options,
+            state,
+            worker,
+        }
+    }
+
+    pub fn mode(&self) -> JogMode {
+        self.mode
+    }
+
+    /// Switches between Cartesian and joint JOG, stopping any motion in the
+    /// old mode first.
+    pub fn set_mode(&mut self, mode: JogMode) -> Result<(), DobotError> {
+        if mode != self.mode {
+            self.stop()?;
+            self.mode = mode;
+        }
+        Ok(())
+    }
+
+    /// Starts a continuous motion, or stops it for [`JogCmd::Idle`].
+    ///
+    /// Starting a motion counts as a keepalive.
+    pub fn jog(&mut self, cmd: JogCmd) -> Result<(), DobotError> {
+        if cmd == JogCmd::Idle {
+            return self.stop();
+        }
+        let request = TagJOGCmd {
+            is_joint: self.mode,
+            cmd,
+        };
+        {
+            let mut state = lock(&self.state);
+            state.moving = Some(request.clone());
+            state.last_keepalive = Instant::now();
+            state.timed_out = false;
+        }
+        self.arm
+            .execute_with_priority::<SetJogCmd>(Priority::Interactive, request)?;
+        Ok(())
+    }
+
+    /// Tells the session the operator still holds the JOG button.
+    pub fn keepalive(&self) {
+        lock(&self.state).last_keepalive = Instant::now();
+    }
+
+    /// Stops the current motion.
+    pub fn stop(&mut self) -> Result<(), DobotError> {
+        stop_motion(&self.arm, &mut lock(&self.state), Priority::Interactive)
+    }
+
+    /// Whether a motion may be in progress.
+    pub fn is_moving(&self) -> bool {
+        lock(&self.state).moving.is_some()
+    }
+
+    /// Whether the last motion was stopped for lack of a keepalive.
+    pub fn timed_out(&self) -> bool {
+        lock(&self.state).timed_out
+    }
+
+    /// Jogs with `cmd` for `duration`, then stops.
+    pub fn nudge_for(&mut self, cmd: JogCmd, duration: Duration) -> Result<(), DobotError> {
+        let started = Instant::now();
+        self.jog(cmd)?;
+        while let Some(remaining) = duration.checked_sub(started.elapsed()) {
+            thread::sleep(remaining.min(self.options.poll_interval));
+            self.keepalive();
+        }
+        self.stop()
+    }
+
+    /// Jogs with `cmd` until the axis it moves has travelled `distance`, then
+    /// stops and returns the distance travelled once stopped.
+    ///
+    /// `distance` is in mm for Cartesian axes and the rail and in degrees for
+    /// joints and R. The pose is read every poll interval, so the arm may
+    /// overshoot by up to one interval of motion. Returns
+    /// [`DobotError::Timeout`] if the distance was not covered within the
+    /// nudge timeout.
+    pub fn nudge_by(&mut self, cmd: JogCmd, distance: f32) -> Result<f32, DobotError> {
+        let start = self.position(cmd)?;
+        let started = Instant::now();
+        self.jog(cmd)?;
+
+        loop {
+            thread::sleep(self.options.poll_interval);
+            self.keepalive();
+            let travelled = match self.position(cmd) {
+                Ok(position) => (position - start).abs(),
+                Err(e) => {
+                    let _ = self.stop();
+                    return Err(e);
+                }
+            };
+            if travelled >= distance.abs() {
+                break;
+            }
+            if started.elapsed() > self.options.nudge_timeout {
+                self.stop()?;
+                return Err(DobotError::Timeout);
+            }
+        }
+        self.stop()?;
+        Ok((self.position(cmd)? - start).abs())
+    }
+
+    /// Sets the rail's JOG velocity and acceleration.
+    pub fn set_rail_params(&mut self, params: TagJOGLParams) -> Result<(), DobotError> {
+        self.arm.execute::<SetJogLParams>(params)?;
+        Ok(())
+    }
+
+    pub fn rail_params(&self) -> Result<TagJOGLParams, DobotError> {
+        self.arm.execute::<GetJogLParams>(EmptyBody {})
+    }
+
+    /// Reads the position of the axis `cmd` moves in the current mode.
+    fn position(&self, cmd: JogCmd) -> Result<f32, DobotError> {
+        let mut realtime = self.arm.controller().realtime_controller;
+        let axis = match cmd {
+            JogCmd::Idle => return Ok(0.0),
+            JogCmd::LpDown | JogCmd::LnDown => return realtime.get_pose_rail(),
+            JogCmd::ApDown | JogCmd::AnDown => 0,
+            JogCmd::BpDown | JogCmd::BnDown => 1,
+            JogCmd::CpDown | JogCmd::CnDown => 2,
+            JogCmd::DpDown | JogCmd::DnDown => 3,
+        };
+        let pose = realtime.get_pose()?;
+        Ok(match self.mode {
+            JogMode::Joint => pose.joint_angle[axis],
+            JogMode::Coordinate => [pose.x, pose.y, pose.z, pose.r][axis],
+        })
+    }
+}
+
+impl<T: CommandSender + 'static> Drop for JogSession<T> {
+    fn drop(&mut self) {
+        self.worker.stop();
+        let _ = stop_motion(&self.arm, &mut lock(&self.state), Priority::Emergency);
+    }
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::Arc,
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use crate::dobot::{
+        dobot_core_serial::{
+            handle::DobotHandle,
+            jog_session::{JogSession, JogSessionOptions},
+        },
+        dobot_trait::{
+            dobot_core::command_sender::simulated_command_sender::{
+                SimulatedCommand, SimulatedCommandSender,
+            },
+            protocol::{
+                bodies::{
+                    tag_jog_cmd::{JogCmd, JogMode},
+                    tag_jog_l_params::TagJOGLParams,
+                },
+                command::{Command, SetJogCmd},
+            },
+        },
+    };
+
+    fn fast_session(
+        sim: &SimulatedCommandSender,
+        mode: JogMode,
+    ) -> JogSession<SimulatedCommandSender> {
+        JogSession::start(
+            DobotHandle::new(sim.clone()),
+            mode,
+            JogSessionOptions {
+                keepalive_timeout: Duration::from_millis(60),
+                check_interval: Duration::from_millis(5),
+                poll_interval: Duration::from_millis(2),
+                nudge_timeout: Duration::from_secs(2),
+            },
+        )
+    }
+
+    fn jog_commands(sim: &SimulatedCommandSender) -> Vec<SimulatedCommand> {
+        let id: u8 = SetJogCmd::ID.into();
+        sim.commands().into_iter().filter(|c| c.id == id).collect()
+    }
+
+    #[test]
+    fn test_drop_sends_idle() {
+        let sim = SimulatedCommandSender::new();
+        let mut session = fast_session(&sim, JogMode::Joint);
+
+        session.jog(JogCmd::BpDown).unwrap();
+        assert!(session.is_moving());
+        drop(session);
+
+        let sent: Vec<_> = jog_commands(&sim).into_iter().map(|c| c.params).collect();
+        assert_eq!(sent, vec![vec![1, 3], vec![1, 0]]);
+    }
+
+    #[test]
+    fn test_motion_stops_without_keepalive() {
+        let sim = SimulatedCommandSender::new();
+        let mut session = fast_session(&sim, JogMode::Coordinate);
+
+        session.jog(JogCmd::ApDown).unwrap();
+        let started = Instant::now();
+        while session.is_moving() {
+            assert!(
+                started.elapsed() < Duration::from_secs(2),
+                "motion was never stopped"
+            );
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        assert!(session.timed_out());
+        assert_eq!(jog_commands(&sim).last().unwrap().params, vec![0, 0]);
+        let stopped_at = sim.pose().x;
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(sim.pose().x, stopped_at);
+    }
+
+    #[test]
+    fn test_keepalive_keeps_motion_going() {
+        let sim = SimulatedCommandSender::new();
+        let mut session = fast_session(&sim, JogMode::Coordinate);
+
+        session.jog(JogCmd::CnDown).unwrap();
+        for _ in 0..30 {
+            session.keepalive();
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        assert!(session.is_moving());
+        assert!(!session.timed_out());
+        assert!(sim.pose().z < 0.0);
+        session.stop().unwrap();
+        assert!(!session.is_moving());
+    }
+
+    #[test]
+    fn test_state_is_free_while_the_jog_is_sent() {
+        let sim = SimulatedCommandSender::new();
+        let mut session = fast_session(&sim, JogMode::Joint);
+        let arm = session.arm.clone();
+        let state = Arc::clone(&session.state);
+
+        let guard = arm.dobot().write().unwrap();
+        thread::scope(|scope| {
+            let jog = scope.spawn(|| session.jog(JogCmd::BpDown));
+            let started = Instant::now();
+            let free = loop {
+                match state.try_lock() {
+                    Ok(state) if state.moving.is_some() => break true,
+                    _ if started.elapsed() > Duration::from_millis(500) => break false,
+                    _ => thread::sleep(Duration::from_millis(1)),
+                }
+            };
+            let sent_early = !jog_commands(&sim).is_empty();
+            drop(guard);
+            jog.join().unwrap().unwrap();
+
+            assert!(free, "the state stayed locked while the arm was busy");
+            assert!(!sent_early);
+        });
+        assert_eq!(jog_commands(&sim).len(), 1);
+    }
+
+    #[test]
+    fn test_nudge_by_moves_one_axis_in_the_current_mode() {
+        let sim = SimulatedCommandSender::new();
+        let mut session = fast_session(&sim, JogMode::Coordinate);
+
+        let travelled = session.nudge_by(JogCmd::BpDown, 5.0).unwrap();
+        let pose = sim.pose();
+        assert!(travelled >= 5.0);
+        assert!((pose.y - travelled).abs() < 1e-3, "{:?}", pose);
+        assert_eq!(pose.joint_angle, [0.0; 4]);
+        assert!(!session.is_moving());
+
+        session.set_mode(JogMode::Joint).unwrap();
+        session.nudge_by(JogCmd::AnDown, 3.0).unwrap();
+        let after = sim.pose();
+        assert!(after.joint_angle[0] <= -3.0);
+        assert_eq!(after.y, pose.y);
+    }
+
+    #[test]
+    fn test_nudge_for_stops_after_the_duration() {
+        let sim = SimulatedCommandSender::new();
+        let mut session = fast_session(&sim, JogMode::Coordinate);
+
+        session
+            .nudge_for(JogCmd::DpDown, Duration::from_millis(100))
+            .unwrap();
+
+        // 50 units/s for 0.1 s with the simulator's default JOG parameters.
+        let r = sim.pose().r;
+        assert!((4.0..8.0).contains(&r), "r = {}", r);
+        assert!(!session.is_moving());
+        assert!(!session.timed_out());
+    }
+
+    #[test]
+    fn test_set_mode_stops_motion_in_the_old_mode() {
+        let sim = SimulatedCommandSender::new();
+        let mut session = fast_session(&sim, JogMode::Coordinate);
+
+        session.jog(JogCmd::ApDown).unwrap();
+        session.set_mode(JogMode::Joint).unwrap();
+
+        assert_eq!(session.mode(), JogMode::Joint);
+        assert!(!session.is_moving());
+        assert_eq!(jog_commands(&sim).last().unwrap().params, vec![0, 0]);
+    }
+
+    #[test]
+    fn test_rail_nudge_uses_rail_params() {
+        let sim = SimulatedCommandSender::new();
+        let mut session = fast_session(&sim, JogMode::Joint);
+        let params = TagJOGLParams {
+            velocity: 200.0,
+            acceleration: 100.0,
+        };
+
+        session.set_rail_params(params.clone()).unwrap();
+        assert_eq!(session.rail_params().unwrap(), params);
+        let started = Instant::now();
+        let travelled = session.nudge_by(JogCmd::LpDown, 20.0).unwrap();
+
+        assert!(travelled >= 20.0);
+        assert!(sim.rail_position() >= 20.0);
+        // At the default 50 mm/s this would take at least 400 ms.
+        assert!(started.elapsed() < Duration::from_millis(350));
+        assert_eq!(sim.pose().joint_angle, [0.0; 4]);
+    }
+}
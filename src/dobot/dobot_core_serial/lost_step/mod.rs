@@ -0,0 +1,338 @@
+mod test;
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use crate::dobot::{
+    dobot_core_serial::{
+        handle::DobotHandle,
+        worker::{PeriodicWorker, lock},
+    },
+    dobot_trait::{
+        dobot_core::{
+            command_sender::CommandSender, dobot_error::DobotError,
+            sub_command_dobot::losing_step_control::LosingStepControl,
+        },
+        protocol::{
+            ProtocolError,
+            alarm::{AlarmCategory, AlarmSet},
+            bodies::{
+                tag_empty_body::EmptyBody,
+                tag_pose::TagPose,
+                tag_ptp_cmd::{PTPMode, TagPTPCmd},
+            },
+            command::{
+                GetAlarmState, GetPose, GetQueuedCmdCurrentIndex, SetLostStepCmd, SetPtpCmd,
+                SetQueuedCmdForceStopExec,
+            },
+        },
+        rwlock::Priority,
+    },
+};
+
+/// Where a queued move should leave the arm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MotionTarget {
+    /// Cartesian `[x, y, z, r]`.
+    Cartesian([f32; 4]),
+    /// Joint angles `[j1, j2, j3, j4]`.
+    Joint([f32; 4]),
+}
+
+impl MotionTarget {
+    /// The target of `cmd` when started from `from`, which only incremental
+    /// modes use.
+    pub fn of_ptp(cmd: &TagPTPCmd, from: &TagPose) -> Self {
+        let values = [cmd.x, cmd.y, cmd.z, cmd.r];
+        match cmd.ptp_mode {
+            PTPMode::JumpXyz | PTPMode::MovjXyz | PTPMode::MovlXyz | PTPMode::JumpMovlXyz => {
+                MotionTarget::Cartesian(values)
+            }
+            PTPMode::JumpAngle | PTPMode::MovjAngle | PTPMode::MovlAngle => {
+                MotionTarget::Joint(values)
+            }
+            PTPMode::MovjInc => {
+                let mut joints = from.joint_angle;
+                for (angle, delta) in joints.iter_mut().zip(values) {
+                    *angle += delta;
+                }
+                MotionTarget::Joint(joints)
+            }
+            PTPMode::MovlInc | PTPMode::MovjXyzInc => MotionTarget::Cartesian([
+                from.x + cmd.x,
+                from.y + cmd.y,
+                from.z + cmd.z,
+                from.r + cmd.r,
+            ]),
+        }
+    }
+
+    /// How far `pose` is from this target: the distance in mm for a
+    /// Cartesian target, the largest joint error in degrees otherwise.
+    pub fn error(&self, pose: &TagPose) -> f32 {
+        match self {
+            MotionTarget::Cartesian([x, y, z, _]) => {
+                ((pose.x - x).powi(2) + (pose.y - y).powi(2) + (pose.z - z).powi(2)).sqrt()
+            }
+            MotionTarget::Joint(joints) => joints
+                .iter()
+                .zip(pose.joint_angle)
+                .map(|(target, angle)| (target - angle).abs())
+                .fold(0.0, f32::max),
+        }
+    }
+}
+
+/// Why a [`LostStepSupervisor`] halted the queue.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CollisionCause {
+    /// The firmware raised lost-step alarms.
+    LostStep(AlarmSet),
+    /// The arm came to rest away from where the last move should have left
+    /// it.
+    Drift { target: MotionTarget, error: f32 },
+}
+
+/// A collision or stall detected by a [`LostStepSupervisor`].
+#[derive(Debug, Clone)]
+pub struct CollisionEvent {
+    pub cause: CollisionCause,
+    pub time: SystemTime,
+    /// The measured pose, or `None` if it could not be read.
+    pub pose: Option<TagPose>,
+    /// The index of the last queued command that ran.
+    pub queue_index: Option<u64>,
+    /// Whether the queue was stopped.
+    pub halted: bool,
+}
+
+/// Settings of a [`LostStepSupervisor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LostStepOptions {
+    /// Lost-step detection threshold sent to the firmware.
+    pub threshold: f32,
+    /// How far in mm the arm may rest from a Cartesian target.
+    pub position_tolerance: f32,
+    /// How far in degrees a joint may rest from a joint target.
+    pub joint_tolerance: f32,
+    /// How often alarms and the pose are checked.
+    pub check_interval: Duration,
+}
+
+impl Default for LostStepOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 5.0,
+            position_tolerance: 2.0,
+            joint_tolerance: 1.0,
+            check_interval: Duration::from_millis(50),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Expected {
+    /// Index of the lost-step check queued after the move.
+    index: u64,
+    target: MotionTarget,
+    checked: bool,
+}
+
+#[derive(Debug, Default)]
+struct SupervisorState {
+    expected: Option<Expected>,
+    collision: Option<CollisionEvent>,
+}
+
+/// Queues moves with lost-step detection and halts the queue when the arm
+/// loses steps.
+///
+/// Every move queued through the supervisor is followed by a
+/// `SetLostStepCmd`, as the firmware only checks for lost steps where it
+/// finds one in the queue. On its own thread the supervisor watches for
+/// lost-step alarms and, once the queue has run, compares the measured pose
+/// with the target of the last move to catch stalls the firmware misses.
+/// Either stops the queue and latches a [`CollisionEvent`] until
+/// [`LostStepSupervisor::rearm`] is called.
+pub struct LostStepSupervisor<T: CommandSender + 'static> {
+    arm: DobotHandle<T>,
+    state: Arc<Mutex<SupervisorState>>,
+    worker: PeriodicWorker,
+}
+
+impl<T: CommandSender + 'static> LostStepSupervisor<T> {
+    /// Sends the detection threshold and starts watching the arm.
+    pub fn spawn(arm: DobotHandle<T>, options: LostStepOptions) -> Result<Self, DobotError> {
+        arm.controller()
+            .losing_step_controller
+            .set_lost_step_params(options.threshold)?;
+
+        let state = Arc::new(Mutex::new(SupervisorState::default()));
+
+        let thread_arm = arm.clone();
+        let thread_state = Arc::clone(&state);
+        let worker = PeriodicWorker::spawn(options.check_interval, move || {
+            if lock(&thread_state).collision.is_some() {
+                return;
+            }
+            if let Some(cause) = check(&thread_arm, &thread_state, &options) {
+                let event = halt(&thread_arm, cause);
+                lock(&thread_state).collision = Some(event);
+            }
+        });
+
+        Ok(Self { arm, state, worker })
+    }
+
+    /// Queues `cmd` followed by a lost-step check and returns the index of
+    /// the check.
+    ///
+    /// An incremental move builds on the target of the last move when both
+    /// are joint or both are Cartesian. Otherwise it starts from the measured
+    /// pose, which is only known once the last move has run; until then it
+    /// is refused with [`DobotError::UntrackedMove`].
+    pub fn queue_ptp(&self, cmd: TagPTPCmd) -> Result<u64, DobotError> {
+        let previous = lock(&self.state)
+            .expected
+            .as_ref()
+            .map(|e| (e.index, e.target));
+        let from = match (cmd.ptp_mode, previous) {
+            (PTPMode::MovjInc, Some((_, target @ MotionTarget::Joint(_))))
+            | (
+                PTPMode::MovlInc | PTPMode::MovjXyzInc,
+                Some((_, target @ MotionTarget::Cartesian(_))),
+            ) => resting_pose(target),
+            (PTPMode::MovjInc | PTPMode::MovlInc | PTPMode::MovjXyzInc, previous) => {
+                if let Some((index, _)) = previous
+                    && read_queue_index(&self.arm)? < index
+                {
+                    return Err(DobotError::UntrackedMove(format!(
+                        "{:?} needs the pose after queued command {}, which has not run yet",
+                        cmd.ptp_mode, index
+                    )));
+                }
+                self.arm.execute::<GetPose>(EmptyBody {})?
+            }
+            _ => resting_pose(MotionTarget::Cartesian([0.0; 4])),
+        };
+        let target = MotionTarget::of_ptp(&cmd, &from);
+
+        let index = {
+            let mut dobot = create_sender!(self.arm.dobot())?;
+            dobot.execute_queued::<SetPtpCmd>(cmd)?;
+            dobot.execute_queued::<SetLostStepCmd>(EmptyBody {})?
+        };
+        lock(&self.state).expected = Some(Expected {
+            index,
+            target,
+            checked: false,
+        });
+        Ok(index)
+    }
+
+    /// Returns the collision that halted the queue, if any.
+    pub fn collision(&self) -> Option<CollisionEvent> {
+        lock(&self.state).collision.clone()
+    }
+
+    /// Clears a collision and forgets the target of the last move, so the
+    /// next move starts from the measured pose.
+    pub fn rearm(&self) {
+        let mut state = lock(&self.state);
+        state.collision = None;
+        state.expected = None;
+    }
+
+    pub fn stop(mut self) {
+        self.worker.stop();
+    }
+}
+
+/// A pose at `target`, with zeros for the part the target leaves open.
+fn resting_pose(target: MotionTarget) -> TagPose {
+    let mut pose = TagPose {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        r: 0.0,
+        joint_angle: [0.0; 4],
+    };
+    match target {
+        MotionTarget::Cartesian([x, y, z, r]) => (pose.x, pose.y, pose.z, pose.r) = (x, y, z, r),
+        MotionTarget::Joint(joint_angle) => pose.joint_angle = joint_angle,
+    }
+    pose
+}
+
+fn read_queue_index<T: CommandSender + 'static>(arm: &DobotHandle<T>) -> Result<u64, DobotError> {
+    let response =
+        arm.execute_with_priority::<GetQueuedCmdCurrentIndex>(Priority::Background, EmptyBody {})?;
+    let params: [u8; 8] = response
+        .params()
+        .try_into()
+        .map_err(|_| DobotError::Protocol(ProtocolError::BufferTooSmall))?;
+    Ok(u64::from_le_bytes(params))
+}
+
+/// Looks for lost-step alarms and, once the queue has run past the last
+/// move, for drift from its target.
+fn check<T: CommandSender + 'static>(
+    arm: &DobotHandle<T>,
+    state: &Mutex<SupervisorState>,
+    options: &LostStepOptions,
+) -> Option<CollisionCause> {
+    let alarms = arm
+        .execute_with_priority::<GetAlarmState>(Priority::Background, EmptyBody {})
+        .ok()?;
+    let mut lost_steps = AlarmSet::new();
+    for alarm in AlarmSet::from_bytes(alarms.params()).iter() {
+        if alarm.category() == AlarmCategory::LoseStep {
+            lost_steps.insert_code(alarm.code());
+        }
+    }
+    if !lost_steps.is_empty() {
+        return Some(CollisionCause::LostStep(lost_steps));
+    }
+
+    let (index, target) = match &lock(state).expected {
+        Some(expected) if !expected.checked => (expected.index, expected.target),
+        _ => return None,
+    };
+    if read_queue_index(arm).ok()? < index {
+        return None;
+    }
+    let pose = arm
+        .execute_with_priority::<GetPose>(Priority::Background, EmptyBody {})
+        .ok()?;
+    // A move queued in the meantime is checked on a later round.
+    if let Some(expected) = &mut lock(state).expected
+        && expected.index == index
+    {
+        expected.checked = true;
+    }
+
+    let error = target.error(&pose);
+    let tolerance = match target {
+        MotionTarget::Cartesian(_) => options.position_tolerance,
+        MotionTarget::Joint(_) => options.joint_tolerance,
+    };
+    (error > tolerance).then_some(CollisionCause::Drift { target, error })
+}
+
+/// Stops the queue ahead of everyone else and records why.
+fn halt<T: CommandSender + 'static>(arm: &DobotHandle<T>, cause: CollisionCause) -> CollisionEvent {
+    let halted = arm
+        .execute_with_priority::<SetQueuedCmdForceStopExec>(Priority::Emergency, EmptyBody {})
+        .is_ok();
+    CollisionEvent {
+        cause,
+        time: SystemTime::now(),
+        pose: arm
+            .execute_with_priority::<GetPose>(Priority::Background, EmptyBody {})
+            .ok(),
+        queue_index: read_queue_index(arm).ok(),
+        halted,
+    }
+}
@@ -0,0 +1,231 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use crate::dobot::{
+        dobot_core_serial::{
+            handle::DobotHandle,
+            lost_step::{
+                CollisionCause, CollisionEvent, LostStepOptions, LostStepSupervisor, MotionTarget,
+            },
+        },
+        dobot_trait::{
+            dobot_core::{
+                command_sender::simulated_command_sender::SimulatedCommandSender,
+                dobot_error::DobotError, sub_command_dobot::queue_control::QueueControl,
+            },
+            protocol::{
+                alarm::{Alarm, AlarmSet},
+                bodies::{
+                    tag_pose::TagPose,
+                    tag_ptp_cmd::{PTPMode, TagPTPCmd},
+                },
+                command::{Command, SetLostStepCmd, SetLostStepParams, SetPtpCmd},
+            },
+        },
+    };
+
+    fn fast_supervisor() -> LostStepOptions {
+        LostStepOptions {
+            check_interval: Duration::from_millis(5),
+            ..LostStepOptions::default()
+        }
+    }
+
+    fn ptp(ptp_mode: PTPMode, x: f32, y: f32, z: f32, r: f32) -> TagPTPCmd {
+        TagPTPCmd {
+            ptp_mode,
+            x,
+            y,
+            z,
+            r,
+        }
+    }
+
+    fn wait_for_collision(
+        supervisor: &LostStepSupervisor<SimulatedCommandSender>,
+    ) -> CollisionEvent {
+        let started = Instant::now();
+        loop {
+            if let Some(event) = supervisor.collision() {
+                return event;
+            }
+            assert!(
+                started.elapsed() < Duration::from_secs(5),
+                "no collision detected"
+            );
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_moves_are_followed_by_a_lost_step_check() {
+        let sim = SimulatedCommandSender::new();
+        let supervisor = LostStepSupervisor::spawn(
+            DobotHandle::new(sim.clone()),
+            LostStepOptions {
+                threshold: 3.5,
+                ..fast_supervisor()
+            },
+        )
+        .unwrap();
+
+        let first = supervisor
+            .queue_ptp(ptp(PTPMode::MovlXyz, 220.0, 10.0, 5.0, 0.0))
+            .unwrap();
+        let second = supervisor
+            .queue_ptp(ptp(PTPMode::MovjXyzInc, 0.0, 0.0, 10.0, 0.0))
+            .unwrap();
+        thread::sleep(Duration::from_millis(50));
+
+        assert_eq!((first, second), (2, 4));
+        let commands = sim.commands();
+        assert_eq!(commands[0].id, u8::from(SetLostStepParams::ID));
+        assert_eq!(commands[0].params, 3.5f32.to_le_bytes());
+        let queued: Vec<u8> = commands
+            .iter()
+            .filter(|c| c.is_queued)
+            .map(|c| c.id)
+            .collect();
+        let (motion, check): (u8, u8) = (SetPtpCmd::ID.into(), SetLostStepCmd::ID.into());
+        assert_eq!(queued, vec![motion, check, motion, check]);
+        assert!(supervisor.collision().is_none());
+    }
+
+    #[test]
+    fn test_lost_step_alarm_halts_the_queue() {
+        let sim = SimulatedCommandSender::new();
+        let supervisor =
+            LostStepSupervisor::spawn(DobotHandle::new(sim.clone()), fast_supervisor()).unwrap();
+        supervisor
+            .queue_ptp(ptp(PTPMode::MovjXyz, 200.0, 50.0, 0.0, 0.0))
+            .unwrap();
+
+        sim.raise_alarm(Alarm::LimitAxis1Pos as u8);
+        sim.raise_alarm(Alarm::LoseStepAxis3 as u8);
+        let event = wait_for_collision(&supervisor);
+
+        assert_eq!(
+            event.cause,
+            CollisionCause::LostStep(AlarmSet::from_iter([Alarm::LoseStepAxis3]))
+        );
+        assert!(event.halted);
+        assert_eq!(event.queue_index, Some(2));
+        assert_eq!(event.pose.unwrap().y, 50.0);
+        assert!(!sim.with_state(|state| state.queue_running));
+    }
+
+    #[test]
+    fn test_drift_from_the_target_is_caught() {
+        let sim = SimulatedCommandSender::new();
+        let supervisor =
+            LostStepSupervisor::spawn(DobotHandle::new(sim.clone()), fast_supervisor()).unwrap();
+        sim.with_state(|state| state.queue_running = false);
+        supervisor
+            .queue_ptp(ptp(PTPMode::MovlXyz, 230.0, 0.0, 20.0, 0.0))
+            .unwrap();
+        thread::sleep(Duration::from_millis(30));
+        assert!(supervisor.collision().is_none());
+
+        sim.with_state(|state| {
+            state.queue_running = true;
+            state.executed_index = state.last_queued_index;
+            state.pose.x = 215.0;
+        });
+        let event = wait_for_collision(&supervisor);
+
+        match event.cause {
+            CollisionCause::Drift { target, error } => {
+                assert_eq!(target, MotionTarget::Cartesian([230.0, 0.0, 20.0, 0.0]));
+                assert!((error - 25.0).abs() < 1e-3, "{}", error);
+            }
+            other => panic!("expected drift, got {:?}", other),
+        }
+        assert!(event.halted);
+
+        supervisor.rearm();
+        assert!(supervisor.collision().is_none());
+    }
+
+    #[test]
+    fn test_incremental_targets_build_on_the_last_move() {
+        let sim = SimulatedCommandSender::new();
+        let supervisor =
+            LostStepSupervisor::spawn(DobotHandle::new(sim.clone()), fast_supervisor()).unwrap();
+
+        supervisor
+            .queue_ptp(ptp(PTPMode::MovjAngle, 10.0, 20.0, 30.0, 0.0))
+            .unwrap();
+        supervisor
+            .queue_ptp(ptp(PTPMode::MovjInc, 5.0, 0.0, -10.0, 0.0))
+            .unwrap();
+        thread::sleep(Duration::from_millis(50));
+
+        assert_eq!(sim.pose().joint_angle, [15.0, 20.0, 20.0, 0.0]);
+        assert!(supervisor.collision().is_none());
+    }
+
+    #[test]
+    fn test_incremental_move_of_the_other_kind_needs_the_last_move_run() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+        let supervisor = LostStepSupervisor::spawn(arm.clone(), fast_supervisor()).unwrap();
+        arm.controller()
+            .queue_controller
+            .set_queued_cmd_stop_exec()
+            .unwrap();
+
+        supervisor
+            .queue_ptp(ptp(PTPMode::MovjAngle, 10.0, 20.0, 30.0, 0.0))
+            .unwrap();
+        let writes = sim.commands().len();
+        assert!(matches!(
+            supervisor.queue_ptp(ptp(PTPMode::MovlInc, 0.0, 0.0, 5.0, 0.0)),
+            Err(DobotError::UntrackedMove(_))
+        ));
+        let ptp_id: u8 = SetPtpCmd::ID.into();
+        assert!(
+            sim.commands()[writes..]
+                .iter()
+                .all(|command| command.id != ptp_id)
+        );
+
+        arm.controller()
+            .queue_controller
+            .set_queued_cmd_start_exec()
+            .unwrap();
+        thread::sleep(Duration::from_millis(50));
+        let z = sim.pose().z;
+        supervisor
+            .queue_ptp(ptp(PTPMode::MovlInc, 0.0, 0.0, 5.0, 0.0))
+            .unwrap();
+        thread::sleep(Duration::from_millis(50));
+
+        assert_eq!(sim.pose().z, z + 5.0);
+        assert!(supervisor.collision().is_none());
+    }
+
+    #[test]
+    fn test_target_error() {
+        let pose = TagPose {
+            x: 203.0,
+            y: 4.0,
+            z: 0.0,
+            r: 90.0,
+            joint_angle: [1.0, -2.0, 0.5, 0.0],
+        };
+
+        assert_eq!(
+            MotionTarget::Cartesian([200.0, 0.0, 0.0, 0.0]).error(&pose),
+            5.0
+        );
+        assert_eq!(MotionTarget::Joint([0.0; 4]).error(&pose), 2.0);
+        assert_eq!(
+            MotionTarget::of_ptp(&ptp(PTPMode::MovlInc, 1.0, 1.0, 1.0, 0.0), &pose),
+            MotionTarget::Cartesian([204.0, 5.0, 1.0, 90.0])
+        );
+    }
+}
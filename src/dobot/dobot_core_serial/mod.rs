@@ -18,9 +18,53 @@ use crate::dobot::dobot_trait::rwlock::RwLock;
 
 pub mod sub_command_dobot;
 
+pub mod handle;
+
 #[cfg(feature = "std")]
 pub mod command_sender_serial;
 
+#[cfg(feature = "std")]
+pub mod discovery;
+
+#[cfg(feature = "std")]
+mod worker;
+
+#[cfg(feature = "std")]
+pub mod connection;
+
+#[cfg(feature = "std")]
+pub mod profile;
+
+#[cfg(feature = "std")]
+pub mod safety;
+
+#[cfg(feature = "std")]
+pub mod jog_session;
+
+#[cfg(feature = "std")]
+pub mod alarm_monitor;
+
+#[cfg(feature = "std")]
+pub mod recovery;
+
+#[cfg(feature = "std")]
+pub mod lost_step;
+
+#[cfg(feature = "std")]
+pub mod homing;
+
+#[cfg(feature = "std")]
+pub mod calibration;
+
+#[cfg(feature = "std")]
+pub mod frames;
+
+#[cfg(feature = "std")]
+pub mod tcp;
+
+#[cfg(feature = "std")]
+pub mod hand_eye;
+
 pub struct DobotApiSerialController<'a, T: CommandSender> {
     command_sender: &'a RwLock<Dobot<T>>,
     pub device_controller: DeviceSerialControl<'a, T>,
@@ -0,0 +1,251 @@
+mod test;
+
+use core::fmt::Debug;
+
+use crate::dobot::{
+    dobot_core_serial::DobotApiSerialController,
+    dobot_trait::{
+        dobot_core::{
+            command_sender::{CommandSender, Dobot},
+            dobot_error::DobotError,
+            sub_command_dobot::{
+                arc_control::ArcControl, cp_control::CPControl,
+                end_effector_control::EndEffectorControl,
+                handhold_teaching_control::HandholdTeachingControl, home_control::HomeControl,
+                io_control::IOControl, jog_control::JOGControl, ptp_control::PTPControl,
+            },
+        },
+        protocol::bodies::{
+            hht_trig_mode::HHTTrigMode, tag_arc_params::TagARCParams, tag_cp_params::TagCPParams,
+            tag_end_effector_params::TagEndEffectorParams, tag_home_params::TagHomeParams,
+            tag_io_multiplexing::TagIOMultiplexing, tag_jog_common_params::TagJOGCommonParams,
+            tag_jog_coordinate_params::TagJOGCoordinateParams,
+            tag_jog_joint_params::TagJOGJointParams, tag_jog_l_params::TagJOGLParams,
+            tag_ptp_common_params::TagPTPCommonParams,
+            tag_ptp_coordinate_params::TagPTPCoordinateParams,
+            tag_ptp_joint_params::TagPTPJointParams, tag_ptp_jump_params::TagPTPJumpParams,
+            tag_ptpl_params::TagPTPLParams,
+        },
+        rwlock::RwLock,
+    },
+};
+
+/// Addresses of the extended IO interfaces whose multiplexing is part of a
+/// profile.
+pub const EIO_ADDRESSES: core::ops::RangeInclusive<u8> = 1..=20;
+
+/// A snapshot of every user-configurable parameter of an arm.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RobotProfile {
+    pub hht_trig_mode: HHTTrigMode,
+    pub ptp_joint: TagPTPJointParams,
+    pub ptp_coordinate: TagPTPCoordinateParams,
+    pub ptp_jump: TagPTPJumpParams,
+    pub ptp_common: TagPTPCommonParams,
+    pub ptpl: TagPTPLParams,
+    pub jog_joint: TagJOGJointParams,
+    pub jog_coordinate: TagJOGCoordinateParams,
+    pub jog_common: TagJOGCommonParams,
+    pub jogl: TagJOGLParams,
+    pub cp: TagCPParams,
+    pub arc: TagARCParams,
+    pub home: TagHomeParams,
+    pub end_effector: TagEndEffectorParams,
+    /// Only the listed addresses are written when the profile is applied.
+    pub io_multiplexing: Vec<TagIOMultiplexing>,
+}
+
+/// A single parameter that differs between two profiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileChange {
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+fn compare<V: Debug + PartialEq>(changes: &mut Vec<ProfileChange>, field: &str, old: &V, new: &V) {
+    if old != new {
+        changes.push(ProfileChange {
+            field: field.to_string(),
+            old: format!("{:?}", old),
+            new: format!("{:?}", new),
+        });
+    }
+}
+
+impl RobotProfile {
+    /// Reads every parameter covered by a profile from the arm.
+    pub fn read<T: CommandSender>(dobot: &RwLock<Dobot<T>>) -> Result<Self, DobotError> {
+        let mut controller = DobotApiSerialController::new(dobot);
+        let io_multiplexing = EIO_ADDRESSES
+            .map(|address| controller.io_controller.get_io_multiplexing(address))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            hht_trig_mode: controller
+                .handhold_teaching_controller
+                .get_hht_trig_mode()?,
+            ptp_joint: controller.ptp_controller.get_ptp_joint_params()?,
+            ptp_coordinate: controller.ptp_controller.get_ptp_coordinate_params()?,
+            ptp_jump: controller.ptp_controller.get_ptp_jump_params()?,
+            ptp_common: controller.ptp_controller.get_ptp_common_params()?,
+            ptpl: controller.ptp_controller.get_ptpl_params()?,
+            jog_joint: controller.jog_controller.get_jog_joint_params()?,
+            jog_coordinate: controller.jog_controller.get_jog_coordinate_params()?,
+            jog_common: controller.jog_controller.get_jog_common_params()?,
+            jogl: controller.jog_controller.get_jogl_params()?,
+            cp: controller.cp_controller.get_cp_params()?,
+            arc: controller.arc_controller.get_arc_params()?,
+            home: controller.homing_controller.get_home_params()?,
+            end_effector: controller
+                .end_effector_controller
+                .get_end_effector_params()?,
+            io_multiplexing,
+        })
+    }
+
+    /// Writes every parameter of the profile to the arm as immediate commands,
+    /// without verifying them.
+    pub fn write<T: CommandSender>(&self, dobot: &RwLock<Dobot<T>>) -> Result<(), DobotError> {
+        let mut controller = DobotApiSerialController::new(dobot);
+        controller
+            .handhold_teaching_controller
+            .set_hht_trig_mode(self.hht_trig_mode)?;
+        let ptp = &mut controller.ptp_controller;
+        ptp.set_ptp_joint_params(self.ptp_joint.clone(), false)?;
+        ptp.set_ptp_coordinate_params(self.ptp_coordinate.clone(), false)?;
+        ptp.set_ptp_jump_params(self.ptp_jump.clone(), false)?;
+        ptp.set_ptp_common_params(self.ptp_common.clone(), false)?;
+        ptp.set_ptpl_params(self.ptpl.clone(), false)?;
+        let jog = &mut controller.jog_controller;
+        jog.set_jog_joint_params(self.jog_joint.clone(), false)?;
+        jog.set_jog_coordinate_params(self.jog_coordinate.clone(), false)?;
+        jog.set_jog_common_params(self.jog_common.clone(), false)?;
+        jog.set_jogl_params(self.jogl.clone(), false)?;
+        controller
+            .cp_controller
+            .set_cp_params(self.cp.clone(), false)?;
+        controller
+            .arc_controller
+            .set_arc_params(self.arc.clone(), false)?;
+        controller
+            .homing_controller
+            .set_home_params(self.home.clone(), false)?;
+        controller
+            .end_effector_controller
+            .set_end_effector_params(self.end_effector.clone(), false)?;
+        for io in &self.io_multiplexing {
+            controller
+                .io_controller
+                .set_io_multiplexing(io.clone(), false)?;
+        }
+        Ok(())
+    }
+
+    /// Applies the profile and reads it back.
+    ///
+    /// If a write fails or a parameter reads back differently, the parameters
+    /// that were active before are written back and the error is returned, so
+    /// the arm is never left with half of a profile.
+    pub fn apply<T: CommandSender>(&self, dobot: &RwLock<Dobot<T>>) -> Result<(), DobotError> {
+        let previous = Self::read(dobot)?;
+        let result = self.write(dobot).and_then(|_| {
+            let changes = self.diff(&Self::read(dobot)?);
+            if changes.is_empty() {
+                Ok(())
+            } else {
+                Err(DobotError::ProfileVerification(
+                    changes.into_iter().map(|change| change.field).collect(),
+                ))
+            }
+        });
+
+        if result.is_err() {
+            let _ = previous.write(dobot);
+        }
+        result
+    }
+
+    /// Lists the parameters that differ from `other`.
+    ///
+    /// `old` holds the value in `self`, `new` the value in `other`. IO
+    /// addresses missing from one of the profiles are not compared.
+    pub fn diff(&self, other: &Self) -> Vec<ProfileChange> {
+        let mut changes = Vec::new();
+        compare(
+            &mut changes,
+            "hht_trig_mode",
+            &self.hht_trig_mode,
+            &other.hht_trig_mode,
+        );
+        compare(&mut changes, "ptp_joint", &self.ptp_joint, &other.ptp_joint);
+        compare(
+            &mut changes,
+            "ptp_coordinate",
+            &self.ptp_coordinate,
+            &other.ptp_coordinate,
+        );
+        compare(&mut changes, "ptp_jump", &self.ptp_jump, &other.ptp_jump);
+        compare(
+            &mut changes,
+            "ptp_common",
+            &self.ptp_common,
+            &other.ptp_common,
+        );
+        compare(&mut changes, "ptpl", &self.ptpl, &other.ptpl);
+        compare(&mut changes, "jog_joint", &self.jog_joint, &other.jog_joint);
+        compare(
+            &mut changes,
+            "jog_coordinate",
+            &self.jog_coordinate,
+            &other.jog_coordinate,
+        );
+        compare(
+            &mut changes,
+            "jog_common",
+            &self.jog_common,
+            &other.jog_common,
+        );
+        compare(&mut changes, "jogl", &self.jogl, &other.jogl);
+        compare(&mut changes, "cp", &self.cp, &other.cp);
+        compare(&mut changes, "arc", &self.arc, &other.arc);
+        compare(&mut changes, "home", &self.home, &other.home);
+        compare(
+            &mut changes,
+            "end_effector",
+            &self.end_effector,
+            &other.end_effector,
+        );
+        for io in &self.io_multiplexing {
+            let other_io = other
+                .io_multiplexing
+                .iter()
+                .find(|other_io| other_io.address == io.address);
+            if let Some(other_io) = other_io {
+                let field = format!("io_multiplexing[{}]", io.address);
+                compare(&mut changes, &field, &io.multiplex, &other_io.multiplex);
+            }
+        }
+        changes
+    }
+}
+
+#[cfg(feature = "serde")]
+impl RobotProfile {
+    pub fn to_json(&self) -> Result<String, DobotError> {
+        serde_json::to_string_pretty(self).map_err(|e| DobotError::ProfileFormat(e.to_string()))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, DobotError> {
+        serde_json::from_str(json).map_err(|e| DobotError::ProfileFormat(e.to_string()))
+    }
+
+    pub fn to_toml(&self) -> Result<String, DobotError> {
+        toml::to_string_pretty(self).map_err(|e| DobotError::ProfileFormat(e.to_string()))
+    }
+
+    pub fn from_toml(toml: &str) -> Result<Self, DobotError> {
+        toml::from_str(toml).map_err(|e| DobotError::ProfileFormat(e.to_string()))
+    }
+}
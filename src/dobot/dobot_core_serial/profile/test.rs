@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::{
+        dobot_core_serial::profile::{EIO_ADDRESSES, RobotProfile},
+        dobot_trait::{
+            dobot_core::{
+                command_sender::{Dobot, simulated_command_sender::SimulatedCommandSender},
+                dobot_error::DobotError,
+            },
+            protocol::{
+                CommunicationProtocolIDs,
+                bodies::{
+                    hht_trig_mode::HHTTrigMode,
+                    tag_io_multiplexing::{IOFunction, TagIOMultiplexing},
+                    tag_jog_common_params::TagJOGCommonParams,
+                    tag_ptp_common_params::TagPTPCommonParams,
+                },
+                command_id::PtpIDs,
+            },
+            rwlock::RwLock,
+        },
+    };
+
+    fn create_dobot() -> (
+        RwLock<Dobot<SimulatedCommandSender>>,
+        SimulatedCommandSender,
+    ) {
+        let sim = SimulatedCommandSender::new();
+        (RwLock::new(Dobot::new(sim.clone())), sim)
+    }
+
+    fn modified(profile: &RobotProfile) -> RobotProfile {
+        let mut profile = profile.clone();
+        profile.hht_trig_mode = HHTTrigMode::TriggeredOnPeriodicInterval;
+        profile.ptp_common = TagPTPCommonParams {
+            velocity_ratio: 50.0,
+            acceleration_ratio: 80.0,
+        };
+        profile.jog_common = TagJOGCommonParams {
+            velocity_ratio: 30.0,
+            acceleration_ratio: 20.0,
+        };
+        profile.home.x = 230.0;
+        profile.io_multiplexing[2] = TagIOMultiplexing {
+            address: 3,
+            multiplex: IOFunction::Pwm,
+        };
+        profile
+    }
+
+    #[test]
+    fn test_read_covers_every_io_address() {
+        let (dobot, _) = create_dobot();
+
+        let profile = RobotProfile::read(&dobot).unwrap();
+
+        assert_eq!(profile.io_multiplexing.len(), EIO_ADDRESSES.count());
+        for (io, address) in profile.io_multiplexing.iter().zip(EIO_ADDRESSES) {
+            assert_eq!(io.address, address);
+        }
+    }
+
+    #[test]
+    fn test_diff_lists_changed_fields() {
+        let (dobot, _) = create_dobot();
+        let profile = RobotProfile::read(&dobot).unwrap();
+        let other = modified(&profile);
+
+        assert!(profile.diff(&profile).is_empty());
+        let fields: Vec<String> = profile
+            .diff(&other)
+            .into_iter()
+            .map(|change| change.field)
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                "hht_trig_mode",
+                "ptp_common",
+                "jog_common",
+                "home",
+                "io_multiplexing[3]"
+            ]
+        );
+        let change = &profile.diff(&other)[1];
+        assert!(change.new.contains("50.0"));
+    }
+
+    #[test]
+    fn test_apply_writes_and_verifies() {
+        let (dobot, _) = create_dobot();
+        let target = modified(&RobotProfile::read(&dobot).unwrap());
+
+        target.apply(&dobot).unwrap();
+
+        assert_eq!(RobotProfile::read(&dobot).unwrap(), target);
+    }
+
+    #[test]
+    fn test_apply_rolls_back_on_verification_failure() {
+        let (dobot, sim) = create_dobot();
+        let before = RobotProfile::read(&dobot).unwrap();
+        sim.pin_reply(
+            CommunicationProtocolIDs::Ptp(PtpIDs::CommonParams),
+            &[0u8; 8],
+        );
+
+        let result = modified(&before).apply(&dobot);
+
+        assert!(
+            matches!(result, Err(DobotError::ProfileVerification(fields)) if fields == vec!["ptp_common"])
+        );
+        assert_eq!(RobotProfile::read(&dobot).unwrap(), before);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_profile_json_round_trip() {
+        let (dobot, _) = create_dobot();
+        let profile = modified(&RobotProfile::read(&dobot).unwrap());
+
+        let json = profile.to_json().unwrap();
+
+        assert!(json.contains("\"hht_trig_mode\""));
+        assert_eq!(RobotProfile::from_json(&json).unwrap(), profile);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_profile_toml_round_trip() {
+        let (dobot, _) = create_dobot();
+        let profile = modified(&RobotProfile::read(&dobot).unwrap());
+
+        let toml = profile.to_toml().unwrap();
+
+        assert!(toml.contains("[ptp_common]"));
+        assert_eq!(RobotProfile::from_toml(&toml).unwrap(), profile);
+        assert!(matches!(
+            RobotProfile::from_toml("ptp_common = 1"),
+            Err(DobotError::ProfileFormat(_))
+        ));
+    }
+}
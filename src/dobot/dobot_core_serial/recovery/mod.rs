@@ -0,0 +1,226 @@
+mod test;
+
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
+
+use crate::dobot::{
+    dobot_core_serial::{handle::DobotHandle, safety::StepOutcome},
+    dobot_trait::{
+        dobot_core::{
+            command_sender::CommandSender,
+            dobot_error::DobotError,
+            sub_command_dobot::{
+                alarm_control::AlarmControl, home_control::HomeControl, ptp_control::PTPControl,
+                queue_control::QueueControl,
+            },
+        },
+        protocol::{
+            alarm::{ActiveAlarm, AlarmCategory, AlarmSet},
+            bodies::{tag_home_cmd::TagHomeCmd, tag_ptp_cmd::TagPTPCmd},
+        },
+    },
+};
+
+/// One action of a [`RecoveryPolicy`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecoveryStep {
+    ClearAlarms,
+    ClearQueue,
+    /// Starts executing the command queue again.
+    ResumeQueue,
+    /// Queues the homing procedure and waits for it to finish.
+    Home,
+    /// Queues a PTP move and waits for it to finish. Use one of the
+    /// incremental modes to back away from wherever the alarm stopped the
+    /// arm.
+    Retreat(TagPTPCmd),
+}
+
+/// The steps taken to recover from the alarms of one [`AlarmCategory`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecoveryPolicy {
+    pub steps: Vec<RecoveryStep>,
+    /// How many times the steps are run before [`FaultRecovery::recover`]
+    /// escalates.
+    pub max_attempts: u32,
+    /// How long a homing or retreat move may take.
+    pub move_timeout: Duration,
+}
+
+impl RecoveryPolicy {
+    pub fn new(steps: Vec<RecoveryStep>) -> Self {
+        Self {
+            steps,
+            max_attempts: 3,
+            move_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// One step taken by [`FaultRecovery::recover`].
+#[derive(Debug, Clone)]
+pub struct RecoveryLogEntry {
+    pub time: SystemTime,
+    /// The category whose policy was run.
+    pub category: AlarmCategory,
+    /// Counts from 1 within one call of [`FaultRecovery::recover`].
+    pub attempt: u32,
+    pub step: RecoveryStep,
+    pub outcome: StepOutcome,
+}
+
+/// The result of [`FaultRecovery::recover`].
+#[derive(Debug, Clone)]
+pub enum RecoveryOutcome {
+    /// No alarm was active.
+    NothingToDo,
+    /// No alarm is active any more after running a policy `attempts` times.
+    Recovered { attempts: u32 },
+    /// An active alarm has no policy; nothing was done for it.
+    Unhandled(ActiveAlarm),
+    /// The alarms were still active after the policy ran
+    /// [`RecoveryPolicy::max_attempts`] times.
+    Escalated { alarms: AlarmSet, attempts: u32 },
+    /// A step or reading the alarm state failed.
+    Failed {
+        step: Option<RecoveryStep>,
+        error: DobotError,
+    },
+}
+
+/// Clears alarms and brings an arm back to work using a [`RecoveryPolicy`]
+/// per [`AlarmCategory`].
+///
+/// When several alarms are active, the policy for the most severe one is
+/// run. Every step taken is kept in [`FaultRecovery::log`].
+pub struct FaultRecovery<T: CommandSender + 'static> {
+    arm: DobotHandle<T>,
+    policies: HashMap<AlarmCategory, RecoveryPolicy>,
+    log: Vec<RecoveryLogEntry>,
+}
+
+impl<T: CommandSender + 'static> FaultRecovery<T> {
+    /// Creates a recovery without any policy.
+    pub fn new(arm: DobotHandle<T>) -> Self {
+        Self {
+            arm,
+            policies: HashMap::new(),
+            log: Vec::new(),
+        }
+    }
+
+    /// Creates a recovery that re-homes after limit and lost-step alarms and
+    /// restarts the queue after planning alarms.
+    pub fn with_default_policies(arm: DobotHandle<T>) -> Self {
+        let rehome = RecoveryPolicy::new(vec![
+            RecoveryStep::ClearAlarms,
+            RecoveryStep::ClearQueue,
+            RecoveryStep::ResumeQueue,
+            RecoveryStep::Home,
+        ]);
+        let restart = RecoveryPolicy::new(vec![
+            RecoveryStep::ClearAlarms,
+            RecoveryStep::ClearQueue,
+            RecoveryStep::ResumeQueue,
+        ]);
+
+        let mut recovery = Self::new(arm);
+        recovery.set_policy(AlarmCategory::Limit, rehome.clone());
+        recovery.set_policy(AlarmCategory::LoseStep, rehome);
+        recovery.set_policy(AlarmCategory::Plan, restart);
+        recovery
+    }
+
+    pub fn set_policy(&mut self, category: AlarmCategory, policy: RecoveryPolicy) {
+        self.policies.insert(category, policy);
+    }
+
+    pub fn remove_policy(&mut self, category: AlarmCategory) -> Option<RecoveryPolicy> {
+        self.policies.remove(&category)
+    }
+
+    pub fn policy(&self, category: AlarmCategory) -> Option<&RecoveryPolicy> {
+        self.policies.get(&category)
+    }
+
+    /// Returns every step taken so far, oldest first.
+    pub fn log(&self) -> &[RecoveryLogEntry] {
+        &self.log
+    }
+
+    pub fn clear_log(&mut self) {
+        self.log.clear();
+    }
+
+    /// Reads the active alarms and runs the matching policy until they stay
+    /// cleared or the policy runs out of attempts.
+    pub fn recover(&mut self) -> RecoveryOutcome {
+        let mut attempts = 0;
+        loop {
+            let alarms = match self.arm.controller().alarm_controller.get_active_alarms() {
+                Ok(alarms) => alarms,
+                Err(error) => return RecoveryOutcome::Failed { step: None, error },
+            };
+            let Some(worst) = alarms.iter().max_by_key(|alarm| alarm.severity()) else {
+                return match attempts {
+                    0 => RecoveryOutcome::NothingToDo,
+                    _ => RecoveryOutcome::Recovered { attempts },
+                };
+            };
+            let category = worst.category();
+            let Some(policy) = self.policies.get(&category).cloned() else {
+                return RecoveryOutcome::Unhandled(worst);
+            };
+            if attempts >= policy.max_attempts {
+                return RecoveryOutcome::Escalated { alarms, attempts };
+            }
+
+            attempts += 1;
+            for step in policy.steps {
+                let result = self.run_step(&step, policy.move_timeout);
+                let outcome = match &result {
+                    Ok(()) => StepOutcome::Done,
+                    Err(e) => StepOutcome::Failed(e.clone()),
+                };
+                self.log.push(RecoveryLogEntry {
+                    time: SystemTime::now(),
+                    category,
+                    attempt: attempts,
+                    step: step.clone(),
+                    outcome,
+                });
+                if let Err(error) = result {
+                    return RecoveryOutcome::Failed {
+                        step: Some(step),
+                        error,
+                    };
+                }
+            }
+        }
+    }
+
+    fn run_step(&self, step: &RecoveryStep, move_timeout: Duration) -> Result<(), DobotError> {
+        let mut controller = self.arm.controller();
+        let index = match step {
+            RecoveryStep::ClearAlarms => {
+                return controller.alarm_controller.clear_all_alarms_state();
+            }
+            RecoveryStep::ClearQueue => return controller.queue_controller.set_queued_cmd_clear(),
+            RecoveryStep::ResumeQueue => {
+                return controller.queue_controller.set_queued_cmd_start_exec();
+            }
+            RecoveryStep::Home => controller
+                .homing_controller
+                .set_home_cmd(TagHomeCmd { reserved: 0 }, true)?,
+            RecoveryStep::Retreat(cmd) => {
+                controller.ptp_controller.set_ptp_cmd(cmd.clone(), true)?
+            }
+        };
+        let Some(index) = index else {
+            return Ok(());
+        };
+        self.arm.wait_for_queue_index(index, move_timeout)
+    }
+}
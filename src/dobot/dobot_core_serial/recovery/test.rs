@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::dobot::{
+        dobot_core_serial::{
+            handle::DobotHandle,
+            recovery::{FaultRecovery, RecoveryOutcome, RecoveryPolicy, RecoveryStep},
+            safety::StepOutcome,
+        },
+        dobot_trait::{
+            dobot_core::{
+                command_sender::simulated_command_sender::SimulatedCommandSender,
+                dobot_error::DobotError,
+            },
+            protocol::{
+                alarm::{ActiveAlarm, Alarm, AlarmCategory, AlarmSet},
+                bodies::tag_ptp_cmd::{PTPMode, TagPTPCmd},
+                command::{ClearAllAlarmsState, Command, SetHomeCmd, SetQueuedCmdStartExec},
+                command_id::{AlarmIDs, CommunicationProtocolIDs},
+            },
+        },
+    };
+
+    fn steps(recovery: &FaultRecovery<SimulatedCommandSender>) -> Vec<(u32, RecoveryStep)> {
+        recovery
+            .log()
+            .iter()
+            .map(|entry| (entry.attempt, entry.step.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_limit_alarm_is_cleared_and_the_arm_rehomed() {
+        let sim = SimulatedCommandSender::new();
+        sim.with_state(|state| {
+            state.pose.x = 310.0;
+            state.queue_running = false;
+        });
+        sim.raise_alarm(Alarm::LimitAxis2Pos as u8);
+        let mut recovery = FaultRecovery::with_default_policies(DobotHandle::new(sim.clone()));
+
+        let outcome = recovery.recover();
+
+        assert!(
+            matches!(outcome, RecoveryOutcome::Recovered { attempts: 1 }),
+            "{:?}",
+            outcome
+        );
+        assert_eq!(
+            steps(&recovery),
+            vec![
+                (1, RecoveryStep::ClearAlarms),
+                (1, RecoveryStep::ClearQueue),
+                (1, RecoveryStep::ResumeQueue),
+                (1, RecoveryStep::Home),
+            ]
+        );
+        assert!(recovery.log().iter().all(|entry| {
+            entry.category == AlarmCategory::Limit && matches!(entry.outcome, StepOutcome::Done)
+        }));
+        let sent: Vec<u8> = sim.commands().iter().map(|c| c.id).collect();
+        assert!(sent.contains(&ClearAllAlarmsState::ID.into()));
+        assert!(sent.contains(&SetQueuedCmdStartExec::ID.into()));
+        assert!(sent.contains(&SetHomeCmd::ID.into()));
+        assert_eq!(sim.pose().x, 200.0);
+    }
+
+    #[test]
+    fn test_retreat_backs_the_arm_away() {
+        let sim = SimulatedCommandSender::new();
+        sim.raise_alarm(Alarm::PlanInvLimit as u8);
+        let mut recovery = FaultRecovery::new(DobotHandle::new(sim.clone()));
+        recovery.set_policy(
+            AlarmCategory::Plan,
+            RecoveryPolicy::new(vec![
+                RecoveryStep::ClearAlarms,
+                RecoveryStep::Retreat(TagPTPCmd {
+                    ptp_mode: PTPMode::MovjXyzInc,
+                    x: 0.0,
+                    y: 0.0,
+                    z: 25.0,
+                    r: 0.0,
+                }),
+            ]),
+        );
+
+        assert!(matches!(
+            recovery.recover(),
+            RecoveryOutcome::Recovered { attempts: 1 }
+        ));
+        assert_eq!(sim.pose().z, 25.0);
+        assert!(matches!(recovery.recover(), RecoveryOutcome::NothingToDo));
+    }
+
+    #[test]
+    fn test_recovery_escalates_after_the_retry_limit() {
+        let sim = SimulatedCommandSender::new();
+        let stuck = AlarmSet::from_iter([Alarm::LoseStepAxis1]);
+        sim.pin_reply(
+            CommunicationProtocolIDs::Alarm(AlarmIDs::GetAlarmState),
+            stuck.as_bytes(),
+        );
+        let mut recovery = FaultRecovery::new(DobotHandle::new(sim.clone()));
+        recovery.set_policy(
+            AlarmCategory::LoseStep,
+            RecoveryPolicy {
+                max_attempts: 2,
+                ..RecoveryPolicy::new(vec![RecoveryStep::ClearAlarms, RecoveryStep::Home])
+            },
+        );
+
+        let outcome = recovery.recover();
+
+        match outcome {
+            RecoveryOutcome::Escalated { alarms, attempts } => {
+                assert_eq!(alarms, stuck);
+                assert_eq!(attempts, 2);
+            }
+            other => panic!("expected escalation, got {:?}", other),
+        }
+        assert_eq!(
+            steps(&recovery),
+            vec![
+                (1, RecoveryStep::ClearAlarms),
+                (1, RecoveryStep::Home),
+                (2, RecoveryStep::ClearAlarms),
+                (2, RecoveryStep::Home),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_alarms_without_a_policy_are_left_alone() {
+        let sim = SimulatedCommandSender::new();
+        sim.raise_alarm(Alarm::LimitAxis1Neg as u8);
+        sim.raise_alarm(Alarm::MotorZPhaseShort as u8);
+        let mut recovery = FaultRecovery::with_default_policies(DobotHandle::new(sim.clone()));
+
+        let outcome = recovery.recover();
+
+        assert!(
+            matches!(
+                outcome,
+                RecoveryOutcome::Unhandled(ActiveAlarm::Known(Alarm::MotorZPhaseShort))
+            ),
+            "{:?}",
+            outcome
+        );
+        assert!(recovery.log().is_empty());
+    }
+
+    #[test]
+    fn test_failed_step_stops_recovery() {
+        let sim = SimulatedCommandSender::new();
+        sim.with_state(|state| state.queue_running = false);
+        sim.raise_alarm(Alarm::LimitAxis4Pos as u8);
+        let mut recovery = FaultRecovery::new(DobotHandle::new(sim.clone()));
+        recovery.set_policy(
+            AlarmCategory::Limit,
+            RecoveryPolicy {
+                move_timeout: Duration::from_millis(50),
+                ..RecoveryPolicy::new(vec![RecoveryStep::ClearAlarms, RecoveryStep::Home])
+            },
+        );
+
+        let outcome = recovery.recover();
+
+        assert!(
+            matches!(
+                outcome,
+                RecoveryOutcome::Failed {
+                    step: Some(RecoveryStep::Home),
+                    error: DobotError::Timeout
+                }
+            ),
+            "{:?}",
+            outcome
+        );
+        assert!(matches!(
+            recovery.log().last().unwrap().outcome,
+            StepOutcome::Failed(DobotError::Timeout)
+        ));
+    }
+}
@@ -0,0 +1,355 @@
+mod test;
+
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::dobot::{
+    dobot_core_serial::{
+        handle::DobotHandle,
+        profile::EIO_ADDRESSES,
+        worker::{PeriodicWorker, lock},
+    },
+    dobot_trait::{
+        dobot_core::{
+            command_sender::{CommandSender, Dobot},
+            dobot_error::DobotError,
+        },
+        protocol::{
+            bodies::{
+                tag_emotor::{EMotorIndex, TagEMotor},
+                tag_empty_body::EmptyBody,
+                tag_end_effector_state::TagEndEffectorState,
+                tag_io_address::TagIOAddress,
+                tag_io_multiplexing::IOFunction,
+                tag_io_pwm::TagIOPWM,
+                tag_jog_cmd::{JogCmd, JogMode, TagJOGCmd},
+            },
+            command::{
+                GetDeviceTime, GetIoMultiplexing, GetIoPwm, SetEMotor, SetEndEffectorGripper,
+                SetEndEffectorLaser, SetEndEffectorSuctionCup, SetIoPwm, SetJogCmd,
+                SetQueuedCmdClear, SetQueuedCmdForceStopExec,
+            },
+        },
+        rwlock::{Priority, RwLock},
+    },
+};
+
+/// One action taken by [`emergency_stop`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SafeStateStep {
+    ForceStop,
+    ClearQueue,
+    JogIdle,
+    LaserOff,
+    SuctionCupOff,
+    GripperOff,
+    /// Looking up which EIO addresses are multiplexed as PWM outputs.
+    FindPwmOutputs,
+    PwmOff(u8),
+    EMotorOff(EMotorIndex),
+}
+
+/// What happened to a single [`SafeStateStep`].
+#[derive(Debug, Clone)]
+pub enum StepOutcome {
+    Done,
+    Failed(DobotError),
+    /// Not attempted because the arm stopped answering in an earlier step.
+    Skipped,
+}
+
+/// The outcome of every step of an [`emergency_stop`], in the order they
+/// were taken.
+#[derive(Debug, Clone)]
+pub struct SafeStateReport {
+    pub steps: Vec<(SafeStateStep, StepOutcome)>,
+}
+
+impl SafeStateReport {
+    /// Whether every step succeeded.
+    pub fn is_safe(&self) -> bool {
+        self.steps
+            .iter()
+            .all(|(_, outcome)| matches!(outcome, StepOutcome::Done))
+    }
+
+    /// Returns the steps that failed or were skipped.
+    pub fn incomplete(&self) -> impl Iterator<Item = &(SafeStateStep, StepOutcome)> {
+        self.steps
+            .iter()
+            .filter(|(_, outcome)| !matches!(outcome, StepOutcome::Done))
+    }
+}
+
+/// Which outputs [`emergency_stop`] switches off besides the motion queue and
+/// the end effectors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SafeStateOptions {
+    /// EIO addresses whose PWM duty cycle is set to zero. `None` looks up
+    /// every address multiplexed as a PWM output.
+    pub pwm_addresses: Option<Vec<u8>>,
+    /// Whether both external stepper motors are stopped.
+    pub e_motors: bool,
+}
+
+impl Default for SafeStateOptions {
+    fn default() -> Self {
+        Self {
+            pwm_addresses: None,
+            e_motors: true,
+        }
+    }
+}
+
+const END_EFFECTOR_OFF: TagEndEffectorState = TagEndEffectorState {
+    is_ctrl_enabled: false,
+    is_on: false,
+};
+
+/// Whether `error` means the arm cannot be reached at all, so further steps
+/// would only wait for their timeouts.
+fn is_unreachable(error: &DobotError) -> bool {
+    matches!(
+        error,
+        DobotError::IO
+            | DobotError::Timeout
+            | DobotError::NoResponse
+            | DobotError::Serial
+            | DobotError::SerialError(_)
+    )
+}
+
+struct Steps<'d, T: CommandSender> {
+    dobot: &'d mut Dobot<T>,
+    report: SafeStateReport,
+    unreachable: bool,
+}
+
+impl<T: CommandSender> Steps<'_, T> {
+    fn run<R>(
+        &mut self,
+        step: SafeStateStep,
+        action: impl FnOnce(&mut Dobot<T>) -> Result<R, DobotError>,
+    ) -> Option<R> {
+        if self.unreachable {
+            self.report.steps.push((step, StepOutcome::Skipped));
+            return None;
+        }
+        match action(self.dobot) {
+            Ok(value) => {
+                self.report.steps.push((step, StepOutcome::Done));
+                Some(value)
+            }
+            Err(e) => {
+                self.unreachable = is_unreachable(&e);
+                self.report.steps.push((step, StepOutcome::Failed(e)));
+                None
+            }
+        }
+    }
+}
+
+/// Brings the arm into a safe state: stops and clears the command queue,
+/// ends any JOG motion and switches off the laser, suction cup, gripper, PWM
+/// outputs and external motors.
+///
+/// The arm is locked once with [`Priority::Emergency`], so no other command
+/// runs in between the steps, and a poisoned lock is taken over rather than
+/// given up on. A failing step does not stop the remaining ones unless the
+/// arm stopped answering altogether.
+pub fn emergency_stop<T: CommandSender>(
+    dobot: &RwLock<Dobot<T>>,
+    options: &SafeStateOptions,
+) -> SafeStateReport {
+    let mut guard = dobot
+        .write_with_priority(Priority::Emergency)
+        .unwrap_or_else(PoisonError::into_inner);
+    let mut steps = Steps {
+        dobot: &mut *guard,
+        report: SafeStateReport { steps: Vec::new() },
+        unreachable: false,
+    };
+
+    steps.run(SafeStateStep::ForceStop, |dobot| {
+        dobot.execute::<SetQueuedCmdForceStopExec>(EmptyBody {})
+    });
+    steps.run(SafeStateStep::ClearQueue, |dobot| {
+        dobot.execute::<SetQueuedCmdClear>(EmptyBody {})
+    });
+    steps.run(SafeStateStep::JogIdle, |dobot| {
+        dobot.execute::<SetJogCmd>(TagJOGCmd {
+            is_joint: JogMode::Coordinate,
+            cmd: JogCmd::Idle,
+        })
+    });
+    steps.run(SafeStateStep::LaserOff, |dobot| {
+        dobot.execute::<SetEndEffectorLaser>(END_EFFECTOR_OFF)
+    });
+    steps.run(SafeStateStep::SuctionCupOff, |dobot| {
+        dobot.execute::<SetEndEffectorSuctionCup>(END_EFFECTOR_OFF)
+    });
+    steps.run(SafeStateStep::GripperOff, |dobot| {
+        dobot.execute::<SetEndEffectorGripper>(END_EFFECTOR_OFF)
+    });
+
+    let pwm_addresses = match &options.pwm_addresses {
+        Some(addresses) => addresses.clone(),
+        None => steps
+            .run(SafeStateStep::FindPwmOutputs, |dobot| {
+                let mut found = Vec::new();
+                for address in EIO_ADDRESSES {
+                    let multiplexing =
+                        dobot.execute::<GetIoMultiplexing>(TagIOAddress { address })?;
+                    if multiplexing.multiplex == IOFunction::Pwm {
+                        found.push(address);
+                    }
+                }
+                Ok(found)
+            })
+            .unwrap_or_default(),
+    };
+    for address in pwm_addresses {
+        steps.run(SafeStateStep::PwmOff(address), |dobot| {
+            let current = dobot.execute::<GetIoPwm>(TagIOAddress { address })?;
+            dobot.execute::<SetIoPwm>(TagIOPWM {
+                address,
+                frequency: current.frequency,
+                duty_cycle: 0.0,
+            })
+        });
+    }
+
+    if options.e_motors {
+        for index in [EMotorIndex::Stepper1, EMotorIndex::Stepper2] {
+            steps.run(SafeStateStep::EMotorOff(index), |dobot| {
+                dobot.execute::<SetEMotor>(TagEMotor {
+                    address: index,
+                    ins_enabled: false,
+                    speed: 0.0,
+                })
+            });
+        }
+    }
+
+    steps.report
+}
+
+/// Why a [`Watchdog`] made the arm safe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TripReason {
+    /// The application did not call [`Watchdog::heartbeat`] in time.
+    HeartbeatMissed,
+    /// The arm did not answer the watchdog's probes.
+    ConnectionLost,
+}
+
+/// A record of a [`Watchdog`] making the arm safe.
+#[derive(Debug, Clone)]
+pub struct WatchdogTrip {
+    pub reason: TripReason,
+    pub report: SafeStateReport,
+}
+
+/// Settings of a [`Watchdog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchdogOptions {
+    /// How long the application may go without a heartbeat.
+    pub heartbeat_timeout: Duration,
+    /// How often the arm is probed and the heartbeat checked.
+    pub check_interval: Duration,
+    /// Number of probes in a row the arm may miss before it counts as lost.
+    pub missed_probes: u32,
+    pub safe_state: SafeStateOptions,
+}
+
+impl Default for WatchdogOptions {
+    fn default() -> Self {
+        Self {
+            heartbeat_timeout: Duration::from_millis(500),
+            check_interval: Duration::from_millis(100),
+            missed_probes: 2,
+            safe_state: SafeStateOptions::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct WatchdogState {
+    last_heartbeat: Instant,
+    trip: Option<WatchdogTrip>,
+}
+
+/// Calls [`emergency_stop`] when the application stops sending heartbeats or
+/// the arm stops answering.
+///
+/// The watchdog checks the heartbeat and then probes the arm with
+/// [`Priority::Interactive`] on its own thread, so regular commands cannot
+/// starve the probe. Once tripped it stays idle until [`Watchdog::rearm`] is
+/// called.
+pub struct Watchdog {
+    state: Arc<Mutex<WatchdogState>>,
+    worker: PeriodicWorker,
+}
+
+impl Watchdog {
+    pub fn spawn<T: CommandSender + 'static>(
+        arm: DobotHandle<T>,
+        options: WatchdogOptions,
+    ) -> Self {
+        let state = Arc::new(Mutex::new(WatchdogState {
+            last_heartbeat: Instant::now(),
+            trip: None,
+        }));
+
+        let thread_state = Arc::clone(&state);
+        let mut missed = 0;
+        let worker = PeriodicWorker::spawn(options.check_interval, move || {
+            if lock(&thread_state).trip.is_some() {
+                missed = 0;
+                return;
+            }
+
+            // The heartbeat is checked before probing, so a caller holding
+            // the arm cannot hold up the trip.
+            let heartbeat_age = lock(&thread_state).last_heartbeat.elapsed();
+            let reason = if heartbeat_age > options.heartbeat_timeout {
+                TripReason::HeartbeatMissed
+            } else {
+                let probe =
+                    arm.execute_with_priority::<GetDeviceTime>(Priority::Interactive, EmptyBody {});
+                missed = if probe.is_ok() { 0 } else { missed + 1 };
+                if missed < options.missed_probes.max(1) {
+                    return;
+                }
+                TripReason::ConnectionLost
+            };
+            let report = emergency_stop(arm.dobot(), &options.safe_state);
+            lock(&thread_state).trip = Some(WatchdogTrip { reason, report });
+        });
+
+        Self { state, worker }
+    }
+
+    /// Tells the watchdog the application is still in control.
+    pub fn heartbeat(&self) {
+        lock(&self.state).last_heartbeat = Instant::now();
+    }
+
+    /// Returns the trip that made the arm safe, if any.
+    pub fn tripped(&self) -> Option<WatchdogTrip> {
+        lock(&self.state).trip.clone()
+    }
+
+    /// Clears a trip and restarts the heartbeat timeout.
+    pub fn rearm(&self) {
+        let mut state = lock(&self.state);
+        state.trip = None;
+        state.last_heartbeat = Instant::now();
+    }
+
+    pub fn stop(mut self) {
+        self.worker.stop();
+    }
+}
@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        },
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use crate::dobot::{
+        dobot_core_serial::{
+            handle::DobotHandle,
+            safety::{
+                SafeStateOptions, SafeStateStep, StepOutcome, TripReason, Watchdog,
+                WatchdogOptions, WatchdogTrip, emergency_stop,
+            },
+        },
+        dobot_trait::{
+            dobot_core::{
+                command_sender::simulated_command_sender::SimulatedCommandSender,
+                dobot_error::DobotError, sub_command_dobot::io_control::IOControl,
+            },
+            protocol::{
+                bodies::{
+                    tag_emotor::EMotorIndex,
+                    tag_io_multiplexing::{IOFunction, TagIOMultiplexing},
+                    tag_io_pwm::TagIOPWM,
+                },
+                command::{
+                    Command, SetEndEffectorLaser, SetIoPwm, SetJogCmd, SetQueuedCmdClear,
+                    SetQueuedCmdForceStopExec,
+                },
+            },
+        },
+    };
+
+    fn wait_for_trip(watchdog: &Watchdog) -> WatchdogTrip {
+        let started = Instant::now();
+        loop {
+            if let Some(trip) = watchdog.tripped() {
+                return trip;
+            }
+            assert!(
+                started.elapsed() < Duration::from_secs(5),
+                "watchdog never tripped"
+            );
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    fn fast_watchdog() -> WatchdogOptions {
+        WatchdogOptions {
+            heartbeat_timeout: Duration::from_millis(80),
+            check_interval: Duration::from_millis(10),
+            missed_probes: 2,
+            safe_state: SafeStateOptions {
+                pwm_addresses: Some(Vec::new()),
+                e_motors: true,
+            },
+        }
+    }
+
+    #[test]
+    fn test_emergency_stop_runs_every_step() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+        let mut io = arm.controller().io_controller;
+        io.set_io_multiplexing(
+            TagIOMultiplexing {
+                address: 4,
+                multiplex: IOFunction::Pwm,
+            },
+            false,
+        )
+        .unwrap();
+        io.set_io_pwm(
+            TagIOPWM {
+                address: 4,
+                frequency: 1000.0,
+                duty_cycle: 60.0,
+            },
+            false,
+        )
+        .unwrap();
+
+        let report = arm.emergency_stop();
+
+        assert!(report.is_safe(), "{:?}", report);
+        let steps: Vec<_> = report.steps.iter().map(|(step, _)| *step).collect();
+        assert_eq!(
+            steps,
+            vec![
+                SafeStateStep::ForceStop,
+                SafeStateStep::ClearQueue,
+                SafeStateStep::JogIdle,
+                SafeStateStep::LaserOff,
+                SafeStateStep::SuctionCupOff,
+                SafeStateStep::GripperOff,
+                SafeStateStep::FindPwmOutputs,
+                SafeStateStep::PwmOff(4),
+                SafeStateStep::EMotorOff(EMotorIndex::Stepper1),
+                SafeStateStep::EMotorOff(EMotorIndex::Stepper2),
+            ]
+        );
+
+        let commands = sim.commands();
+        let sent = |id: u8| commands.iter().filter(|c| c.id == id).collect::<Vec<_>>();
+        assert_eq!(sent(SetQueuedCmdForceStopExec::ID.into()).len(), 1);
+        assert_eq!(sent(SetQueuedCmdClear::ID.into()).len(), 1);
+        assert_eq!(sent(SetJogCmd::ID.into())[0].params, vec![0, 0]);
+        assert_eq!(sent(SetEndEffectorLaser::ID.into())[0].params, vec![0, 0]);
+        let pwm = sent(SetIoPwm::ID.into());
+        let last = &pwm.last().unwrap().params;
+        assert_eq!(last[0], 4);
+        assert_eq!(&last[1..5], &1000.0f32.to_le_bytes());
+        assert_eq!(&last[5..9], &0.0f32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_unreachable_arm_skips_remaining_steps() {
+        let sim = SimulatedCommandSender::new();
+        sim.set_connected(false);
+        let arm = DobotHandle::new(sim.clone());
+
+        let report = emergency_stop(arm.dobot(), &SafeStateOptions::default());
+
+        assert!(!report.is_safe());
+        assert!(matches!(
+            report.steps[0],
+            (
+                SafeStateStep::ForceStop,
+                StepOutcome::Failed(DobotError::IO)
+            )
+        ));
+        assert!(
+            report.steps[1..]
+                .iter()
+                .all(|(_, outcome)| matches!(outcome, StepOutcome::Skipped))
+        );
+        assert_eq!(report.incomplete().count(), report.steps.len());
+    }
+
+    #[test]
+    fn test_watchdog_trips_without_heartbeat() {
+        let sim = SimulatedCommandSender::new();
+        let watchdog = Watchdog::spawn(DobotHandle::new(sim.clone()), fast_watchdog());
+
+        let trip = wait_for_trip(&watchdog);
+
+        assert_eq!(trip.reason, TripReason::HeartbeatMissed);
+        assert!(trip.report.is_safe());
+        let force_stop: u8 = SetQueuedCmdForceStopExec::ID.into();
+        assert!(sim.commands().iter().any(|c| c.id == force_stop));
+    }
+
+    #[test]
+    fn test_watchdog_trips_when_connection_drops_and_can_be_rearmed() {
+        let sim = SimulatedCommandSender::new();
+        let watchdog = Watchdog::spawn(DobotHandle::new(sim.clone()), fast_watchdog());
+
+        for _ in 0..20 {
+            watchdog.heartbeat();
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(watchdog.tripped().is_none());
+
+        sim.set_connected(false);
+        let trip = wait_for_trip(&watchdog);
+        assert_eq!(trip.reason, TripReason::ConnectionLost);
+        assert!(!trip.report.is_safe());
+
+        sim.set_connected(true);
+        watchdog.rearm();
+        for _ in 0..10 {
+            watchdog.heartbeat();
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(watchdog.tripped().is_none());
+        watchdog.stop();
+    }
+
+    #[test]
+    fn test_watchdog_trips_while_another_thread_holds_the_arm() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+        let done = Arc::new(AtomicBool::new(false));
+
+        // Two threads take turns holding the arm, so a writer of normal
+        // priority is always waiting.
+        let busy: Vec<_> = (0..2)
+            .map(|_| {
+                let arm = arm.clone();
+                let done = Arc::clone(&done);
+                thread::spawn(move || {
+                    while !done.load(Ordering::SeqCst) {
+                        let _guard = arm.dobot().write().unwrap();
+                        thread::sleep(Duration::from_millis(20));
+                    }
+                })
+            })
+            .collect();
+        let started = Instant::now();
+        let watchdog = Watchdog::spawn(arm, fast_watchdog());
+
+        let trip = wait_for_trip(&watchdog);
+        let elapsed = started.elapsed();
+        done.store(true, Ordering::SeqCst);
+        for thread in busy {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(trip.reason, TripReason::HeartbeatMissed);
+        assert!(trip.report.is_safe());
+        assert!(
+            elapsed < Duration::from_millis(500),
+            "tripped after {elapsed:?}"
+        );
+    }
+}
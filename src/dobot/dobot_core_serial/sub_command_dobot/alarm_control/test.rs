@@ -9,7 +9,7 @@ mod tests {
                 sub_command_dobot::alarm_control::AlarmControl,
             },
             protocol::{
-                alarm::Alarm, command_id::AlarmIDs, CommunicationProtocolIDs, ProtocolError
+                alarm::{ActiveAlarm, Alarm}, command_id::AlarmIDs, CommunicationProtocolIDs, ProtocolError
             }, rwlock::RwLock,
         },
     };
@@ -38,15 +38,40 @@ mod tests {
         assert!(result.is_ok());
         let alarms = result.unwrap();
 
-        // Check specific alarms that should be active
-        assert_eq!(alarms[0], Some(Alarm::CommonResetting)); // Alarm 0
-        assert_eq!(alarms[16], Some(Alarm::PlanInvSingularity)); // This is the default for byte 1, but bit 1 (alarm 9) is checked below
-        // Check that other alarms are the default `CommonResetting`
-        for (i, alarm) in alarms.iter().enumerate() {
-            if i != 0 && i != 16 {
-                assert!(alarm.is_none());
-            }
-        }
+        // Check specific alarms that should be active, and no others
+        assert!(alarms.contains(Alarm::CommonResetting)); // Alarm 0
+        assert!(alarms.contains(Alarm::PlanInvSingularity)); // Alarm 16, byte 2 bit 0
+        assert_eq!(alarms.codes().collect::<Vec<_>>(), vec![0, 16]);
+    }
+
+    // Codes without a known alarm are kept instead of failing the call.
+    #[test]
+    fn test_get_active_alarms_keeps_unknown_codes() {
+        let mut mock_response_body = [0u8; 32];
+        mock_response_body[0x40 / 8] = 0b00000001; // LimitAxis1Pos
+        mock_response_body[0x0F / 8] |= 0b10000000; // 0x0F, unassigned
+        mock_response_body[0xF3 / 8] = 0b00001000; // 0xF3, beyond the first 16 bytes
+
+        let mock_response = create_response_packet(
+            CommunicationProtocolIDs::Alarm(AlarmIDs::GetAlarmState),
+            &mock_response_body,
+        );
+        let length = mock_response.len();
+        let mock_sender = MockCommandSender::new(mock_response, Ok(length));
+        let mutex = create_mock_sender_lock!(mock_sender);
+        let mut alarm_control = AlarmSerialControl::new(&mutex);
+
+        let alarms = alarm_control.get_active_alarms().unwrap();
+
+        assert_eq!(
+            alarms.iter().collect::<Vec<_>>(),
+            vec![
+                ActiveAlarm::Unknown(0x0F),
+                ActiveAlarm::Known(Alarm::LimitAxis1Pos),
+                ActiveAlarm::Unknown(0xF3),
+            ]
+        );
+        assert_eq!(alarms.alarms().collect::<Vec<_>>(), vec![Alarm::LimitAxis1Pos]);
     }
 
     // Test for `get_active_alarms` when the response buffer is too small.
@@ -128,4 +153,16 @@ mod tests {
             DobotError::Protocol(ProtocolError::ChecksumError)
         ));
     }
+
+    // Alarms are persisted under stable snake_case names.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_alarm_serde_names() {
+        let alarms = vec![Alarm::LimitAxis1Pos, Alarm::CommonResetting];
+
+        let json = serde_json::to_string(&alarms).unwrap();
+
+        assert_eq!(json, r#"["limit_axis1_pos","common_resetting"]"#);
+        assert_eq!(serde_json::from_str::<Vec<Alarm>>(&json).unwrap(), alarms);
+    }
 }
@@ -2,7 +2,6 @@
 mod test;
 
 use crate::dobot::dobot_trait::protocol::bodies::tag_empty_body::EmptyBody;
-use crate::dobot::dobot_trait::protocol::bodies::tag_queue::received::TagQueue;
 use crate::dobot::dobot_trait::{
     dobot_core::{
         command_sender::{CommandSender, Dobot},
@@ -12,9 +11,10 @@ use crate::dobot::dobot_trait::{
         ,
     },
     protocol::{
+        command::{GetArcParams, SetArcCmd, SetArcParams},
         bodies::{
             tag_arc_cmd::TagARCCmd, tag_arc_params::TagARCParams
-        }, command_id::ArcIDs, CommunicationProtocolIDs
+        }
     },
     rwlock::RwLock,
 };
@@ -35,13 +35,11 @@ impl<'a, T: CommandSender> ArcControl for ArcSerialControl<'a, T> {
         params: TagARCParams,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagARCParams, CommunicationProtocolIDs::Arc(ArcIDs::ArcParams), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetArcParams>(params)?));
         }
-        send_cmd!(send sender, TagARCParams, CommunicationProtocolIDs::Arc(ArcIDs::ArcParams), params, write=true)?;
+        sender.execute::<SetArcParams>(params)?;
         Ok(None)
     }
 
@@ -49,9 +47,8 @@ impl<'a, T: CommandSender> ArcControl for ArcSerialControl<'a, T> {
         &mut self,
     ) -> Result<TagARCParams, DobotError>
     {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 16];
-        let response = send_cmd!(get sender, TagARCParams, CommunicationProtocolIDs::Arc(ArcIDs::ArcParams), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response = sender.execute::<GetArcParams>(EmptyBody {})?;
         Ok(response)
     }
 
@@ -60,13 +57,11 @@ impl<'a, T: CommandSender> ArcControl for ArcSerialControl<'a, T> {
         cmd: TagARCCmd,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagARCCmd, CommunicationProtocolIDs::Arc(ArcIDs::ArcCmd), cmd, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetArcCmd>(cmd)?));
         }
-        send_cmd!(send sender, TagARCCmd, CommunicationProtocolIDs::Arc(ArcIDs::ArcParams), cmd, write=true)?;
+        sender.execute::<SetArcCmd>(cmd)?;
         Ok(None)
     }
 }
@@ -7,7 +7,13 @@ use crate::dobot::dobot_trait::{dobot_core::{
         sub_command_dobot::
             calibration_control::CalibrationControl
         ,
-    }, protocol::{bodies::{general_request::GeneralRequest, general_response::GeneralResponse, tag_empty_body::EmptyBody}, command_id::CalIDs, CommunicationProtocolIDs, ProtocolError}, rwlock::RwLock};
+    }, protocol::{
+        command::{
+            GetAngleSensorCoef, GetAngleSensorStaticError, GetBaseDecoderStaticError,
+            GetRhandCalibrateValue, SetAngleSensorCoef, SetAngleSensorStaticError,
+            SetBaseDecoderStaticError, SetRhandCalibrateValue,
+        },
+        bodies::{tag_calibration::{TagAngleSensorParams, TagCalibrationValue}, tag_empty_body::EmptyBody}, ProtocolError}, rwlock::RwLock};
 
 pub struct CalibrationSerialControl<'a, T: CommandSender> {
     command_sender: &'a RwLock<Dobot<T>>,
@@ -25,34 +31,84 @@ impl<'a, T: CommandSender> CalibrationControl for CalibrationSerialControl<'a, T
         rear_arm_angle_error: f32,
         front_arm_angle_error: f32,
     ) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut request_buffer = [0u8;8];
-        request_buffer[..4].copy_from_slice(&rear_arm_angle_error.to_le_bytes());
-        request_buffer[4..8].copy_from_slice(&front_arm_angle_error.to_le_bytes());
-        let request = GeneralRequest {  params: &request_buffer};
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::Cal(CalIDs::AngleSensorStaticError), request, write=true)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagAngleSensorParams {
+            rear_arm: rear_arm_angle_error,
+            front_arm: front_arm_angle_error,
+        };
+        sender.execute::<SetAngleSensorStaticError>(request)?;
         Ok(())
     }
 
     fn get_angle_sensor_static_error(&mut self) -> Result<(f32, f32), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8;8];
-        let response = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::Cal(CalIDs::AngleSensorStaticError), &mut response_buffer)?;
-        if response.params.len() < 8 {
-            return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
-        }
-
-        let rear_angle_buffer: [u8; 4] = match (&response.params[..4]).try_into() {
-            Ok(x) => x,
-            Err(_) => return Err(DobotError::Protocol(ProtocolError::BufferTooSmall)),
+        let mut sender = create_sender!(self.command_sender)?;
+        let response = sender.execute::<GetAngleSensorStaticError>(EmptyBody {})?;
+        read_f32_pair(response.params())
+    }
+
+    fn set_angle_sensor_coef(
+        &mut self,
+        rear_arm_angle_coef: f32,
+        front_arm_angle_coef: f32,
+    ) -> Result<(), DobotError> {
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagAngleSensorParams {
+            rear_arm: rear_arm_angle_coef,
+            front_arm: front_arm_angle_coef,
         };
-        let front_angle_buffer: [u8; 4] = match (&response.params[4..8]).try_into() {
-            Ok(x) => x,
-            Err(_) => return Err(DobotError::Protocol(ProtocolError::BufferTooSmall)),
+        sender.execute::<SetAngleSensorCoef>(request)?;
+        Ok(())
+    }
+
+    fn get_angle_sensor_coef(&mut self) -> Result<(f32, f32), DobotError> {
+        let mut sender = create_sender!(self.command_sender)?;
+        let response = sender.execute::<GetAngleSensorCoef>(EmptyBody {})?;
+        read_f32_pair(response.params())
+    }
+
+    fn set_base_decoder_static_error(&mut self, base_decoder_error: f32) -> Result<(), DobotError> {
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagCalibrationValue {
+            value: base_decoder_error,
         };
-        let rear_angle = f32::from_le_bytes(rear_angle_buffer);
-        let front_angle = f32::from_le_bytes(front_angle_buffer);
+        sender.execute::<SetBaseDecoderStaticError>(request)?;
+        Ok(())
+    }
+
+    fn get_base_decoder_static_error(&mut self) -> Result<f32, DobotError> {
+        let mut sender = create_sender!(self.command_sender)?;
+        let response = sender.execute::<GetBaseDecoderStaticError>(EmptyBody {})?;
+        read_f32(response.params())
+    }
+
+    fn set_rhand_calibrate_value(&mut self, rhand_calibrate_value: f32) -> Result<(), DobotError> {
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagCalibrationValue {
+            value: rhand_calibrate_value,
+        };
+        sender.execute::<SetRhandCalibrateValue>(request)?;
+        Ok(())
+    }
+
+    fn get_rhand_calibrate_value(&mut self) -> Result<f32, DobotError> {
+        let mut sender = create_sender!(self.command_sender)?;
+        let response = sender.execute::<GetRhandCalibrateValue>(EmptyBody {})?;
+        read_f32(response.params())
+    }
+}
+
+fn read_f32(params: &[u8]) -> Result<f32, DobotError> {
+    let buffer: [u8; 4] = params
+        .get(..4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(DobotError::Protocol(ProtocolError::BufferTooSmall))?;
+    Ok(f32::from_le_bytes(buffer))
+}
 
-        Ok((rear_angle, front_angle))
+/// Reads the (rear arm, front arm) values of the angle sensor commands.
+fn read_f32_pair(params: &[u8]) -> Result<(f32, f32), DobotError> {
+    if params.len() < 8 {
+        return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
     }
+    Ok((read_f32(&params[..4])?, read_f32(&params[4..8])?))
 }
@@ -117,4 +117,87 @@ mod tests {
             DobotError::Protocol(ProtocolError::ChecksumError)
         ));
     }
+
+    // --- Tests for the angle sensor coefficients, base decoder and right-hand values ---
+
+    #[test]
+    fn test_get_angle_sensor_coef_ok() {
+        let mut response_buffer = [0u8; 8];
+        response_buffer[..4].copy_from_slice(&1.01f32.to_le_bytes());
+        response_buffer[4..8].copy_from_slice(&0.99f32.to_le_bytes());
+        let mock_response = create_response_packet(
+            CommunicationProtocolIDs::Cal(CalIDs::AngleSensorCoef),
+            &response_buffer,
+        );
+        let length = mock_response.len();
+        let mock_sender = MockCommandSender::new(mock_response, Ok(length));
+        let mutex = create_mock_sender_lock!(mock_sender);
+        let mut calibration_control = CalibrationSerialControl::new(&mutex);
+
+        let result = calibration_control.get_angle_sensor_coef();
+
+        assert_eq!(result.unwrap(), (1.01, 0.99));
+    }
+
+    #[test]
+    fn test_set_angle_sensor_coef_ok() {
+        let mock_response =
+            create_response_packet(CommunicationProtocolIDs::Cal(CalIDs::AngleSensorCoef), b"");
+        let length = mock_response.len();
+        let mock_sender = MockCommandSender::new(mock_response, Ok(length));
+        let mutex = create_mock_sender_lock!(mock_sender);
+        let mut calibration_control = CalibrationSerialControl::new(&mutex);
+
+        assert!(calibration_control.set_angle_sensor_coef(1.0, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_get_base_decoder_static_error_ok() {
+        let mock_response = create_response_packet(
+            CommunicationProtocolIDs::Cal(CalIDs::BaseDecoderStaticError),
+            &(-0.75f32).to_le_bytes(),
+        );
+        let length = mock_response.len();
+        let mock_sender = MockCommandSender::new(mock_response, Ok(length));
+        let mutex = create_mock_sender_lock!(mock_sender);
+        let mut calibration_control = CalibrationSerialControl::new(&mutex);
+
+        let result = calibration_control.get_base_decoder_static_error();
+
+        assert_eq!(result.unwrap(), -0.75);
+    }
+
+    #[test]
+    fn test_set_rhand_calibrate_value_ok() {
+        let mock_response = create_response_packet(
+            CommunicationProtocolIDs::Cal(CalIDs::RhandCalibrateValue),
+            b"",
+        );
+        let length = mock_response.len();
+        let mock_sender = MockCommandSender::new(mock_response, Ok(length));
+        let mutex = create_mock_sender_lock!(mock_sender);
+        let mut calibration_control = CalibrationSerialControl::new(&mutex);
+
+        assert!(calibration_control.set_rhand_calibrate_value(2.5).is_ok());
+    }
+
+    // A right-hand value shorter than an f32 is rejected.
+    #[test]
+    fn test_get_rhand_calibrate_value_invalid_response() {
+        let mock_response = create_response_packet(
+            CommunicationProtocolIDs::Cal(CalIDs::RhandCalibrateValue),
+            &[1, 2],
+        );
+        let length = mock_response.len();
+        let mock_sender = MockCommandSender::new(mock_response, Ok(length));
+        let mutex = create_mock_sender_lock!(mock_sender);
+        let mut calibration_control = CalibrationSerialControl::new(&mutex);
+
+        let result = calibration_control.get_rhand_calibrate_value();
+
+        assert!(matches!(
+            result,
+            Err(DobotError::Protocol(ProtocolError::BufferTooSmall))
+        ));
+    }
 }
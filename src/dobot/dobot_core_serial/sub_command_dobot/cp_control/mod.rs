@@ -5,15 +5,13 @@ use crate::dobot::dobot_trait::{
     dobot_core::{
         command_sender::{CommandSender, Dobot},
         dobot_error::DobotError,
-        sub_command_dobot::{
-            cp_control::CPControl, home_control::HomeControl, jog_control::JOGControl,
-            ptp_control::PTPControl,
-        },
+        sub_command_dobot::cp_control::CPControl,
     },
     protocol::{
+        command::{GetCpParams, SetCpCmd, SetCpParams, SetCpleCmd},
         bodies::{
-            general_response::GeneralResponse, tag_auto_leveling_params::TagAutoLevelingParams, tag_cp_cmd::TagCPCmd, tag_cp_params::TagCPParams, tag_empty_body::EmptyBody, tag_home_cmd::TagHomeCmd, tag_home_params::TagHomeParams, tag_queue::received::TagQueue
-        }, command_id::{CpIDs, HomeIDs}, CommunicationProtocolIDs, ProtocolError
+            tag_cp_cmd::TagCPCmd, tag_cp_params::TagCPParams, tag_empty_body::EmptyBody
+        }
     },
     rwlock::RwLock,
 };
@@ -34,13 +32,11 @@ impl<'a, T: CommandSender> CPControl for CPSerialControl<'a, T> {
         cmd: TagCPCmd,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagCPCmd, CommunicationProtocolIDs::Cp(CpIDs::CpCmd), cmd, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetCpCmd>(cmd)?));
         }
-        send_cmd!(send sender, TagCPCmd, CommunicationProtocolIDs::Cp(CpIDs::CpCmd), cmd, write=true)?;
+        sender.execute::<SetCpCmd>(cmd)?;
         Ok(None)
     }
 
@@ -49,13 +45,11 @@ impl<'a, T: CommandSender> CPControl for CPSerialControl<'a, T> {
         params: TagCPParams,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagCPParams, CommunicationProtocolIDs::Cp(CpIDs::CpParams), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetCpParams>(params)?));
         }
-        send_cmd!(send sender, TagCPParams, CommunicationProtocolIDs::Cp(CpIDs::CpParams), params, write=true)?;
+        sender.execute::<SetCpParams>(params)?;
         Ok(None)
     }
 
@@ -63,10 +57,9 @@ impl<'a, T: CommandSender> CPControl for CPSerialControl<'a, T> {
         &mut self,
     ) -> Result<TagCPParams, DobotError>
     {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 13];
+        let mut sender = create_sender!(self.command_sender)?;
 
-        let result = send_cmd!(get sender, TagCPParams, CommunicationProtocolIDs::Cp(CpIDs::CpParams), &mut response_buffer)?;
+        let result = sender.execute::<GetCpParams>(EmptyBody {})?;
         Ok(result)
     }
 
@@ -75,13 +68,11 @@ impl<'a, T: CommandSender> CPControl for CPSerialControl<'a, T> {
         cmd: TagCPCmd,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagCPCmd, CommunicationProtocolIDs::Cp(CpIDs::CpleCmd), cmd, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetCpleCmd>(cmd)?));
         }
-        send_cmd!(send sender, TagCPCmd, CommunicationProtocolIDs::Cp(CpIDs::CpleCmd), cmd, write=true)?;
+        sender.execute::<SetCpleCmd>(cmd)?;
         Ok(None)
     }
 }
@@ -8,12 +8,15 @@ use crate::dobot::dobot_trait::{
         sub_command_dobot::device_control::DeviceControl,
     },
     protocol::{
-        Body, CommunicationProtocolIDs, ProtocolError,
+        command::{
+            GetDeviceId, GetDeviceName, GetDeviceSn, GetDeviceTime, GetDeviceVersion,
+            GetDeviceWithRail, SetDeviceName, SetDeviceSn, SetDeviceWithRail,
+        },
+        ProtocolError,
         bodies::{
-            general_request::GeneralRequest, general_response::GeneralResponse,
+            general_request::GeneralRequest,
             tag_empty_body::EmptyBody, tag_with_l::TagWithL,
         },
-        command_id::DeviceInfoIDs,
     },
     rwlock::RwLock,
 };
@@ -34,41 +37,40 @@ impl<'a, T: CommandSender> DeviceControl for DeviceSerialControl<'a, T> {
             params: device_serial_number,
         };
 
-        let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Sn), request_body, write=true)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        sender.execute::<SetDeviceSn>(request_body)?;
 
         Ok(())
     }
 
     fn get_device_sn(&mut self, buffer: &mut [u8]) -> Result<usize, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Sn), buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetDeviceSn>(EmptyBody {})?;
 
-        Ok(response_body.params.len())
+        Ok(response_body.copy_to(buffer)?)
     }
 
     fn set_device_name(&mut self, device_name: &[u8]) -> Result<(), DobotError> {
         let request_body = GeneralRequest {
             params: device_name,
         };
-        let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Name), request_body, write=true)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        sender.execute::<SetDeviceName>(request_body)?;
 
         Ok(())
     }
 
     fn get_device_name(&mut self, buffer: &mut [u8]) -> Result<usize, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Name), buffer)?;
-        Ok(response_body.params.len())
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetDeviceName>(EmptyBody {})?;
+        Ok(response_body.copy_to(buffer)?)
     }
 
     fn get_device_version(&mut self) -> Result<(u8, u8, u8), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 3];
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Version), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetDeviceVersion>(EmptyBody {})?;
 
-        let params = response_body.params;
+        let params = response_body.params();
         if params.len() < 3 {
             return Err(DobotError::Protocol(ProtocolError::InvalidEnumValue));
         }
@@ -77,30 +79,28 @@ impl<'a, T: CommandSender> DeviceControl for DeviceSerialControl<'a, T> {
     }
 
     fn set_device_rail_capability(&mut self, params: TagWithL) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
 
-        send_cmd!(send sender, TagWithL, CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::WithRail), params, write=true)?;
+        sender.execute::<SetDeviceWithRail>(params)?;
         Ok(())
     }
 
     fn get_device_rail_capability(&mut self) -> Result<bool, DobotError> {
-        let mut response_buffer = [0u8; 1];
-        let sender = create_sender!(self.command_sender)?;
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::WithRail), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetDeviceWithRail>(EmptyBody {})?;
 
-        if response_body.params.is_empty() {
+        if response_body.params().is_empty() {
             return Err(DobotError::Protocol(ProtocolError::InvalidOperation));
         }
 
-        Ok(response_body.params[0] != 0)
+        Ok(response_body.params()[0] != 0)
     }
 
     fn get_device_time(&mut self) -> Result<u32, DobotError> {
-        let mut response_buffer = [0u8; 4];
-        let sender = create_sender!(self.command_sender)?;
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Time), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetDeviceTime>(EmptyBody {})?;
 
-        let params = response_body.params;
+        let params = response_body.params();
         if params.len() < 4 {
             return Err(DobotError::Protocol(ProtocolError::InvalidOperation));
         }
@@ -110,11 +110,10 @@ impl<'a, T: CommandSender> DeviceControl for DeviceSerialControl<'a, T> {
     }
 
     fn get_device_id(&mut self) -> Result<(u32, u32, u32), DobotError> {
-        let mut response_buffer = [0u8; 12];
-        let sender = create_sender!(self.command_sender)?;
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Id), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetDeviceId>(EmptyBody {})?;
 
-        let params = response_body.params;
+        let params = response_body.params();
         if params.len() < 12 {
             return Err(DobotError::Protocol(ProtocolError::InvalidOperation));
         }
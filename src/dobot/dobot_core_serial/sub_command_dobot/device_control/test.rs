@@ -267,4 +267,23 @@ mod tests {
             DobotError::Protocol(ProtocolError::ChecksumError)
         ));
     }
+
+    #[test]
+    fn test_get_device_time_rejects_reply_to_other_command() {
+        let mock_response = create_response_packet(
+            CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Sn),
+            &1234u32.to_le_bytes(),
+        );
+        let length = mock_response.len();
+        let mock_sender = MockCommandSender::new(mock_response, Ok(length));
+        let mutex = create_mock_sender_lock!(mock_sender);
+        let mut device_control = DeviceSerialControl::new(&mutex);
+
+        let result = device_control.get_device_time();
+
+        assert!(matches!(
+            result,
+            Err(DobotError::Protocol(ProtocolError::UnexpectedResponse { .. }))
+        ));
+    }
 }
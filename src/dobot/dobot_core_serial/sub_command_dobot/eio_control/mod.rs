@@ -1,8 +1,6 @@
 #[cfg(feature = "std")]
 mod test;
 
-use crate::dobot::dobot_trait::protocol::bodies::tag_empty_body::EmptyBody;
-use crate::dobot::dobot_trait::protocol::bodies::tag_queue::received::TagQueue;
 use crate::dobot::dobot_trait::{
     dobot_core::{
         command_sender::{CommandSender, Dobot},
@@ -12,13 +10,28 @@ use crate::dobot::dobot_trait::{
         ,
     },
     protocol::{
+        command::{
+            GetColorSensor, GetIoAdc, GetIoDi, GetIoDo, GetIoMultiplexing, GetIoPwm, GetIrSwitch,
+            SetColorSensor, SetEMotor, SetIoDo, SetIoMultiplexing, SetIoPwm, SetIrSwitch,
+        },
         bodies::{
-            general_request::GeneralRequest, general_response::GeneralResponse, level::Level, tag_color::TagColor, tag_device::TagDevice, tag_emotor::TagEMotor, tag_io_do::TagIODO, tag_io_multiplexing::TagIOMultiplexing, tag_io_pwm::TagIOPWM
-        }, command_id::EioIDs, CommunicationProtocolIDs, ProtocolError
+            level::Level, tag_color::TagColor, tag_device::TagDevice, tag_emotor::TagEMotor, tag_io_address::TagIOAddress, tag_io_do::TagIODO, tag_io_multiplexing::TagIOMultiplexing, tag_io_pwm::TagIOPWM
+        }, ProtocolError
     },
     rwlock::RwLock,
 };
 
+/// Value of an address-indexed EIO read. The firmware replies with the
+/// address followed by the value; a reply holding the value alone is also
+/// accepted.
+fn eio_value(params: &[u8], value_len: usize) -> Result<&[u8], DobotError> {
+    match params.len() {
+        len if len == value_len => Ok(params),
+        len if len == value_len + 1 => Ok(&params[1..]),
+        _ => Err(DobotError::Protocol(ProtocolError::BufferTooSmall)),
+    }
+}
+
 pub struct IOSerialControl<'a, T: CommandSender> {
     command_sender: &'a RwLock<Dobot<T>>,
 }
@@ -35,13 +48,11 @@ impl<'a, T: CommandSender> IOControl for IOSerialControl<'a, T> {
         params: TagIOMultiplexing,
         is_queued: bool
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagIOMultiplexing, CommunicationProtocolIDs::Eio(EioIDs::IoMultiplexing), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetIoMultiplexing>(params)?));
         }
-        send_cmd!(send sender, TagIOMultiplexing, CommunicationProtocolIDs::Eio(EioIDs::IoMultiplexing), params, write=true)?;
+        sender.execute::<SetIoMultiplexing>(params)?;
         Ok(None)
     }
 
@@ -52,10 +63,9 @@ impl<'a, T: CommandSender> IOControl for IOSerialControl<'a, T> {
         TagIOMultiplexing,
         DobotError,
     > {
-        let sender = create_sender!(self.command_sender)?;
-        let request = GeneralRequest { params: &[address]};
-        let mut response_buffer = [0u8; 2];
-        let response_body = send_cmd!(get sender, GeneralRequest, TagIOMultiplexing, CommunicationProtocolIDs::Eio(EioIDs::IoMultiplexing), request, &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagIOAddress { address };
+        let response_body = sender.execute::<GetIoMultiplexing>(request)?;
 
         Ok(response_body)
     }
@@ -65,14 +75,11 @@ impl<'a, T: CommandSender> IOControl for IOSerialControl<'a, T> {
         params: TagIODO,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagIODO, CommunicationProtocolIDs::Eio(EioIDs::Iodo), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetIoDo>(params)?));
         }
-
-        send_cmd!(send sender, TagIODO, CommunicationProtocolIDs::Eio(EioIDs::Iodo), params, write=true)?;
+        sender.execute::<SetIoDo>(params)?;
         Ok(None)
     }
 
@@ -80,12 +87,12 @@ impl<'a, T: CommandSender> IOControl for IOSerialControl<'a, T> {
         &mut self,
         address: u8,
     ) -> Result<Level, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let request = GeneralRequest { params: &[address]};
-        let mut response_buffer = [0u8; 1];
-        let response_body = send_cmd!(get sender, GeneralRequest, Level, CommunicationProtocolIDs::Eio(EioIDs::IoMultiplexing), request, &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagIOAddress { address };
+        let response_body = sender.execute::<GetIoDo>(request)?;
+        let level = eio_value(response_body.params(), 1)?;
 
-        Ok(response_body)
+        Ok(Level::try_from(level[0])?)
     }
 
     fn set_io_pwm(
@@ -93,13 +100,11 @@ impl<'a, T: CommandSender> IOControl for IOSerialControl<'a, T> {
         params: TagIOPWM,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagIOPWM, CommunicationProtocolIDs::Eio(EioIDs::IoPwm), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetIoPwm>(params)?));
         }
-        send_cmd!(send sender, TagIOPWM, CommunicationProtocolIDs::Eio(EioIDs::IoPwm), params, write=true)?;
+        sender.execute::<SetIoPwm>(params)?;
         Ok(None)
     }
 
@@ -107,10 +112,9 @@ impl<'a, T: CommandSender> IOControl for IOSerialControl<'a, T> {
         &mut self,
         address: u8,
     ) -> Result<TagIOPWM, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let request = GeneralRequest { params: &[address]};
-        let mut response_buffer = [0u8; 9];
-        let response_body = send_cmd!(get sender, GeneralRequest, TagIOPWM, CommunicationProtocolIDs::Eio(EioIDs::IoPwm), request, &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagIOAddress { address };
+        let response_body = sender.execute::<GetIoPwm>(request)?;
 
         Ok(response_body)
     }
@@ -119,23 +123,20 @@ impl<'a, T: CommandSender> IOControl for IOSerialControl<'a, T> {
         &mut self,
         address: u8,
     ) -> Result<Level, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let request = GeneralRequest { params: &[address]};
-        let mut response_buffer = [0u8; 1];
-        let response_body = send_cmd!(get sender, GeneralRequest, Level, CommunicationProtocolIDs::Eio(EioIDs::Iodi), request, &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagIOAddress { address };
+        let response_body = sender.execute::<GetIoDi>(request)?;
+        let level = eio_value(response_body.params(), 1)?;
 
-        Ok(response_body)
+        Ok(Level::try_from(level[0])?)
     }
 
     fn get_io_adc(&mut self, address: u8) -> Result<u16, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let request = GeneralRequest { params: &[address]};
-        let mut response_buffer = [0u8; 2];
-        let response_body = send_cmd!(get sender, GeneralRequest, GeneralResponse, CommunicationProtocolIDs::Eio(EioIDs::Iodi), request, &mut response_buffer)?;
-        if response_body.params.len() != 2 {
-            return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
-        }
-        let adc_value = u16::from_le_bytes(response_buffer);
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagIOAddress { address };
+        let response_body = sender.execute::<GetIoAdc>(request)?;
+        let params = eio_value(response_body.params(), 2)?;
+        let adc_value = u16::from_le_bytes([params[0], params[1]]);
 
         Ok(adc_value)
     }
@@ -145,13 +146,11 @@ impl<'a, T: CommandSender> IOControl for IOSerialControl<'a, T> {
         params: TagEMotor,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagEMotor, CommunicationProtocolIDs::Eio(EioIDs::Emotor), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetEMotor>(params)?));
         }
-        send_cmd!(send sender, TagEMotor, CommunicationProtocolIDs::Eio(EioIDs::Emotor), params, write=true)?;
+        sender.execute::<SetEMotor>(params)?;
         Ok(None)
     }
 
@@ -160,13 +159,11 @@ impl<'a, T: CommandSender> IOControl for IOSerialControl<'a, T> {
         params: TagDevice,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagDevice, CommunicationProtocolIDs::Eio(EioIDs::ColorSensor), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetColorSensor>(params)?));
         }
-        send_cmd!(send sender, TagDevice, CommunicationProtocolIDs::Eio(EioIDs::ColorSensor), params, write=true)?;
+        sender.execute::<SetColorSensor>(params)?;
         Ok(None)
     }
 
@@ -174,10 +171,9 @@ impl<'a, T: CommandSender> IOControl for IOSerialControl<'a, T> {
         &mut self,
         port: u8,
     ) -> Result<TagColor, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let request = GeneralRequest { params: &[port]};
-        let mut response_buffer = [0u8; 3];
-        let response_body = send_cmd!(get sender, GeneralRequest, TagColor, CommunicationProtocolIDs::Eio(EioIDs::ColorSensor), request, &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagIOAddress { address: port };
+        let response_body = sender.execute::<GetColorSensor>(request)?;
 
         Ok(response_body)
     }
@@ -187,22 +183,19 @@ impl<'a, T: CommandSender> IOControl for IOSerialControl<'a, T> {
         params: TagDevice,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagDevice, CommunicationProtocolIDs::Eio(EioIDs::IrSwitch), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetIrSwitch>(params)?));
         }
-        send_cmd!(send sender, TagDevice, CommunicationProtocolIDs::Eio(EioIDs::IrSwitch), params, write=true)?;
+        sender.execute::<SetIrSwitch>(params)?;
         Ok(None)
     }
 
     fn get_ir_switch(&mut self, port: u8) -> Result<bool, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let request = GeneralRequest { params: &[port]};
-        let mut response_buffer = [0u8; 1];
-        let response_body = send_cmd!(get sender, GeneralRequest, GeneralResponse, CommunicationProtocolIDs::Eio(EioIDs::IrSwitch), request, &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagIOAddress { address: port };
+        let response_body = sender.execute::<GetIrSwitch>(request)?;
 
-        Ok(response_body.params[0] != 0)
+        Ok(response_body.params()[0] != 0)
     }
 }
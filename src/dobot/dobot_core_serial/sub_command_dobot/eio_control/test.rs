@@ -161,7 +161,7 @@ mod tests {
     #[test]
     fn test_get_io_adc_ok() {
         let adc_val: u16 = 1023;
-        let mock_response = create_response_packet(CommunicationProtocolIDs::Eio(EioIDs::Iodi), &adc_val.to_le_bytes());
+        let mock_response = create_response_packet(CommunicationProtocolIDs::Eio(EioIDs::IoAdc), &adc_val.to_le_bytes());
         let length = mock_response.len();
         let mock_sender = MockCommandSender::new(mock_response, Ok(length));
         let mutex = create_mock_sender_lock!(mock_sender);
@@ -172,9 +172,35 @@ mod tests {
         assert_eq!(result.unwrap(), adc_val);
     }
 
+    #[test]
+    fn test_get_io_di_address_prefixed() {
+        let mock_response = create_response_packet(CommunicationProtocolIDs::Eio(EioIDs::Iodi), &[3, 1]);
+        let length = mock_response.len();
+        let mock_sender = MockCommandSender::new(mock_response, Ok(length));
+        let mutex = create_mock_sender_lock!(mock_sender);
+        let mut io_control = IOSerialControl::new(&mutex);
+
+        let result = io_control.get_io_di(3);
+        assert_eq!(result.unwrap(), Level::High);
+    }
+
+    #[test]
+    fn test_get_io_adc_address_prefixed() {
+        let mut response_body = vec![3];
+        response_body.extend(4095u16.to_le_bytes());
+        let mock_response = create_response_packet(CommunicationProtocolIDs::Eio(EioIDs::IoAdc), &response_body);
+        let length = mock_response.len();
+        let mock_sender = MockCommandSender::new(mock_response, Ok(length));
+        let mutex = create_mock_sender_lock!(mock_sender);
+        let mut io_control = IOSerialControl::new(&mutex);
+
+        let result = io_control.get_io_adc(3);
+        assert_eq!(result.unwrap(), 4095);
+    }
+
     #[test]
     fn test_get_io_adc_invalid_response() {
-        let mock_response = create_response_packet(CommunicationProtocolIDs::Eio(EioIDs::Iodi), &[1]);
+        let mock_response = create_response_packet(CommunicationProtocolIDs::Eio(EioIDs::IoAdc), &[1]);
         let length = mock_response.len();
         let mock_sender = MockCommandSender::new(mock_response, Ok(length));
         let mutex = create_mock_sender_lock!(mock_sender);
@@ -5,21 +5,33 @@ use crate::dobot::dobot_trait::{
     dobot_core::{
         command_sender::{CommandSender, Dobot},
         dobot_error::DobotError,
-        sub_command_dobot::{end_effector_control::EndEffectorControl, home_control::HomeControl},
+        sub_command_dobot::end_effector_control::EndEffectorControl,
     },
     protocol::{
-        CommunicationProtocolIDs, ProtocolError,
+        command::{
+            GetEndEffectorGripper, GetEndEffectorLaser, GetEndEffectorParams,
+            GetEndEffectorSuctionCup, SetEndEffectorGripper, SetEndEffectorLaser,
+            SetEndEffectorParams, SetEndEffectorSuctionCup,
+        },
+        ProtocolError,
         bodies::{
-            general_request::GeneralRequest, general_response::GeneralResponse,
-            tag_auto_leveling_params::TagAutoLevelingParams, tag_empty_body::EmptyBody,
-            tag_end_effector_params::TagEndEffectorParams, tag_home_cmd::TagHomeCmd,
-            tag_home_params::TagHomeParams, tag_queue::received::TagQueue,
+            tag_empty_body::EmptyBody, tag_end_effector_params::TagEndEffectorParams,
+            tag_end_effector_state::TagEndEffectorState,
         },
-        command_id::{EndEffectorIDs, HomeIDs},
     },
-    rwlock::RwLock,
+    rwlock::{Priority, RwLock},
 };
 
+/// Switching a tool off right away is part of making the arm safe, so it
+/// is let in ahead of other commands.
+fn switch_priority(on: bool, is_queued: bool) -> Priority {
+    if on || is_queued {
+        Priority::Normal
+    } else {
+        Priority::Emergency
+    }
+}
+
 pub struct EndEffectorSerialControl<'a, T: CommandSender> {
     command_sender: &'a RwLock<Dobot<T>>,
 }
@@ -37,18 +49,16 @@ impl<'a, T: CommandSender> EndEffectorControl for EndEffectorSerialControl<'a, T
         grip: bool,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let params_body = [enable as u8, grip as u8];
-        let request_body = GeneralRequest {
-            params: &params_body,
+        let mut sender = create_sender!(self.command_sender, switch_priority(enable && grip, is_queued))?;
+        let request_body = TagEndEffectorState {
+            is_ctrl_enabled: enable,
+            is_on: grip,
         };
 
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, GeneralRequest, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::Gripper), request_body, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetEndEffectorGripper>(request_body)?));
         }
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::Gripper), request_body, write=true)?;
+        sender.execute::<SetEndEffectorGripper>(request_body)?;
         Ok(None)
     }
 
@@ -58,18 +68,16 @@ impl<'a, T: CommandSender> EndEffectorControl for EndEffectorSerialControl<'a, T
         suck: bool,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let params_body = [enable as u8, suck as u8];
-        let request_body = GeneralRequest {
-            params: &params_body,
+        let mut sender = create_sender!(self.command_sender, switch_priority(enable && suck, is_queued))?;
+        let request_body = TagEndEffectorState {
+            is_ctrl_enabled: enable,
+            is_on: suck,
         };
 
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, GeneralRequest, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::SuctionCup), request_body, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetEndEffectorSuctionCup>(request_body)?));
         }
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::SuctionCup), request_body, write=true)?;
+        sender.execute::<SetEndEffectorSuctionCup>(request_body)?;
         Ok(None)
     }
 
@@ -79,55 +87,50 @@ impl<'a, T: CommandSender> EndEffectorControl for EndEffectorSerialControl<'a, T
         on: bool,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let params_body = [enable_ctrl as u8, on as u8];
-        let request_body = GeneralRequest {
-            params: &params_body,
+        let mut sender = create_sender!(self.command_sender, switch_priority(enable_ctrl && on, is_queued))?;
+        let request_body = TagEndEffectorState {
+            is_ctrl_enabled: enable_ctrl,
+            is_on: on,
         };
 
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, GeneralRequest, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::Laser), request_body, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetEndEffectorLaser>(request_body)?));
         }
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::Laser), request_body, write=true)?;
+        sender.execute::<SetEndEffectorLaser>(request_body)?;
         Ok(None)
     }
 
     fn get_gripper_state(&mut self) -> Result<(bool, bool), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 2];
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::Gripper), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetEndEffectorGripper>(EmptyBody {})?;
 
-        if response_body.params.len() != 2 {
+        if response_body.params().len() != 2 {
             return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
         }
 
-        Ok((response_body.params[0] != 0, response_body.params[1] != 0))
+        Ok((response_body.params()[0] != 0, response_body.params()[1] != 0))
     }
 
     fn get_suction_cup_state(&mut self) -> Result<(bool, bool), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 2];
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::SuctionCup), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetEndEffectorSuctionCup>(EmptyBody {})?;
 
-        if response_body.params.len() != 2 {
+        if response_body.params().len() != 2 {
             return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
         }
 
-        Ok((response_body.params[0] != 0, response_body.params[1] != 0))
+        Ok((response_body.params()[0] != 0, response_body.params()[1] != 0))
     }
 
     fn get_laser_state(&mut self) -> Result<(bool, bool), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 2];
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::Laser), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetEndEffectorLaser>(EmptyBody {})?;
 
-        if response_body.params.len() != 2 {
+        if response_body.params().len() != 2 {
             return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
         }
 
-        Ok((response_body.params[0] != 0, response_body.params[1] != 0))
+        Ok((response_body.params()[0] != 0, response_body.params()[1] != 0))
     }
 
     fn set_end_effector_params(
@@ -135,20 +138,17 @@ impl<'a, T: CommandSender> EndEffectorControl for EndEffectorSerialControl<'a, T
         params: TagEndEffectorParams,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagEndEffectorParams, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::Params), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetEndEffectorParams>(params)?));
         }
-        send_cmd!(send sender, TagEndEffectorParams, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::Params), params, write=true)?;
+        sender.execute::<SetEndEffectorParams>(params)?;
         Ok(None)
     }
 
     fn get_end_effector_params(&mut self) -> Result<TagEndEffectorParams, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 12];
-        let response_body = send_cmd!(get sender, TagEndEffectorParams, CommunicationProtocolIDs::EndEffector(EndEffectorIDs::Params), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetEndEffectorParams>(EmptyBody {})?;
 
         Ok(response_body)
     }
@@ -7,7 +7,12 @@ use crate::dobot::dobot_trait::{dobot_core::{
         sub_command_dobot::
             handhold_teaching_control::HandholdTeachingControl
         ,
-    }, protocol::{bodies::{general_request::GeneralRequest, general_response::GeneralResponse, hht_trig_mode::HHTTrigMode, tag_empty_body::EmptyBody}, command_id::HHTIDs, CommunicationProtocolIDs, ProtocolError}, rwlock::RwLock};
+    }, protocol::{
+        command::{
+            GetHhtTrigMode, GetHhtTrigOutput, GetHhtTrigOutputEnabled, SetHhtTrigMode,
+            SetHhtTrigOutputEnabled,
+        },
+        bodies::{hht_trig_mode::HHTTrigMode, tag_empty_body::EmptyBody, tag_enabled::TagEnabled}, ProtocolError}, rwlock::RwLock};
 
 pub struct HandholdTeachingSerialControl<'a, T: CommandSender> {
     command_sender: &'a RwLock<Dobot<T>>,
@@ -24,11 +29,8 @@ impl<'a, T: CommandSender> HandholdTeachingControl for HandholdTeachingSerialCon
         &mut self,
         mode: HHTTrigMode,
     ) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let request_buffer = [mode as u8];
-        let request = GeneralRequest { params: &request_buffer };
-        
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::HHT(HHTIDs::HHTTrigMode), request, write=true)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        sender.execute::<SetHhtTrigMode>(mode)?;
         
         Ok(())
     }
@@ -37,48 +39,44 @@ impl<'a, T: CommandSender> HandholdTeachingControl for HandholdTeachingSerialCon
         &mut self,
     ) -> Result<HHTTrigMode, DobotError>
     {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 1];
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::HHT(HHTIDs::HHTTrigMode), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetHhtTrigMode>(EmptyBody {})?;
 
-        if response_body.params.len() != 1 {
+        if response_body.params().len() != 1 {
             return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
         }
 
-        Ok(HHTTrigMode::try_from_u8(response_body.params[0])?)
+        Ok(HHTTrigMode::try_from_u8(response_body.params()[0])?)
     }
 
     fn set_hht_trig_output_enabled(&mut self, is_enabled: bool) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let request_buffer = [is_enabled as u8];
-        let request = GeneralRequest { params: &request_buffer };
+        let mut sender = create_sender!(self.command_sender)?;
+        let request = TagEnabled { is_enabled };
         
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::HHT(HHTIDs::HHTTrigOutputEnabled), request, write=true)?;
+        sender.execute::<SetHhtTrigOutputEnabled>(request)?;
         
         Ok(())
     }
 
     fn get_hht_trig_output_enabled(&mut self) -> Result<bool, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 1];
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::HHT(HHTIDs::HHTTrigOutputEnabled), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetHhtTrigOutputEnabled>(EmptyBody {})?;
 
-        if response_body.params.len() != 1 {
+        if response_body.params().len() != 1 {
             return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
         }
 
-        Ok(response_body.params[0] != 0)
+        Ok(response_body.params()[0] != 0)
     }
 
     fn get_hht_trig_output(&mut self) -> Result<bool, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 1];
-        let response_body = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::HHT(HHTIDs::HHTTrigOutput), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetHhtTrigOutput>(EmptyBody {})?;
 
-        if response_body.params.len() != 1 {
+        if response_body.params().len() != 1 {
             return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
         }
 
-        Ok(response_body.params[0] != 0)
+        Ok(response_body.params()[0] != 0)
     }
 }
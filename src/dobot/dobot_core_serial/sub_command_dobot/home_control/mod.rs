@@ -8,13 +8,12 @@ use crate::dobot::dobot_trait::{
         sub_command_dobot::home_control::HomeControl,
     },
     protocol::{
-        CommunicationProtocolIDs, ProtocolError,
+        command::{GetAutoLeveling, GetHomeParams, SetAutoLeveling, SetHomeCmd, SetHomeParams},
+        ProtocolError,
         bodies::{
-            general_response::GeneralResponse, tag_auto_leveling_params::TagAutoLevelingParams,
+            tag_auto_leveling_params::TagAutoLevelingParams,
             tag_empty_body::EmptyBody, tag_home_cmd::TagHomeCmd, tag_home_params::TagHomeParams,
-            tag_queue::received::TagQueue,
         },
-        command_id::HomeIDs,
     },
     rwlock::RwLock,
 };
@@ -35,20 +34,17 @@ impl<'a, T: CommandSender> HomeControl for HomeSerialControl<'a, T> {
         params: TagHomeParams,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagHomeParams, CommunicationProtocolIDs::Home(HomeIDs::HomeParams), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetHomeParams>(params)?));
         }
-        send_cmd!(send sender, TagHomeParams, CommunicationProtocolIDs::Home(HomeIDs::HomeParams), params, write=true)?;
+        sender.execute::<SetHomeParams>(params)?;
         Ok(None)
     }
 
     fn get_home_params(&mut self) -> Result<TagHomeParams, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 16];
-        let response = send_cmd!(get sender, TagHomeParams, CommunicationProtocolIDs::Home(HomeIDs::HomeParams), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response = sender.execute::<GetHomeParams>(EmptyBody {})?;
         Ok(response)
     }
 
@@ -57,13 +53,11 @@ impl<'a, T: CommandSender> HomeControl for HomeSerialControl<'a, T> {
         params: TagHomeCmd,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagHomeCmd, CommunicationProtocolIDs::Home(HomeIDs::HomeCmd), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetHomeCmd>(params)?));
         }
-        send_cmd!(send sender, TagHomeCmd, CommunicationProtocolIDs::Home(HomeIDs::HomeCmd), params, write=true)?;
+        sender.execute::<SetHomeCmd>(params)?;
         Ok(None)
     }
 
@@ -72,24 +66,22 @@ impl<'a, T: CommandSender> HomeControl for HomeSerialControl<'a, T> {
         params: TagAutoLevelingParams,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagAutoLevelingParams, CommunicationProtocolIDs::Home(HomeIDs::AutoLeveling), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetAutoLeveling>(params)?));
         }
-        send_cmd!(send sender, TagAutoLevelingParams, CommunicationProtocolIDs::Home(HomeIDs::AutoLeveling), params, write=true)?;
+        sender.execute::<SetAutoLeveling>(params)?;
         Ok(None)
     }
 
     fn get_autoleveling(&mut self) -> Result<f32, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 4];
-        let response = send_cmd!(get sender, GeneralResponse, CommunicationProtocolIDs::Home(HomeIDs::HomeParams), &mut response_buffer)?;
-        if response.params.len() < 4 {
+        let mut sender = create_sender!(self.command_sender)?;
+        let response = sender.execute::<GetAutoLeveling>(EmptyBody {})?;
+        let params = response.params();
+        if params.len() < 4 {
             return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
         }
-        let result = f32::from_le_bytes(response_buffer);
+        let result = f32::from_le_bytes([params[0], params[1], params[2], params[3]]);
         Ok(result)
     }
 }
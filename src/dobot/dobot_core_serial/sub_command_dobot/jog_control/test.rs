@@ -278,6 +278,8 @@ mod tests {
             JogCmd::CnDown,
             JogCmd::DpDown,
             JogCmd::DnDown,
+            JogCmd::LpDown,
+            JogCmd::LnDown,
         ];
 
         for cmd_type in commands.iter() {
@@ -1,7 +1,6 @@
 #[cfg(feature = "std")]
 mod test;
 
-use crate::dobot::dobot_trait::protocol::bodies::tag_queue::received::TagQueue;
 use crate::dobot::dobot_trait::{
     dobot_core::{
         command_sender::{CommandSender, Dobot},
@@ -9,9 +8,8 @@ use crate::dobot::dobot_trait::{
         sub_command_dobot::losing_step_control::LosingStepControl,
     },
     protocol::{
-        CommunicationProtocolIDs,
-        bodies::{general_request::GeneralRequest, tag_empty_body::EmptyBody},
-        command_id::LostStepIDs,
+        command::{SetLostStepCmd, SetLostStepParams},
+        bodies::{tag_empty_body::EmptyBody, tag_lost_step_params::TagLostStepParams},
     },
     rwlock::RwLock,
 };
@@ -28,25 +26,21 @@ impl<'a, T: CommandSender> LosingStepDetectionSerialControl<'a, T> {
 
 impl<'a, T: CommandSender> LosingStepControl for LosingStepDetectionSerialControl<'a, T> {
     fn set_lost_step_params(&mut self, value: f32) -> Result<(), DobotError> {
-        let request_body = GeneralRequest {
-            params: &value.to_le_bytes(),
-        };
+        let request_body = TagLostStepParams { threshold: value };
 
-        let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::LostStep(LostStepIDs::SetLostStepParams), request_body, write=true)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        sender.execute::<SetLostStepParams>(request_body)?;
 
         Ok(())
     }
 
     fn set_lost_step_cmd(&mut self, is_queued: bool) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
 
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, EmptyBody, CommunicationProtocolIDs::LostStep(LostStepIDs::SetLostStepCmd), EmptyBody {  }, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetLostStepCmd>(EmptyBody {})?));
         }
-        send_cmd!(send sender, EmptyBody, CommunicationProtocolIDs::LostStep(LostStepIDs::SetLostStepCmd), EmptyBody {  }, write=true)?;
+        sender.execute::<SetLostStepCmd>(EmptyBody {})?;
         Ok(None)
     }
 }
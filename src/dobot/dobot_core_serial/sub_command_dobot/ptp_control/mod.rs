@@ -10,9 +10,15 @@ use crate::dobot::dobot_trait::{
         ,
     },
     protocol::{
+        command::{
+            GetPtpCommonParams, GetPtpCoordinateParams, GetPtpJointParams, GetPtpJump2Params,
+            GetPtpJumpParams, GetPtpLParams, SetPtpCmd, SetPtpCommonParams, SetPtpCoordinateParams,
+            SetPtpJointParams, SetPtpJump2Params, SetPtpJumpParams, SetPtpLParams, SetPtpPoCmd,
+            SetPtpPoWithLCmd, SetPtpWithLCmd,
+        },
         bodies::{
-            general_request::GeneralRequest, tag_empty_body::EmptyBody, tag_po_cmd::TagPOCmd, tag_ptp_cmd::TagPTPCmd, tag_ptp_common_params::TagPTPCommonParams, tag_ptp_coordinate_params::TagPTPCoordinateParams, tag_ptp_joint_params::TagPTPJointParams, tag_ptp_jump2_params::TagPTPJump2Params, tag_ptp_jump_params::TagPTPJumpParams, tag_ptp_with_l_cmd::TagPTPWithLCmd, tag_ptpl_params::TagPTPLParams, tag_queue::received::TagQueue
-        }, command_id::PtpIDs, Body, CommunicationProtocolIDs, ProtocolError
+            general_request::GeneralRequest, tag_empty_body::EmptyBody, tag_po_cmd::TagPOCmd, tag_ptp_cmd::TagPTPCmd, tag_ptp_common_params::TagPTPCommonParams, tag_ptp_coordinate_params::TagPTPCoordinateParams, tag_ptp_joint_params::TagPTPJointParams, tag_ptp_jump2_params::TagPTPJump2Params, tag_ptp_jump_params::TagPTPJumpParams, tag_ptp_with_l_cmd::TagPTPWithLCmd, tag_ptpl_params::TagPTPLParams
+        }, Body, ProtocolError
     },
     rwlock::RwLock,
 };
@@ -34,17 +40,15 @@ impl<'a, T: CommandSender> PTPControl for PTPSerialControl<'a, T> {
         TagPTPJointParams,
         DobotError,
     > {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 32];
-        let response_body = send_cmd!(get sender, TagPTPJointParams, CommunicationProtocolIDs::Ptp(PtpIDs::JointParams), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetPtpJointParams>(EmptyBody {})?;
 
         Ok(response_body)
     }
 
     fn get_ptp_coordinate_params(&mut self) -> Result<TagPTPCoordinateParams, DobotError>{
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 16];
-        let response_body = send_cmd!(get sender, TagPTPCoordinateParams, CommunicationProtocolIDs::Ptp(PtpIDs::CoordinateParams), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetPtpCoordinateParams>(EmptyBody {})?;
 
         Ok(response_body)
     }
@@ -55,9 +59,8 @@ impl<'a, T: CommandSender> PTPControl for PTPSerialControl<'a, T> {
         TagPTPJumpParams,
         DobotError,
     > {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 16];
-        let response_body = send_cmd!(get sender, TagPTPJumpParams, CommunicationProtocolIDs::Ptp(PtpIDs::JumpParams), &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetPtpJumpParams>(EmptyBody {})?;
 
         Ok(response_body)
     }
@@ -68,9 +71,8 @@ impl<'a, T: CommandSender> PTPControl for PTPSerialControl<'a, T> {
         TagPTPCommonParams,
         DobotError,
     > {
-let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 8];
-        let response_body = send_cmd!(get sender, TagPTPCommonParams, CommunicationProtocolIDs::Ptp(PtpIDs::JumpParams), &mut response_buffer)?;
+let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetPtpCommonParams>(EmptyBody {})?;
 
         Ok(response_body)
     }
@@ -80,13 +82,11 @@ let sender = create_sender!(self.command_sender)?;
         params: TagPTPLParams,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-let sender = create_sender!(self.command_sender)?;
+let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagPTPLParams, CommunicationProtocolIDs::Ptp(PtpIDs::LParams), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetPtpLParams>(params)?));
         }
-        send_cmd!(send sender, TagPTPLParams, CommunicationProtocolIDs::Ptp(PtpIDs::LParams), params, write=true)?;
+        sender.execute::<SetPtpLParams>(params)?;
         Ok(None)
     }
 
@@ -96,9 +96,8 @@ let sender = create_sender!(self.command_sender)?;
         TagPTPLParams,
         DobotError,
     > {
-let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 8];
-        let response_body = send_cmd!(get sender, TagPTPLParams, CommunicationProtocolIDs::Ptp(PtpIDs::LParams), &mut response_buffer)?;
+let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetPtpLParams>(EmptyBody {})?;
 
         Ok(response_body)
     }
@@ -108,13 +107,11 @@ let sender = create_sender!(self.command_sender)?;
         cmd: TagPTPWithLCmd,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-let sender = create_sender!(self.command_sender)?;
+let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagPTPWithLCmd, CommunicationProtocolIDs::Ptp(PtpIDs::WithLCmd), cmd, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetPtpWithLCmd>(cmd)?));
         }
-        send_cmd!(send sender, TagPTPWithLCmd, CommunicationProtocolIDs::Ptp(PtpIDs::WithLCmd), cmd, write=true)?;
+        sender.execute::<SetPtpWithLCmd>(cmd)?;
         Ok(None)
     }
 
@@ -123,13 +120,11 @@ let sender = create_sender!(self.command_sender)?;
         params: TagPTPJump2Params,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-let sender = create_sender!(self.command_sender)?;
+let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagPTPJump2Params, CommunicationProtocolIDs::Ptp(PtpIDs::JumpToParams), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetPtpJump2Params>(params)?));
         }
-        send_cmd!(send sender, TagPTPJump2Params, CommunicationProtocolIDs::Ptp(PtpIDs::JumpToParams), params, write=true)?;
+        sender.execute::<SetPtpJump2Params>(params)?;
         Ok(None)
     }
 
@@ -139,9 +134,8 @@ let sender = create_sender!(self.command_sender)?;
         TagPTPJump2Params,
         DobotError,
     > {
-let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 12];
-        let response_body = send_cmd!(get sender, TagPTPJump2Params, CommunicationProtocolIDs::Ptp(PtpIDs::JumpToParams), &mut response_buffer)?;
+let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetPtpJump2Params>(EmptyBody {})?;
 
         Ok(response_body)
     }
@@ -152,7 +146,7 @@ let sender = create_sender!(self.command_sender)?;
         po_cmds: &[TagPOCmd],
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-let sender = create_sender!(self.command_sender)?;
+let mut sender = create_sender!(self.command_sender)?;
         let mut data = [0u8; 250];
         if ptp_cmd.size() + po_cmds.len() * 4 > data.len() {
             return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
@@ -168,11 +162,9 @@ let sender = create_sender!(self.command_sender)?;
 
 
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, GeneralRequest, CommunicationProtocolIDs::Ptp(PtpIDs::PoCmd), wrapper, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetPtpPoCmd>(wrapper)?));
         }
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::Ptp(PtpIDs::PoCmd), wrapper, write=true)?;
+        sender.execute::<SetPtpPoCmd>(wrapper)?;
         Ok(None)
     }
 
@@ -182,7 +174,7 @@ let sender = create_sender!(self.command_sender)?;
         po_cmds: &[TagPOCmd],
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-let sender = create_sender!(self.command_sender)?;
+let mut sender = create_sender!(self.command_sender)?;
         let mut data = [0u8; 250];
         if ptp_cmd.size() + po_cmds.len() * 4 > data.len() {
             return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
@@ -198,11 +190,9 @@ let sender = create_sender!(self.command_sender)?;
 
 
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, GeneralRequest, CommunicationProtocolIDs::Ptp(PtpIDs::PoWithLCmd), wrapper, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetPtpPoWithLCmd>(wrapper)?));
         }
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::Ptp(PtpIDs::PoWithLCmd), wrapper, write=true)?;
+        sender.execute::<SetPtpPoWithLCmd>(wrapper)?;
         Ok(None)
     }
     
@@ -212,13 +202,11 @@ let sender = create_sender!(self.command_sender)?;
         params: TagPTPJointParams,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-let sender = create_sender!(self.command_sender)?;
+let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagPTPJointParams, CommunicationProtocolIDs::Ptp(PtpIDs::JointParams), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetPtpJointParams>(params)?));
         }
-        send_cmd!(send sender, TagPTPJointParams, CommunicationProtocolIDs::Ptp(PtpIDs::JointParams), params, write=true)?;
+        sender.execute::<SetPtpJointParams>(params)?;
         Ok(None)
     }
 
@@ -227,13 +215,11 @@ let sender = create_sender!(self.command_sender)?;
         params: TagPTPCoordinateParams,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-let sender = create_sender!(self.command_sender)?;
+let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagPTPCoordinateParams, CommunicationProtocolIDs::Ptp(PtpIDs::CoordinateParams), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetPtpCoordinateParams>(params)?));
         }
-        send_cmd!(send sender, TagPTPCoordinateParams, CommunicationProtocolIDs::Ptp(PtpIDs::CoordinateParams), params, write=true)?;
+        sender.execute::<SetPtpCoordinateParams>(params)?;
         Ok(None)
     }
 
@@ -242,13 +228,11 @@ let sender = create_sender!(self.command_sender)?;
         params: TagPTPJumpParams,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-let sender = create_sender!(self.command_sender)?;
+let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagPTPJumpParams, CommunicationProtocolIDs::Ptp(PtpIDs::JumpParams), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetPtpJumpParams>(params)?));
         }
-        send_cmd!(send sender, TagPTPJumpParams, CommunicationProtocolIDs::Ptp(PtpIDs::JumpParams), params, write=true)?;
+        sender.execute::<SetPtpJumpParams>(params)?;
         Ok(None)
     }
 
@@ -257,13 +241,11 @@ let sender = create_sender!(self.command_sender)?;
         params: TagPTPCommonParams,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-let sender = create_sender!(self.command_sender)?;
+let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagPTPCommonParams, CommunicationProtocolIDs::Ptp(PtpIDs::CommonParams), params, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetPtpCommonParams>(params)?));
         }
-        send_cmd!(send sender, TagPTPCommonParams, CommunicationProtocolIDs::Ptp(PtpIDs::CommonParams), params, write=true)?;
+        sender.execute::<SetPtpCommonParams>(params)?;
         Ok(None)
     }
 
@@ -272,13 +254,11 @@ let sender = create_sender!(self.command_sender)?;
         cmd: TagPTPCmd,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-let sender = create_sender!(self.command_sender)?;
+let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagPTPCmd, CommunicationProtocolIDs::Ptp(PtpIDs::Cmd), cmd, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetPtpCmd>(cmd)?));
         }
-        send_cmd!(send sender, TagPTPCmd, CommunicationProtocolIDs::Ptp(PtpIDs::Cmd), cmd, write=true)?;
+        sender.execute::<SetPtpCmd>(cmd)?;
         Ok(None)
     }
 }
@@ -234,7 +234,7 @@ mod tests {
         let response_body = create_float_array_response(&values);
         
         let mock_response = create_response_packet(
-            CommunicationProtocolIDs::Ptp(PtpIDs::JumpParams),
+            CommunicationProtocolIDs::Ptp(PtpIDs::CommonParams),
             &response_body
         );
         let length = mock_response.len();
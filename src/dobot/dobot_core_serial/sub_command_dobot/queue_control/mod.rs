@@ -1,8 +1,6 @@
 #[cfg(feature = "std")]
 mod test;
 
-use core::mem;
-
 use crate::dobot::dobot_trait::{
     dobot_core::{
         command_sender::{CommandSender, Dobot},
@@ -12,11 +10,16 @@ use crate::dobot::dobot_trait::{
         ,
     },
     protocol::{
+        command::{
+            GetQueuedCmdCurrentIndex, SetQueuedCmdClear, SetQueuedCmdForceStopExec,
+            SetQueuedCmdStartDownload, SetQueuedCmdStartExec, SetQueuedCmdStopDownload,
+            SetQueuedCmdStopExec,
+        },
         bodies::{
-            general_request::GeneralRequest, general_response::GeneralResponse, tag_empty_body::EmptyBody
-        }, command_id::QueuedCmdIDs, CommunicationProtocolIDs, ProtocolError
+            tag_empty_body::EmptyBody, tag_queued_cmd_download::TagQueuedCmdDownload
+        }, ProtocolError
     },
-    rwlock::RwLock,
+    rwlock::{Priority, RwLock},
 };
 
 pub struct QueueSerialControl<'a, T: CommandSender> {
@@ -31,8 +34,8 @@ impl<'a, T: CommandSender> QueueSerialControl<'a, T> {
 
 impl<'a, T: CommandSender> QueueControl for QueueSerialControl<'a, T> {
     fn set_queued_cmd_force_stop_exec(&mut self) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, EmptyBody, CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::StopExec), EmptyBody {  }, write=true)?;
+        let mut sender = create_sender!(self.command_sender, Priority::Emergency)?;
+        sender.execute::<SetQueuedCmdForceStopExec>(EmptyBody {})?;
         Ok(())
     }
 
@@ -41,49 +44,48 @@ impl<'a, T: CommandSender> QueueControl for QueueSerialControl<'a, T> {
         total_loop: u32,
         line_per_loop: u32,
     ) -> Result<(), DobotError> {
-        let mut request_buffer = [0u8; 2 * mem::size_of::<u32>()];
-        request_buffer[0..mem::size_of::<u32>()].copy_from_slice(&total_loop.to_le_bytes());
-        request_buffer[mem::size_of::<u32>()..2*mem::size_of::<u32>()].copy_from_slice(&line_per_loop.to_le_bytes());
-        
-        let params = GeneralRequest { params: &request_buffer};
+        let params = TagQueuedCmdDownload {
+            total_loop,
+            line_per_loop,
+        };
 
-let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::StartDownload), params, write=true)?;
+let mut sender = create_sender!(self.command_sender)?;
+        sender.execute::<SetQueuedCmdStartDownload>(params)?;
         Ok(())
     }
 
     fn set_queued_cmd_stop_download(&mut self) -> Result<(), DobotError> {
-let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, EmptyBody, CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::StopDownload), EmptyBody {  }, write=true)?;
+let mut sender = create_sender!(self.command_sender)?;
+        sender.execute::<SetQueuedCmdStopDownload>(EmptyBody {})?;
         Ok(())
     }
 
     fn set_queued_cmd_start_exec(&mut self) -> Result<(), DobotError> {
-let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, EmptyBody, CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::StartExec), EmptyBody {  }, write=true)?;
+let mut sender = create_sender!(self.command_sender)?;
+        sender.execute::<SetQueuedCmdStartExec>(EmptyBody {})?;
         Ok(())
     }
 
     fn set_queued_cmd_stop_exec(&mut self) -> Result<(), DobotError> {
-let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, EmptyBody, CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::StopExec), EmptyBody {  }, write=true)?;
+let mut sender = create_sender!(self.command_sender, Priority::Emergency)?;
+        sender.execute::<SetQueuedCmdStopExec>(EmptyBody {})?;
         Ok(())
     }
 
     fn set_queued_cmd_clear(&mut self) -> Result<(), DobotError> {
-let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, EmptyBody, CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::Clear), EmptyBody {  }, write=true)?;
+let mut sender = create_sender!(self.command_sender, Priority::Emergency)?;
+        sender.execute::<SetQueuedCmdClear>(EmptyBody {})?;
         Ok(())
     }
 
     fn get_queued_cmd_current_index(&mut self) -> Result<u64, DobotError> {
-let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 8];
-        let response_body = send_cmd!(get sender, EmptyBody, GeneralResponse, CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::CurrentIndex), EmptyBody {}, &mut response_buffer)?;
-        if response_body.params.len() < 8 {
-            return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
-        }
-        let current_idx = u64::from_le_bytes(response_buffer);
+let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetQueuedCmdCurrentIndex>(EmptyBody {})?;
+        let params: [u8; 8] = match response_body.params().try_into() {
+            Ok(x) => x,
+            Err(_) => return Err(DobotError::Protocol(ProtocolError::BufferTooSmall)),
+        };
+        let current_idx = u64::from_le_bytes(params);
 
         Ok(current_idx)
     }
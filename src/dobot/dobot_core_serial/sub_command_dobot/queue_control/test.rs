@@ -20,7 +20,7 @@ mod tests {
     #[test]
     fn test_set_queued_cmd_force_stop_exec_ok() {
         let mock_response = create_response_packet(
-            CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::StopExec),
+            CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::ForceStopExec),
             b""
         );
         let length = mock_response.len();
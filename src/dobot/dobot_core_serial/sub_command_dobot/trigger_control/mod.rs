@@ -8,11 +8,8 @@ use crate::dobot::dobot_trait::{
         sub_command_dobot::trigger_control::TriggerControl,
     },
     protocol::{
-        CommunicationProtocolIDs,
-        bodies::{
-            tag_empty_body::EmptyBody, tag_queue::received::TagQueue, tag_trig_cmd::TagTRIGCmd,
-        },
-        command_id::TrigIDs,
+        command::SetTrigCmd,
+        bodies::tag_trig_cmd::TagTRIGCmd,
     },
     rwlock::RwLock,
 };
@@ -33,13 +30,11 @@ impl<'a, T: CommandSender> TriggerControl for TriggerSerialControl<'a, T> {
         cmd: TagTRIGCmd,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, TagTRIGCmd, CommunicationProtocolIDs::Trig(TrigIDs::TrigCmd), cmd, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetTrigCmd>(cmd)?));
         }
-        send_cmd!(send sender, TagTRIGCmd, CommunicationProtocolIDs::Trig(TrigIDs::TrigCmd), cmd, write=true)?;
+        sender.execute::<SetTrigCmd>(cmd)?;
         Ok(None)
     }
 }
@@ -7,8 +7,9 @@ use crate::dobot::dobot_trait::{dobot_core::{
         sub_command_dobot::
             wait_control::WaitControl
         ,
-    }, protocol::{bodies::{
-            general_request::GeneralRequest, tag_empty_body::EmptyBody, tag_queue::received::TagQueue}, command_id::WaitIDs, CommunicationProtocolIDs}, rwlock::RwLock};
+    }, protocol::{
+        command::SetWaitCmd,
+        bodies::tag_wait_cmd::TagWAITCmd}, rwlock::RwLock};
 
 pub struct WaitSerialControl<'a, T: CommandSender> {
     command_sender: &'a RwLock<Dobot<T>>,
@@ -26,17 +27,13 @@ impl<'a, T: CommandSender> WaitControl for WaitSerialControl<'a, T> {
         timeout: u32,
         is_queued: bool,
     ) -> Result<Option<u64>, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut request_buffer = [0u8; 4];
-        request_buffer.copy_from_slice(&timeout.to_le_bytes());
-        let request_body = GeneralRequest { params: &request_buffer };
+        let mut sender = create_sender!(self.command_sender)?;
+        let request_body = TagWAITCmd { timeout };
 
         if is_queued {
-            let mut response = [0u8; 8];
-            let queue_idx = send_cmd!(get_queue sender, GeneralRequest, CommunicationProtocolIDs::Wait(WaitIDs::WaitCmd), request_body, &mut response, write=true)?;
-            return Ok(Some(queue_idx.queue_idx));
+            return Ok(Some(sender.execute_queued::<SetWaitCmd>(request_body)?));
         }
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::Wait(WaitIDs::WaitCmd), request_body, write=true)?;
+        sender.execute::<SetWaitCmd>(request_body)?;
         Ok(None)
     }
 }
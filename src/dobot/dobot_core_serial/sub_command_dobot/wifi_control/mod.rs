@@ -10,9 +10,14 @@ use crate::dobot::dobot_trait::{
         ,
     },
     protocol::{
+        command::{
+            GetWifiConfigMode, GetWifiConnectStatus, GetWifiDns, GetWifiGateway, GetWifiIpAddress,
+            GetWifiNetmask, GetWifiPassword, GetWifiSsid, SetWifiConfigMode, SetWifiDns,
+            SetWifiGateway, SetWifiIpAddress, SetWifiNetmask, SetWifiPassword, SetWifiSsid,
+        },
         bodies::{
-            general_request::GeneralRequest, general_response::GeneralResponse, tag_empty_body::EmptyBody, tag_wifi_dns::TagWIFIDNS, tag_wifi_gateway::TagWIFIGateway, tag_wifi_ip_address::TagWIFIIPAddress, tag_wifi_netmask::TagWIFINetmask
-        }, command_id::WifiIDs, CommunicationProtocolIDs
+            general_request::GeneralRequest, tag_empty_body::EmptyBody, tag_enabled::TagEnabled, tag_wifi_dns::TagWIFIDNS, tag_wifi_gateway::TagWIFIGateway, tag_wifi_ip_address::TagWIFIIPAddress, tag_wifi_netmask::TagWIFINetmask
+        }
     },
     rwlock::RwLock,
 };
@@ -29,56 +34,56 @@ impl<'a, T: CommandSender> WifiSerialControl<'a, T> {
 
 impl<'a, T: CommandSender> WifiControl for WifiSerialControl<'a, T> {
     fn set_wifi_config_mode(&mut self, enable: bool) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let request_buffer = [enable as u8; 1];
-        let request_body = GeneralRequest { params: &request_buffer };
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::Wifi(WifiIDs::ConfigMode), request_body, write=true)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let request_body = TagEnabled { is_enabled: enable };
+        sender.execute::<SetWifiConfigMode>(request_body)?;
         Ok(())
     }
 
     fn get_wifi_config_mode(&mut self) -> Result<bool, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 1];
-        let response_body = send_cmd!(get sender, EmptyBody, GeneralResponse, CommunicationProtocolIDs::Wifi(WifiIDs::ConfigMode), EmptyBody {  }, &mut response_buffer)?;
-        let enabled = response_body.params[0] != 0;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetWifiConfigMode>(EmptyBody {})?;
+        let enabled = response_body.params()[0] != 0;
 
         Ok(enabled)
     }
 
     fn set_wifi_ssid(&mut self, ssid: &[u8]) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         let request_body = GeneralRequest { params: ssid };
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::Wifi(WifiIDs::Ssid), request_body, write=true)?;
+        sender.execute::<SetWifiSsid>(request_body)?;
         Ok(())
     }
 
     fn get_wifi_ssid<'b>(&'b mut self, buffer: &'b mut [u8]) -> Result<&'b str, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let response_body = send_cmd!(get sender, EmptyBody, GeneralResponse, CommunicationProtocolIDs::Wifi(WifiIDs::Ssid), EmptyBody {  }, buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetWifiSsid>(EmptyBody {})?;
 
-        Ok(core::str::from_utf8(response_body.params)?)
+        let len = response_body.copy_to(buffer)?;
+        Ok(core::str::from_utf8(&buffer[..len])?)
     }
 
     fn set_wifi_password(&mut self, password: &[u8]) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
+        let mut sender = create_sender!(self.command_sender)?;
         let request_body = GeneralRequest { params: password };
-        send_cmd!(send sender, GeneralRequest, CommunicationProtocolIDs::Wifi(WifiIDs::Password), request_body, write=true)?;
+        sender.execute::<SetWifiPassword>(request_body)?;
         Ok(())
     }
 
     fn get_wifi_password<'b>(&'b mut self, buffer: &'b mut [u8]) -> Result<&'b str, DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        let response_body = send_cmd!(get sender, EmptyBody, GeneralResponse, CommunicationProtocolIDs::Wifi(WifiIDs::Password), EmptyBody {  }, buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetWifiPassword>(EmptyBody {})?;
 
-        Ok(core::str::from_utf8(response_body.params)?)
+        let len = response_body.copy_to(buffer)?;
+        Ok(core::str::from_utf8(&buffer[..len])?)
     }
 
     fn set_wifi_ip_address(
         &mut self,
         params: TagWIFIIPAddress,
     ) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, TagWIFIIPAddress, CommunicationProtocolIDs::Wifi(WifiIDs::IpAddress), params, write=true)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        sender.execute::<SetWifiIpAddress>(params)?;
         Ok(())
     }
 
@@ -88,9 +93,8 @@ impl<'a, T: CommandSender> WifiControl for WifiSerialControl<'a, T> {
         TagWIFIIPAddress,
         DobotError,
     > {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 5];
-        let response_body = send_cmd!(get sender, EmptyBody, TagWIFIIPAddress, CommunicationProtocolIDs::Wifi(WifiIDs::IpAddress), EmptyBody {  }, &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetWifiIpAddress>(EmptyBody {})?;
 
         Ok(response_body)
     }
@@ -99,8 +103,8 @@ impl<'a, T: CommandSender> WifiControl for WifiSerialControl<'a, T> {
         &mut self,
         params: TagWIFINetmask,
     ) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, TagWIFINetmask, CommunicationProtocolIDs::Wifi(WifiIDs::Netmask), params, write=true)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        sender.execute::<SetWifiNetmask>(params)?;
         Ok(())
     }
 
@@ -110,9 +114,8 @@ impl<'a, T: CommandSender> WifiControl for WifiSerialControl<'a, T> {
         TagWIFINetmask,
         DobotError,
     > {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 4];
-        let response_body = send_cmd!(get sender, EmptyBody, TagWIFINetmask, CommunicationProtocolIDs::Wifi(WifiIDs::Netmask), EmptyBody {  }, &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetWifiNetmask>(EmptyBody {})?;
 
         Ok(response_body)
     }
@@ -121,8 +124,8 @@ impl<'a, T: CommandSender> WifiControl for WifiSerialControl<'a, T> {
         &mut self,
         params: TagWIFIGateway,
     ) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, TagWIFIGateway, CommunicationProtocolIDs::Wifi(WifiIDs::Gateway), params, write=true)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        sender.execute::<SetWifiGateway>(params)?;
         Ok(())
     }
 
@@ -132,9 +135,8 @@ impl<'a, T: CommandSender> WifiControl for WifiSerialControl<'a, T> {
         TagWIFIGateway,
         DobotError,
     > {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 4];
-        let response_body = send_cmd!(get sender, EmptyBody, TagWIFIGateway, CommunicationProtocolIDs::Wifi(WifiIDs::Gateway), EmptyBody {  }, &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetWifiGateway>(EmptyBody {})?;
 
         Ok(response_body)
     }
@@ -143,8 +145,8 @@ impl<'a, T: CommandSender> WifiControl for WifiSerialControl<'a, T> {
         &mut self,
         params: TagWIFIDNS,
     ) -> Result<(), DobotError> {
-        let sender = create_sender!(self.command_sender)?;
-        send_cmd!(send sender, TagWIFIDNS, CommunicationProtocolIDs::Wifi(WifiIDs::Dns), params, write=true)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        sender.execute::<SetWifiDns>(params)?;
         Ok(())
     }
 
@@ -152,18 +154,16 @@ impl<'a, T: CommandSender> WifiControl for WifiSerialControl<'a, T> {
         &mut self,
     ) -> Result<TagWIFIDNS, DobotError>
     {
-        let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 4];
-        let response_body = send_cmd!(get sender, EmptyBody, TagWIFIDNS, CommunicationProtocolIDs::Wifi(WifiIDs::Dns), EmptyBody {  }, &mut response_buffer)?;
+        let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetWifiDns>(EmptyBody {})?;
 
         Ok(response_body)
     }
 
     fn get_wifi_connect_status(&mut self) -> Result<bool, DobotError> {
-let sender = create_sender!(self.command_sender)?;
-        let mut response_buffer = [0u8; 1];
-        let response_body = send_cmd!(get sender, EmptyBody, GeneralResponse, CommunicationProtocolIDs::Wifi(WifiIDs::ConnectStatus), EmptyBody {  }, &mut response_buffer)?;
-        let enabled = response_body.params[0] != 0;
+let mut sender = create_sender!(self.command_sender)?;
+        let response_body = sender.execute::<GetWifiConnectStatus>(EmptyBody {})?;
+        let enabled = response_body.params()[0] != 0;
 
         Ok(enabled)
     }
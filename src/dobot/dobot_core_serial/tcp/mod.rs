@@ -0,0 +1,254 @@
+mod test;
+
+use std::collections::BTreeMap;
+
+use crate::dobot::{
+    dobot_core_serial::{frames::rotate, handle::DobotHandle},
+    dobot_trait::{
+        dobot_core::{
+            command_sender::CommandSender,
+            dobot_error::DobotError,
+            sub_command_dobot::{
+                end_effector_control::EndEffectorControl, real_time_control::RealTimeControl,
+            },
+        },
+        protocol::bodies::tag_end_effector_params::TagEndEffectorParams,
+    },
+};
+
+/// A pose recorded with the tool tip on the reference point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TcpSample {
+    /// Where the wrist is, with the end effector bias taken off the
+    /// reported pose.
+    pub flange: [f32; 3],
+    /// Angle of joint 1, which the x and y bias turn with.
+    pub heading: f32,
+}
+
+/// Settings of [`TcpCalibration::solve`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TcpOptions {
+    /// Height of the reference point in the base frame, in mm. Touching one
+    /// point gives no hold on the z bias, so without it the current z bias
+    /// is kept.
+    pub reference_z: Option<f32>,
+    /// Largest distance in mm a sample may put the tip from the reference
+    /// point with the solved offset.
+    pub max_error: f32,
+}
+
+impl Default for TcpOptions {
+    fn default() -> Self {
+        Self {
+            reference_z: None,
+            max_error: 1.0,
+        }
+    }
+}
+
+/// The tool offset found by [`TcpCalibration::solve`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TcpSolution {
+    pub bias: TagEndEffectorParams,
+    /// Where the reference point is in the base frame.
+    pub point: [f32; 3],
+    /// Root mean square distance of the samples from the reference point,
+    /// in mm.
+    pub residual: f32,
+    /// Largest distance of a sample from the reference point, in mm.
+    pub max_error: f32,
+}
+
+/// Finds the end effector bias of a tool from poses where its tip touches
+/// the same point with the arm coming in from different directions.
+///
+/// The bias is the offset of the tip from the wrist, turning with joint 1.
+/// At least two samples with different headings are needed; more make the
+/// least squares fit more robust.
+pub struct TcpCalibration<T: CommandSender + 'static> {
+    arm: DobotHandle<T>,
+    samples: Vec<TcpSample>,
+    z_bias: f32,
+}
+
+impl<T: CommandSender + 'static> TcpCalibration<T> {
+    pub fn new(arm: DobotHandle<T>) -> Self {
+        Self {
+            arm,
+            samples: Vec::new(),
+            z_bias: 0.0,
+        }
+    }
+
+    /// Records the current pose and returns how many samples there are.
+    pub fn record(&mut self) -> Result<usize, DobotError> {
+        let mut controller = self.arm.controller();
+        let bias = controller
+            .end_effector_controller
+            .get_end_effector_params()?;
+        let pose = controller.realtime_controller.get_pose()?;
+
+        let heading = pose.joint_angle[0];
+        let [x, y] = rotate([bias.x_bias, bias.y_bias], heading);
+        self.z_bias = bias.z_bias;
+        self.samples.push(TcpSample {
+            flange: [pose.x - x, pose.y - y, pose.z - bias.z_bias],
+            heading,
+        });
+        Ok(self.samples.len())
+    }
+
+    pub fn samples(&self) -> &[TcpSample] {
+        &self.samples
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Solves for the bias by least squares.
+    ///
+    /// Returns [`DobotError::Calibration`] if the headings are too close to
+    /// tell the offset apart or a sample is further than
+    /// [`TcpOptions::max_error`] from the reference point.
+    pub fn solve(&self, options: &TcpOptions) -> Result<TcpSolution, DobotError> {
+        if self.samples.len() < 2 {
+            return Err(DobotError::Calibration(vec![
+                "at least two samples are needed".to_string(),
+            ]));
+        }
+        let count = self.samples.len() as f32;
+        let mut mean = [0.0; 5];
+        for sample in &self.samples {
+            let (sin, cos) = sample.heading.to_radians().sin_cos();
+            let [x, y, z] = sample.flange;
+            for (mean, value) in mean.iter_mut().zip([x, y, z, cos, sin]) {
+                *mean += value / count;
+            }
+        }
+        let [mean_x, mean_y, mean_z, mean_cos, mean_sin] = mean;
+
+        // With the reference point taken out by centering, each sample gives
+        // R(heading - mean) * bias = -(flange - mean), whose normal equations
+        // are diagonal.
+        let (mut spread, mut x_sum, mut y_sum) = (0.0, 0.0, 0.0);
+        for sample in &self.samples {
+            let (sin, cos) = sample.heading.to_radians().sin_cos();
+            let (dc, ds) = (cos - mean_cos, sin - mean_sin);
+            let (dx, dy) = (sample.flange[0] - mean_x, sample.flange[1] - mean_y);
+            spread += dc * dc + ds * ds;
+            x_sum += -dc * dx - ds * dy;
+            y_sum += ds * dx - dc * dy;
+        }
+        if spread < 1e-3 {
+            return Err(DobotError::Calibration(vec![
+                "the samples need different headings".to_string(),
+            ]));
+        }
+        let z_bias = match options.reference_z {
+            Some(z) => z - mean_z,
+            None => self.z_bias,
+        };
+        let bias = TagEndEffectorParams {
+            x_bias: x_sum / spread,
+            y_bias: y_sum / spread,
+            z_bias,
+        };
+
+        let tips: Vec<[f32; 3]> = self.samples.iter().map(|s| tip(s, &bias)).collect();
+        let point = [0, 1, 2].map(|axis| tips.iter().map(|tip| tip[axis]).sum::<f32>() / count);
+        let errors: Vec<f32> = tips
+            .iter()
+            .map(|tip| {
+                ((tip[0] - point[0]).powi(2)
+                    + (tip[1] - point[1]).powi(2)
+                    + (tip[2] - point[2]).powi(2))
+                .sqrt()
+            })
+            .collect();
+        let residual = (errors.iter().map(|e| e * e).sum::<f32>() / count).sqrt();
+        let max_error = errors.iter().copied().fold(0.0, f32::max);
+
+        let far: Vec<String> = errors
+            .iter()
+            .enumerate()
+            .filter(|(_, error)| **error > options.max_error)
+            .map(|(i, error)| format!("sample {} is {:.2} mm off the reference point", i, error))
+            .collect();
+        if !far.is_empty() {
+            return Err(DobotError::Calibration(far));
+        }
+
+        Ok(TcpSolution {
+            bias,
+            point,
+            residual,
+            max_error,
+        })
+    }
+
+    /// Writes the bias of `solution` to the arm.
+    pub fn apply(&self, solution: &TcpSolution) -> Result<(), DobotError> {
+        self.arm
+            .controller()
+            .end_effector_controller
+            .set_end_effector_params(solution.bias.clone(), false)?;
+        Ok(())
+    }
+}
+
+/// Where `sample` puts the tool tip with `bias`.
+fn tip(sample: &TcpSample, bias: &TagEndEffectorParams) -> [f32; 3] {
+    let [x, y] = rotate([bias.x_bias, bias.y_bias], sample.heading);
+    [
+        sample.flange[0] + x,
+        sample.flange[1] + y,
+        sample.flange[2] + bias.z_bias,
+    ]
+}
+
+/// End effector biases of named tools.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ToolPresets {
+    pub tools: BTreeMap<String, TagEndEffectorParams>,
+}
+
+impl ToolPresets {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores `bias` under `name`, returning the bias it replaces.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        bias: TagEndEffectorParams,
+    ) -> Option<TagEndEffectorParams> {
+        self.tools.insert(name.into(), bias)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TagEndEffectorParams> {
+        self.tools.get(name)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<TagEndEffectorParams> {
+        self.tools.remove(name)
+    }
+
+    /// Writes the bias of the tool `name` to the arm.
+    pub fn select<T: CommandSender + 'static>(
+        &self,
+        arm: &DobotHandle<T>,
+        name: &str,
+    ) -> Result<(), DobotError> {
+        let bias = self
+            .get(name)
+            .ok_or_else(|| DobotError::UnknownTool(name.to_string()))?;
+        arm.controller()
+            .end_effector_controller
+            .set_end_effector_params(bias.clone(), false)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::{
+        dobot_core_serial::{
+            frames::rotate,
+            handle::DobotHandle,
+            tcp::{TcpCalibration, TcpOptions, ToolPresets},
+        },
+        dobot_trait::{
+            dobot_core::{
+                command_sender::simulated_command_sender::SimulatedCommandSender,
+                dobot_error::DobotError,
+                sub_command_dobot::end_effector_control::EndEffectorControl,
+            },
+            protocol::bodies::tag_end_effector_params::TagEndEffectorParams,
+        },
+    };
+
+    const POINT: [f32; 3] = [250.0, 30.0, -20.0];
+
+    fn bias(x_bias: f32, y_bias: f32, z_bias: f32) -> TagEndEffectorParams {
+        TagEndEffectorParams {
+            x_bias,
+            y_bias,
+            z_bias,
+        }
+    }
+
+    fn set_bias(arm: &DobotHandle<SimulatedCommandSender>, bias: TagEndEffectorParams) {
+        arm.controller()
+            .end_effector_controller
+            .set_end_effector_params(bias, false)
+            .unwrap();
+    }
+
+    /// Puts the tip of a tool with offset `tool` on `point`, coming in at
+    /// `heading`, and records the pose the arm reports with `current` set.
+    fn touch(
+        calibration: &mut TcpCalibration<SimulatedCommandSender>,
+        sim: &SimulatedCommandSender,
+        tool: &TagEndEffectorParams,
+        current: &TagEndEffectorParams,
+        point: [f32; 3],
+        heading: f32,
+    ) {
+        let [tool_x, tool_y] = rotate([tool.x_bias, tool.y_bias], heading);
+        let [current_x, current_y] = rotate([current.x_bias, current.y_bias], heading);
+        sim.with_state(|state| {
+            state.pose.x = point[0] - tool_x + current_x;
+            state.pose.y = point[1] - tool_y + current_y;
+            state.pose.z = point[2] - tool.z_bias + current.z_bias;
+            state.pose.joint_angle[0] = heading;
+        });
+        calibration.record().unwrap();
+    }
+
+    #[test]
+    fn test_solves_and_applies_the_tool_offset() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+        let current = bias(10.0, 0.0, 5.0);
+        set_bias(&arm, current.clone());
+        let tool = bias(45.0, -6.0, -12.0);
+        let mut calibration = TcpCalibration::new(arm.clone());
+
+        for heading in [-30.0, 0.0, 15.0, 40.0] {
+            touch(&mut calibration, &sim, &tool, &current, POINT, heading);
+        }
+        let solution = calibration
+            .solve(&TcpOptions {
+                reference_z: Some(POINT[2]),
+                ..TcpOptions::default()
+            })
+            .unwrap();
+        calibration.apply(&solution).unwrap();
+
+        let solved = &solution.bias;
+        assert!((solved.x_bias - 45.0).abs() < 1e-2, "{:?}", solved);
+        assert!((solved.y_bias + 6.0).abs() < 1e-2, "{:?}", solved);
+        assert!((solved.z_bias + 12.0).abs() < 1e-2, "{:?}", solved);
+        assert!(solution.residual < 1e-2 && solution.max_error < 1e-2);
+        assert!((solution.point[0] - POINT[0]).abs() < 1e-2);
+        assert_eq!(
+            arm.controller()
+                .end_effector_controller
+                .get_end_effector_params()
+                .unwrap(),
+            solution.bias
+        );
+    }
+
+    #[test]
+    fn test_z_bias_is_kept_without_a_reference_height() {
+        let sim = SimulatedCommandSender::new();
+        let arm = DobotHandle::new(sim.clone());
+        let current = bias(0.0, 0.0, 8.0);
+        set_bias(&arm, current.clone());
+        let mut calibration = TcpCalibration::new(arm);
+
+        for heading in [0.0, 30.0] {
+            touch(
+                &mut calibration,
+                &sim,
+                &bias(30.0, 0.0, 8.0),
+                &current,
+                POINT,
+                heading,
+            );
+        }
+        let solution = calibration.solve(&TcpOptions::default()).unwrap();
+
+        assert_eq!(solution.bias.z_bias, 8.0);
+        assert!((solution.bias.x_bias - 30.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_unusable_samples_are_refused() {
+        let sim = SimulatedCommandSender::new();
+        let tool = bias(40.0, 0.0, 0.0);
+        let current = bias(0.0, 0.0, 0.0);
+        let mut calibration = TcpCalibration::new(DobotHandle::new(sim.clone()));
+
+        touch(&mut calibration, &sim, &tool, &current, POINT, 10.0);
+        assert!(matches!(
+            calibration.solve(&TcpOptions::default()),
+            Err(DobotError::Calibration(_))
+        ));
+        touch(&mut calibration, &sim, &tool, &current, POINT, 10.0);
+        match calibration.solve(&TcpOptions::default()) {
+            Err(DobotError::Calibration(errors)) => {
+                assert!(errors[0].contains("different headings"), "{:?}", errors)
+            }
+            other => panic!("expected a calibration error, got {:?}", other),
+        }
+
+        // The tip slipped 5 mm off the point for the last sample.
+        touch(&mut calibration, &sim, &tool, &current, POINT, -20.0);
+        let slipped = [POINT[0] + 5.0, POINT[1], POINT[2]];
+        touch(&mut calibration, &sim, &tool, &current, slipped, 35.0);
+        match calibration.solve(&TcpOptions::default()) {
+            Err(DobotError::Calibration(errors)) => {
+                assert!(
+                    errors.iter().any(|e| e.starts_with("sample 3")),
+                    "{:?}",
+                    errors
+                )
+            }
+            other => panic!("expected a calibration error, got {:?}", other),
+        }
+
+        calibration.clear();
+        assert!(calibration.samples().is_empty());
+    }
+
+    #[test]
+    fn test_tool_presets_switch_the_bias() {
+        let arm = DobotHandle::new(SimulatedCommandSender::new());
+        let mut presets = ToolPresets::new();
+        presets.insert("pen", bias(61.0, 0.0, 0.0));
+        presets.insert("laser", bias(70.0, 0.0, 0.0));
+        assert_eq!(
+            presets.insert("pen", bias(62.0, 0.0, -3.0)),
+            Some(bias(61.0, 0.0, 0.0))
+        );
+
+        presets.select(&arm, "pen").unwrap();
+
+        assert_eq!(
+            arm.controller()
+                .end_effector_controller
+                .get_end_effector_params()
+                .unwrap(),
+            bias(62.0, 0.0, -3.0)
+        );
+        assert!(matches!(
+            presets.select(&arm, "gripper"),
+            Err(DobotError::UnknownTool(name)) if name == "gripper"
+        ));
+        assert_eq!(presets.remove("laser"), Some(bias(70.0, 0.0, 0.0)));
+        assert_eq!(presets.tools.len(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_tool_presets_serde_round_trip() {
+        let mut presets = ToolPresets::new();
+        presets.insert("gripper", bias(59.7, 0.0, 0.0));
+
+        let json = serde_json::to_string(&presets).unwrap();
+
+        assert_eq!(serde_json::from_str::<ToolPresets>(&json).unwrap(), presets);
+    }
+}
@@ -0,0 +1,62 @@
+mod test;
+
+use std::{
+    sync::{
+        Arc, Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+/// Locks `mutex`, taking over the state of a thread that panicked while
+/// holding it.
+///
+/// The background workers only keep flags and records behind their mutexes,
+/// so a panic cannot leave them half-updated.
+pub(crate) fn lock<U>(mutex: &Mutex<U>) -> MutexGuard<'_, U> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A thread that calls a task right away and then once every interval.
+///
+/// [`PeriodicWorker::stop`] wakes the thread from its wait, so stopping never
+/// takes longer than the task currently running. The thread is stopped when
+/// the worker is dropped.
+pub(crate) struct PeriodicWorker {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl PeriodicWorker {
+    pub(crate) fn spawn(interval: Duration, mut task: impl FnMut() + Send + 'static) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let thread = thread::spawn(move || {
+            while !thread_stop.load(Ordering::SeqCst) {
+                task();
+                thread::park_timeout(interval);
+            }
+        });
+        Self {
+            stop,
+            thread: Some(thread),
+        }
+    }
+
+    /// Stops the thread and waits for it to finish. Does nothing if it was
+    /// already stopped.
+    pub(crate) fn stop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for PeriodicWorker {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
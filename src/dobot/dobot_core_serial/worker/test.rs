@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use crate::dobot::dobot_core_serial::worker::PeriodicWorker;
+
+    fn counting(interval: Duration) -> (PeriodicWorker, Arc<AtomicUsize>) {
+        let count = Arc::new(AtomicUsize::new(0));
+        let task_count = Arc::clone(&count);
+        let worker = PeriodicWorker::spawn(interval, move || {
+            task_count.fetch_add(1, Ordering::SeqCst);
+        });
+        (worker, count)
+    }
+
+    #[test]
+    fn test_task_runs_until_stopped() {
+        let (mut worker, count) = counting(Duration::from_millis(5));
+
+        let started = Instant::now();
+        while count.load(Ordering::SeqCst) < 3 {
+            assert!(started.elapsed() < Duration::from_secs(5), "task never ran");
+            thread::sleep(Duration::from_millis(5));
+        }
+        worker.stop();
+        let stopped_at = count.load(Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(30));
+
+        assert_eq!(count.load(Ordering::SeqCst), stopped_at);
+        worker.stop();
+    }
+
+    #[test]
+    fn test_stop_does_not_wait_out_the_interval() {
+        let (worker, count) = counting(Duration::from_secs(60));
+        let started = Instant::now();
+        while count.load(Ordering::SeqCst) == 0 {
+            assert!(started.elapsed() < Duration::from_secs(5), "task never ran");
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        let started = Instant::now();
+        drop(worker);
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+}
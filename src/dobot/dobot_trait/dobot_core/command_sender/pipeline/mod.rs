@@ -0,0 +1,262 @@
+mod test;
+
+use crate::dobot::dobot_trait::{
+    dobot_core::dobot_error::DobotError,
+    protocol::{
+        Body, COMMAND_ID_OFFSET, MAX_PACKET_SIZE, Protocol, ProtocolError,
+        bodies::{general_response::GeneralResponse, tag_queue::received::TagQueue},
+        command::{QUEUED_RESPONSE_SIZE, Queueable},
+    },
+};
+
+use super::CommandSender;
+
+/// A sender that can write a request before the replies to earlier requests
+/// have been read.
+///
+/// Replies must be returned in the order the requests were sent.
+pub trait PipelinedSender: CommandSender {
+    /// Writes `request_packet` without waiting for its reply.
+    fn send_packet(&mut self, request_packet: &[u8]) -> Result<(), DobotError>;
+
+    /// Waits for the reply to `request_packet`, the oldest request whose
+    /// reply has not been read yet.
+    fn receive_packet(
+        &mut self,
+        request_packet: &[u8],
+        response_buffer: &mut [u8],
+    ) -> Result<usize, DobotError>;
+}
+
+/// A fixed ring of up to `N` encoded frames.
+///
+/// Frames are encoded in place, so pushing and popping never allocates.
+pub struct FrameRing<const N: usize> {
+    frames: [[u8; MAX_PACKET_SIZE]; N],
+    lengths: [usize; N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for FrameRing<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> FrameRing<N> {
+    pub const fn new() -> Self {
+        Self {
+            frames: [[0u8; MAX_PACKET_SIZE]; N],
+            lengths: [0; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Lets `encode` write a frame into the next free slot and returns it.
+    ///
+    /// Nothing is added if the ring is full or `encode` fails.
+    pub fn push_with(
+        &mut self,
+        encode: impl FnOnce(&mut [u8]) -> Result<usize, ProtocolError>,
+    ) -> Result<&[u8], ProtocolError> {
+        if self.is_full() {
+            return Err(ProtocolError::BufferTooSmall);
+        }
+        let slot = (self.head + self.len) % N;
+        let len = encode(&mut self.frames[slot])?;
+        self.lengths[slot] = len;
+        self.len += 1;
+        Ok(&self.frames[slot][..len])
+    }
+
+    /// Returns the oldest frame.
+    pub fn front(&self) -> Option<&[u8]> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(&self.frames[self.head][..self.lengths[self.head]])
+    }
+
+    /// Removes the oldest frame.
+    pub fn pop_front(&mut self) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        true
+    }
+
+    /// Removes every frame.
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Removes the newest frame.
+    pub fn pop_back(&mut self) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        self.len -= 1;
+        true
+    }
+}
+
+/// Streams queued commands with up to `N` requests in flight.
+///
+/// Stop-and-wait exchanges spend most of their time waiting for the round
+/// trip; a pipeline writes the next request while earlier replies are still
+/// on their way. Replies are matched to requests in order by command ID.
+///
+/// A transport error or a reply to another command poisons the pipeline:
+/// the requests still in flight are forgotten and every later call returns
+/// [`DobotError::PipelinePoisoned`]. Bringing the transport back in step is
+/// left to the caller.
+///
+/// Dropping a healthy pipeline reads the replies still in flight, so the
+/// transport is left in step for the next command.
+pub struct Pipeline<'d, T: PipelinedSender, const N: usize> {
+    sender: &'d mut T,
+    ring: FrameRing<N>,
+    poisoned: bool,
+}
+
+impl<'d, T: PipelinedSender, const N: usize> Pipeline<'d, T, N> {
+    pub fn new(sender: &'d mut T) -> Self {
+        const { assert!(N > 0, "a pipeline needs room for at least one request") };
+        Self {
+            sender,
+            ring: FrameRing::new(),
+            poisoned: false,
+        }
+    }
+
+    /// Number of requests whose replies have not been read yet.
+    pub fn in_flight(&self) -> usize {
+        self.ring.len()
+    }
+
+    /// Whether an earlier error left the pipeline out of step.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned
+    }
+
+    fn poison(&mut self, error: DobotError) -> DobotError {
+        self.poisoned = true;
+        self.ring.clear();
+        error
+    }
+
+    /// Sends `request` to the command queue.
+    ///
+    /// If `N` requests are already in flight, the reply to the oldest one is
+    /// read first and its queue index returned.
+    pub fn send<C: Queueable>(
+        &mut self,
+        request: C::Request<'_>,
+    ) -> Result<Option<u64>, DobotError> {
+        if self.poisoned {
+            return Err(DobotError::PipelinePoisoned);
+        }
+        let completed = if self.ring.is_full() {
+            self.receive()?
+        } else {
+            None
+        };
+
+        let protocol = Protocol::new(C::ID, true, C::IS_WRITE, request);
+        let frame = self.ring.push_with(|buffer| protocol.to_packet(buffer))?;
+        if let Err(e) = self.sender.send_packet(frame) {
+            return Err(self.poison(e));
+        }
+        Ok(completed)
+    }
+
+    /// Waits for the reply to the oldest request in flight and returns its
+    /// queue index, or `None` if nothing is in flight.
+    pub fn receive(&mut self) -> Result<Option<u64>, DobotError> {
+        if self.poisoned {
+            return Err(DobotError::PipelinePoisoned);
+        }
+        let Some(request) = self.ring.front() else {
+            return Ok(None);
+        };
+        let expected = request[COMMAND_ID_OFFSET];
+        let mut response_buffer = [0u8; MAX_PACKET_SIZE];
+        let len = match self.sender.receive_packet(request, &mut response_buffer) {
+            Ok(len) => len,
+            Err(e) => return Err(self.poison(e)),
+        };
+        self.ring.pop_front();
+
+        let response = match Protocol::<GeneralResponse>::from_packet(&response_buffer[..len]) {
+            Ok(response) => response,
+            Err(e) => return Err(self.poison(e.into())),
+        };
+        let received: u8 = response.command_id.into();
+        if received != expected {
+            return Err(
+                self.poison(DobotError::Protocol(ProtocolError::UnexpectedResponse {
+                    expected,
+                    received,
+                })),
+            );
+        }
+        let params = response.body.params;
+        if params.len() > QUEUED_RESPONSE_SIZE {
+            return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
+        }
+        Ok(Some(TagQueue::deserialize(params)?.queue_idx))
+    }
+
+    /// Reads every reply still in flight, passing the queue indices to
+    /// `on_index` in order.
+    pub fn flush(&mut self, mut on_index: impl FnMut(u64)) -> Result<(), DobotError> {
+        while let Some(index) = self.receive()? {
+            on_index(index);
+        }
+        Ok(())
+    }
+
+    /// Sends every request and waits for all replies, passing the queue
+    /// indices to `on_index` in the order of `requests`.
+    pub fn stream<'r, C: Queueable>(
+        &mut self,
+        requests: impl IntoIterator<Item = C::Request<'r>>,
+        mut on_index: impl FnMut(u64),
+    ) -> Result<(), DobotError> {
+        for request in requests {
+            if let Some(index) = self.send::<C>(request)? {
+                on_index(index);
+            }
+        }
+        self.flush(on_index)
+    }
+}
+
+impl<T: PipelinedSender, const N: usize> Drop for Pipeline<'_, T, N> {
+    fn drop(&mut self) {
+        while !self.poisoned && !self.ring.is_empty() {
+            let _ = self.receive();
+        }
+    }
+}
@@ -0,0 +1,231 @@
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::dobot::dobot_trait::{
+        dobot_core::{
+            command_sender::{
+                CommandSender, Dobot,
+                pipeline::{FrameRing, Pipeline, PipelinedSender},
+                simulated_command_sender::SimulatedCommandSender,
+            },
+            dobot_error::DobotError,
+        },
+        protocol::{
+            MAX_PACKET_SIZE, Protocol, ProtocolError,
+            bodies::{
+                tag_cp_cmd::{CPMode, TagCPCmd},
+                tag_empty_body::EmptyBody,
+            },
+            command::{Command, GetPose, SetCpCmd},
+        },
+    };
+
+    fn points(count: usize) -> impl Iterator<Item = TagCPCmd> {
+        (0..count).map(|i| TagCPCmd {
+            cp_mode: CPMode::Absolute,
+            x: 200.0 + i as f32,
+            y: 0.0,
+            z: 10.0,
+            velocity_or_power: 50.0,
+        })
+    }
+
+    /// Sends a `GetPose` in place of every request, so replies never match.
+    #[derive(Debug)]
+    struct Misrouted(SimulatedCommandSender);
+
+    impl CommandSender for Misrouted {
+        fn send_raw_packet(
+            &mut self,
+            request_packet: &[u8],
+            response_buffer: &mut [u8],
+        ) -> Result<usize, DobotError> {
+            self.0.send_raw_packet(request_packet, response_buffer)
+        }
+
+        fn get_status_str(&self, buffer: &mut [u8]) -> Result<usize, DobotError> {
+            self.0.get_status_str(buffer)
+        }
+    }
+
+    impl PipelinedSender for Misrouted {
+        fn send_packet(&mut self, _request_packet: &[u8]) -> Result<(), DobotError> {
+            let mut buffer = [0u8; MAX_PACKET_SIZE];
+            let len =
+                Protocol::new(GetPose::ID, false, false, EmptyBody {}).to_packet(&mut buffer)?;
+            self.0.send_packet(&buffer[..len])
+        }
+
+        fn receive_packet(
+            &mut self,
+            request_packet: &[u8],
+            response_buffer: &mut [u8],
+        ) -> Result<usize, DobotError> {
+            self.0.receive_packet(request_packet, response_buffer)
+        }
+    }
+
+    /// Loses the reply to the first request it is asked for.
+    #[derive(Debug)]
+    struct Lossy(SimulatedCommandSender, bool);
+
+    impl CommandSender for Lossy {
+        fn send_raw_packet(
+            &mut self,
+            request_packet: &[u8],
+            response_buffer: &mut [u8],
+        ) -> Result<usize, DobotError> {
+            self.0.send_raw_packet(request_packet, response_buffer)
+        }
+
+        fn get_status_str(&self, buffer: &mut [u8]) -> Result<usize, DobotError> {
+            self.0.get_status_str(buffer)
+        }
+    }
+
+    impl PipelinedSender for Lossy {
+        fn send_packet(&mut self, request_packet: &[u8]) -> Result<(), DobotError> {
+            self.0.send_packet(request_packet)
+        }
+
+        fn receive_packet(
+            &mut self,
+            request_packet: &[u8],
+            response_buffer: &mut [u8],
+        ) -> Result<usize, DobotError> {
+            if std::mem::take(&mut self.1) {
+                return Err(DobotError::Timeout);
+            }
+            self.0.receive_packet(request_packet, response_buffer)
+        }
+    }
+
+    #[test]
+    fn test_frame_ring_wraps_around() {
+        let mut ring = FrameRing::<2>::new();
+        let frame = |byte: u8| {
+            move |buffer: &mut [u8]| {
+                buffer[0] = byte;
+                Ok(1)
+            }
+        };
+
+        assert_eq!(ring.push_with(frame(1)), Ok(&[1u8][..]));
+        assert_eq!(ring.push_with(frame(2)), Ok(&[2u8][..]));
+        assert_eq!(ring.push_with(frame(3)), Err(ProtocolError::BufferTooSmall));
+        assert!(ring.pop_front());
+        assert_eq!(ring.push_with(frame(4)), Ok(&[4u8][..]));
+
+        assert_eq!(ring.front(), Some(&[2u8][..]));
+        assert!(ring.pop_front());
+        assert_eq!(ring.front(), Some(&[4u8][..]));
+        assert!(ring.pop_back());
+        assert!(ring.is_empty());
+        assert!(!ring.pop_front());
+    }
+
+    #[test]
+    fn test_stream_collects_queue_indices_in_order() {
+        let sim = SimulatedCommandSender::new();
+        let mut dobot = Dobot::new(sim.clone());
+        let mut indices = Vec::new();
+
+        dobot
+            .pipeline::<4>()
+            .stream::<SetCpCmd>(points(10), |index| indices.push(index))
+            .unwrap();
+
+        let first = indices[0];
+        assert_eq!(indices, (first..first + 10).collect::<Vec<_>>());
+        let sent: Vec<_> = sim.commands().iter().map(|c| c.params.clone()).collect();
+        assert_eq!(sent.len(), 10);
+        assert_eq!(&sent[9][1..5], &209.0f32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_pipelining_beats_stop_and_wait() {
+        const COUNT: usize = 40;
+        let sim = SimulatedCommandSender::new();
+        sim.set_latency(Duration::from_millis(5));
+        let mut dobot = Dobot::new(sim.clone());
+
+        let started = Instant::now();
+        for point in points(COUNT) {
+            dobot.execute_queued::<SetCpCmd>(point).unwrap();
+        }
+        let stop_and_wait = started.elapsed();
+
+        let started = Instant::now();
+        let mut completed = 0;
+        dobot
+            .pipeline::<8>()
+            .stream::<SetCpCmd>(points(COUNT), |_| completed += 1)
+            .unwrap();
+        let pipelined = started.elapsed();
+
+        assert_eq!(completed, COUNT);
+        assert!(
+            pipelined * 3 < stop_and_wait,
+            "pipelined {:?}, stop-and-wait {:?}",
+            pipelined,
+            stop_and_wait
+        );
+    }
+
+    #[test]
+    fn test_reply_to_another_command_is_rejected() {
+        let mut sender = Misrouted(SimulatedCommandSender::new());
+        let mut pipeline = Pipeline::<_, 2>::new(&mut sender);
+
+        assert_eq!(
+            pipeline.send::<SetCpCmd>(points(1).next().unwrap()).ok(),
+            Some(None)
+        );
+        assert_eq!(pipeline.in_flight(), 1);
+        assert!(matches!(
+            pipeline.receive(),
+            Err(DobotError::Protocol(ProtocolError::UnexpectedResponse {
+                expected,
+                received,
+            })) if expected == u8::from(SetCpCmd::ID) && received == u8::from(GetPose::ID)
+        ));
+        assert_eq!(pipeline.in_flight(), 0);
+        assert!(pipeline.is_poisoned());
+        assert!(matches!(
+            pipeline.receive(),
+            Err(DobotError::PipelinePoisoned)
+        ));
+    }
+
+    #[test]
+    fn test_transport_error_poisons_the_pipeline() {
+        let sim = SimulatedCommandSender::new();
+        let mut sender = Lossy(sim.clone(), true);
+        let mut pipeline = Pipeline::<_, 4>::new(&mut sender);
+        let mut requests = points(3);
+
+        for request in requests.by_ref().take(2) {
+            assert_eq!(pipeline.send::<SetCpCmd>(request).ok(), Some(None));
+        }
+        assert!(matches!(pipeline.receive(), Err(DobotError::Timeout)));
+        assert!(pipeline.is_poisoned());
+        assert_eq!(pipeline.in_flight(), 0);
+        assert!(matches!(
+            pipeline.receive(),
+            Err(DobotError::PipelinePoisoned)
+        ));
+        assert!(matches!(
+            pipeline.send::<SetCpCmd>(requests.next().unwrap()),
+            Err(DobotError::PipelinePoisoned)
+        ));
+        drop(pipeline);
+
+        // Nothing more was written, and the replies are left for the caller.
+        assert_eq!(sim.commands().len(), 2);
+        let mut buffer = [0u8; MAX_PACKET_SIZE];
+        let mut rest = sim.clone();
+        assert!(rest.receive_packet(&[], &mut buffer).is_ok());
+        assert!(rest.receive_packet(&[], &mut buffer).is_ok());
+    }
+}
@@ -0,0 +1,588 @@
+use core::fmt::Debug;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::dobot::dobot_trait::{
+    dobot_core::dobot_error::DobotError,
+    protocol::{
+        Body, CommunicationProtocolIDs, MAX_PACKET_SIZE, Protocol, ProtocolError,
+        bodies::{
+            general_request::GeneralRequest, general_response::GeneralResponse, tag_pose::TagPose,
+        },
+        command_id::{
+            AlarmIDs, DeviceInfoIDs, DevicePoseIDs, EioIDs, HomeIDs, JogIDs, PtpIDs,
+            QueuedCmdIDs,
+        },
+    },
+};
+
+use super::{CommandSender, pipeline::PipelinedSender};
+
+/// A single request as it was received by the [`SimulatedCommandSender`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedCommand {
+    pub id: u8,
+    pub is_write: bool,
+    pub is_queued: bool,
+    pub params: Vec<u8>,
+}
+
+/// The mutable state behind a [`SimulatedCommandSender`].
+///
+/// Every clone of a simulated sender shares the same state, so tests can keep
+/// a handle to inspect the arm or inject faults after the sender itself has
+/// been moved into a `Dobot`.
+#[derive(Debug)]
+pub struct SimulatorState {
+    pub serial_number: Vec<u8>,
+    pub name: Vec<u8>,
+    pub version: (u8, u8, u8),
+    pub with_rail: bool,
+    pub device_id: (u32, u32, u32),
+    pub tick: u32,
+    pub pose: TagPose,
+    pub rail_position: f32,
+    /// One bit per alarm code, laid out like the firmware's alarm state.
+    pub alarms: [u8; 32],
+    pub queue_running: bool,
+    pub last_queued_index: u64,
+    pub executed_index: u64,
+    pub connected: bool,
+    pub latency: Duration,
+    pending: Vec<(u64, u8, Vec<u8>)>,
+    /// The JOG motion in progress: joint mode, command and when the pose was
+    /// last moved along it.
+    jog: Option<(bool, u8, Instant)>,
+    /// Replies to pipelined requests and when they arrive at the host.
+    in_flight: VecDeque<(Instant, Vec<u8>)>,
+    params: HashMap<(u8, Option<u8>), Vec<u8>>,
+    pinned: HashMap<u8, Vec<u8>>,
+    log: Vec<SimulatedCommand>,
+}
+
+impl Default for SimulatorState {
+    fn default() -> Self {
+        Self {
+            serial_number: b"SIM-0000".to_vec(),
+            name: b"Simulated Dobot".to_vec(),
+            version: (3, 7, 0),
+            with_rail: false,
+            device_id: (0, 0, 0),
+            tick: 0,
+            pose: TagPose {
+                x: 200.0,
+                y: 0.0,
+                z: 0.0,
+                r: 0.0,
+                joint_angle: [0.0; 4],
+            },
+            rail_position: 0.0,
+            alarms: [0u8; 32],
+            queue_running: true,
+            last_queued_index: 0,
+            executed_index: 0,
+            connected: true,
+            latency: Duration::ZERO,
+            pending: Vec::new(),
+            jog: None,
+            in_flight: VecDeque::new(),
+            params: HashMap::from([(
+                (
+                    CommunicationProtocolIDs::Home(HomeIDs::HomeParams).into(),
+                    None,
+                ),
+                [200.0f32, 0.0, 0.0, 0.0]
+                    .iter()
+                    .flat_map(|value| value.to_le_bytes())
+                    .collect(),
+            )]),
+            pinned: HashMap::new(),
+            log: Vec::new(),
+        }
+    }
+}
+
+/// An in-memory Dobot Magician used to exercise controllers without hardware.
+///
+/// Unlike [`super::mock_command_sender::MockCommandSender`], which replays a
+/// single canned packet, the simulator decodes every request and answers it
+/// from a small model of the arm: parameters are stored and read back, queued
+/// commands receive increasing queue indices, motion commands move the pose
+/// and alarms can be raised from the outside.
+#[derive(Clone, Default)]
+pub struct SimulatedCommandSender {
+    state: Arc<Mutex<SimulatorState>>,
+}
+
+impl SimulatedCommandSender {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a simulator that reports the given device name and serial number.
+    pub fn with_identity(name: &str, serial_number: &str) -> Self {
+        let sender = Self::new();
+        sender.with_state(|state| {
+            state.name = name.as_bytes().to_vec();
+            state.serial_number = serial_number.as_bytes().to_vec();
+        });
+        sender
+    }
+
+    /// Runs `f` with exclusive access to the simulator state.
+    pub fn with_state<R>(&self, f: impl FnOnce(&mut SimulatorState) -> R) -> R {
+        let mut state = match self.state.lock() {
+            Ok(x) => x,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        f(&mut state)
+    }
+
+    /// Sets the alarm bit for `code`.
+    pub fn raise_alarm(&self, code: u8) {
+        self.with_state(|state| state.alarms[code as usize / 8] |= 1 << (code % 8));
+    }
+
+    /// Simulates unplugging (`false`) or replugging (`true`) the arm.
+    pub fn set_connected(&self, connected: bool) {
+        self.with_state(|state| state.connected = connected);
+    }
+
+    /// Delays every reply by `latency`.
+    pub fn set_latency(&self, latency: Duration) {
+        self.with_state(|state| state.latency = latency);
+    }
+
+    /// Makes every read of `id` answer `params`, whatever was written to it,
+    /// like firmware that clamps or ignores a setting.
+    pub fn pin_reply(&self, id: CommunicationProtocolIDs, params: &[u8]) {
+        self.with_state(|state| state.pinned.insert(id.into(), params.to_vec()));
+    }
+
+    /// Returns a copy of every request received so far.
+    pub fn commands(&self) -> Vec<SimulatedCommand> {
+        self.with_state(|state| state.log.clone())
+    }
+
+    /// Returns the current pose of the simulated arm.
+    pub fn pose(&self) -> TagPose {
+        self.with_state(|state| {
+            state.advance_jog();
+            state.pose.clone()
+        })
+    }
+
+    /// Returns the current position of the simulated rail.
+    pub fn rail_position(&self) -> f32 {
+        self.with_state(|state| {
+            state.advance_jog();
+            state.rail_position
+        })
+    }
+}
+
+impl Debug for SimulatedCommandSender {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SimulatedCommandSender")
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+fn read_f32(params: &[u8], offset: usize) -> f32 {
+    params
+        .get(offset..offset + 4)
+        .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .unwrap_or(0.0)
+}
+
+/// JOG velocity, in mm/s or degrees/s, for parameters that were never written.
+const DEFAULT_JOG_VELOCITY: f32 = 50.0;
+
+/// Size of the reply body for a read of a parameter that was never written.
+fn default_read_len(id: CommunicationProtocolIDs) -> usize {
+    use crate::dobot::dobot_trait::protocol::command_id::{
+        ArcIDs, CalIDs, CpIDs, EndEffectorIDs, HHTIDs, JogIDs, WifiIDs,
+    };
+    match id {
+        CommunicationProtocolIDs::Home(HomeIDs::HomeParams) => 16,
+        CommunicationProtocolIDs::Home(_) => 4,
+        CommunicationProtocolIDs::HHT(HHTIDs::HHTTrigMode) => 1,
+        CommunicationProtocolIDs::HHT(_) => 1,
+        CommunicationProtocolIDs::EndEffector(EndEffectorIDs::Params) => 12,
+        CommunicationProtocolIDs::EndEffector(_) => 2,
+        CommunicationProtocolIDs::Jog(JogIDs::JointParams | JogIDs::CoordinateParams) => 32,
+        CommunicationProtocolIDs::Jog(_) => 8,
+        CommunicationProtocolIDs::Ptp(PtpIDs::JointParams) => 32,
+        CommunicationProtocolIDs::Ptp(PtpIDs::CoordinateParams) => 16,
+        CommunicationProtocolIDs::Ptp(PtpIDs::JumpToParams) => 12,
+        CommunicationProtocolIDs::Ptp(_) => 8,
+        CommunicationProtocolIDs::Cp(CpIDs::CpParams) => 13,
+        CommunicationProtocolIDs::Arc(ArcIDs::ArcParams) => 16,
+        CommunicationProtocolIDs::Cal(CalIDs::AngleSensorStaticError | CalIDs::AngleSensorCoef) => {
+            8
+        }
+        CommunicationProtocolIDs::Cal(_) => 4,
+        CommunicationProtocolIDs::Eio(EioIDs::IoPwm) => 9,
+        CommunicationProtocolIDs::Eio(EioIDs::IoMultiplexing | EioIDs::Iodo | EioIDs::Iodi) => 2,
+        CommunicationProtocolIDs::Eio(EioIDs::IoAdc) => 3,
+        CommunicationProtocolIDs::Eio(EioIDs::ColorSensor) => 3,
+        CommunicationProtocolIDs::Eio(_) => 1,
+        CommunicationProtocolIDs::Wifi(WifiIDs::IpAddress) => 5,
+        CommunicationProtocolIDs::Wifi(WifiIDs::Netmask | WifiIDs::Gateway | WifiIDs::Dns) => 4,
+        CommunicationProtocolIDs::Wifi(WifiIDs::Ssid | WifiIDs::Password) => 0,
+        CommunicationProtocolIDs::Wifi(_) => 1,
+        _ => 0,
+    }
+}
+
+impl SimulatorState {
+    /// Address-indexed EIO reads and writes are stored per address.
+    fn param_key(id: CommunicationProtocolIDs, params: &[u8]) -> (u8, Option<u8>) {
+        match id {
+            CommunicationProtocolIDs::Eio(_) => (id.into(), params.first().copied()),
+            _ => (id.into(), None),
+        }
+    }
+
+    /// Speed of a JOG motion with the stored JOG parameters.
+    fn jog_velocity(&self, is_joint: bool, cmd: u8) -> f32 {
+        let stored = |id: JogIDs, offset: usize| {
+            self.params
+                .get(&(CommunicationProtocolIDs::Jog(id).into(), None))
+                .map(|params| read_f32(params, offset))
+        };
+        let axis = usize::from(cmd.saturating_sub(1) / 2);
+        let velocity = match cmd {
+            9 | 10 => stored(JogIDs::LParams, 0),
+            _ if is_joint => stored(JogIDs::JointParams, axis * 4),
+            _ => stored(JogIDs::CoordinateParams, axis * 4),
+        };
+        let ratio = stored(JogIDs::CommonParams, 0).unwrap_or(100.0) / 100.0;
+        velocity.unwrap_or(DEFAULT_JOG_VELOCITY) * ratio
+    }
+
+    /// Moves the arm along the JOG motion in progress up to now.
+    fn advance_jog(&mut self) {
+        let Some((is_joint, cmd, since)) = self.jog else {
+            return;
+        };
+        let now = Instant::now();
+        let sign = if cmd % 2 == 1 { 1.0 } else { -1.0 };
+        let delta =
+            sign * self.jog_velocity(is_joint, cmd) * now.duration_since(since).as_secs_f32();
+        let axis = usize::from((cmd - 1) / 2);
+        match cmd {
+            9 | 10 => self.rail_position += delta,
+            _ if is_joint => self.pose.joint_angle[axis] += delta,
+            _ => match axis {
+                0 => self.pose.x += delta,
+                1 => self.pose.y += delta,
+                2 => self.pose.z += delta,
+                _ => self.pose.r += delta,
+            },
+        }
+        self.jog = Some((is_joint, cmd, now));
+    }
+
+    fn execute(&mut self, id: u8, params: &[u8]) {
+        let Ok(id) = CommunicationProtocolIDs::try_from(id) else {
+            return;
+        };
+        match id {
+            CommunicationProtocolIDs::Jog(JogIDs::Cmd) => {
+                self.advance_jog();
+                self.jog = match params {
+                    [mode, cmd @ 1..=10, ..] => Some((*mode != 0, *cmd, Instant::now())),
+                    _ => None,
+                };
+            }
+            CommunicationProtocolIDs::Ptp(PtpIDs::Cmd | PtpIDs::WithLCmd) => {
+                let mode = params.first().copied().unwrap_or(0);
+                let (x, y, z, r) = (
+                    read_f32(params, 1),
+                    read_f32(params, 5),
+                    read_f32(params, 9),
+                    read_f32(params, 13),
+                );
+                match mode {
+                    // JumpXyz, MovjXyz, MovlXyz, JumpMovlXyz
+                    0x00..=0x02 | 0x09 => {
+                        self.pose.x = x;
+                        self.pose.y = y;
+                        self.pose.z = z;
+                        self.pose.r = r;
+                    }
+                    // JumpAngle, MovjAngle, MovlAngle
+                    0x03..=0x05 => self.pose.joint_angle = [x, y, z, r],
+                    // MovjInc
+                    0x06 => {
+                        for (angle, delta) in self.pose.joint_angle.iter_mut().zip([x, y, z, r]) {
+                            *angle += delta;
+                        }
+                    }
+                    // MovlInc, MovjXyzInc
+                    _ => {
+                        self.pose.x += x;
+                        self.pose.y += y;
+                        self.pose.z += z;
+                        self.pose.r += r;
+                    }
+                }
+                if id == CommunicationProtocolIDs::Ptp(PtpIDs::WithLCmd) {
+                    self.rail_position = read_f32(params, 17);
+                }
+            }
+            CommunicationProtocolIDs::Home(HomeIDs::HomeCmd) => {
+                let key = (
+                    CommunicationProtocolIDs::Home(HomeIDs::HomeParams).into(),
+                    None,
+                );
+                let home = self.params.get(&key).cloned().unwrap_or_default();
+                self.pose.x = read_f32(&home, 0);
+                self.pose.y = read_f32(&home, 4);
+                self.pose.z = read_f32(&home, 8);
+                self.pose.r = read_f32(&home, 12);
+                self.rail_position = 0.0;
+            }
+            // Levels to the requested accuracy, which is then read back as
+            // the result.
+            CommunicationProtocolIDs::Home(HomeIDs::AutoLeveling) => {
+                let accuracy = read_f32(params, 1).to_le_bytes().to_vec();
+                self.params.insert((id.into(), None), accuracy);
+            }
+            _ => {}
+        }
+    }
+
+    fn enqueue(&mut self, id: u8, params: &[u8]) -> u64 {
+        self.last_queued_index += 1;
+        let index = self.last_queued_index;
+        if self.queue_running {
+            self.execute(id, params);
+            self.executed_index = index;
+        } else {
+            self.pending.push((index, id, params.to_vec()));
+        }
+        index
+    }
+
+    fn run_pending(&mut self) {
+        for (index, id, params) in core::mem::take(&mut self.pending) {
+            self.execute(id, &params);
+            self.executed_index = index;
+        }
+    }
+
+    fn respond(
+        &mut self,
+        id: CommunicationProtocolIDs,
+        is_write: bool,
+        is_queued: bool,
+        params: &[u8],
+    ) -> Vec<u8> {
+        self.tick = self.tick.wrapping_add(1);
+        self.advance_jog();
+        if is_queued {
+            let index = self.enqueue(id.into(), params);
+            return index.to_le_bytes().to_vec();
+        }
+        if !is_write && let Some(pinned) = self.pinned.get(&id.into()) {
+            return pinned.clone();
+        }
+
+        match id {
+            CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Sn) => {
+                if is_write {
+                    self.serial_number = params.to_vec();
+                    return Vec::new();
+                }
+                self.serial_number.clone()
+            }
+            CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Name) => {
+                if is_write {
+                    self.name = params.to_vec();
+                    return Vec::new();
+                }
+                self.name.clone()
+            }
+            CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Version) => {
+                vec![self.version.0, self.version.1, self.version.2]
+            }
+            CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::WithRail) => {
+                if is_write {
+                    self.with_rail = params.first().is_some_and(|x| *x != 0);
+                    return Vec::new();
+                }
+                vec![self.with_rail as u8]
+            }
+            CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Time) => {
+                self.tick.to_le_bytes().to_vec()
+            }
+            CommunicationProtocolIDs::DeviceInfo(DeviceInfoIDs::Id) => {
+                let mut bytes = self.device_id.0.to_le_bytes().to_vec();
+                bytes.extend_from_slice(&self.device_id.1.to_le_bytes());
+                bytes.extend_from_slice(&self.device_id.2.to_le_bytes());
+                bytes
+            }
+            CommunicationProtocolIDs::DevicePose(DevicePoseIDs::GetPose) => {
+                let mut bytes = [0u8; 32];
+                let _ = self.pose.serialize(&mut bytes);
+                bytes.to_vec()
+            }
+            CommunicationProtocolIDs::DevicePose(DevicePoseIDs::GetPoseL) => {
+                self.rail_position.to_le_bytes().to_vec()
+            }
+            CommunicationProtocolIDs::Alarm(AlarmIDs::GetAlarmState) => self.alarms.to_vec(),
+            CommunicationProtocolIDs::Alarm(AlarmIDs::ClearAlarmState) => {
+                self.alarms = [0u8; 32];
+                Vec::new()
+            }
+            CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::StartExec) => {
+                self.queue_running = true;
+                self.run_pending();
+                Vec::new()
+            }
+            CommunicationProtocolIDs::QueuedCmd(
+                QueuedCmdIDs::StopExec | QueuedCmdIDs::ForceStopExec,
+            ) => {
+                self.queue_running = false;
+                Vec::new()
+            }
+            CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::Clear) => {
+                self.pending.clear();
+                Vec::new()
+            }
+            CommunicationProtocolIDs::QueuedCmd(QueuedCmdIDs::CurrentIndex) => {
+                self.executed_index.to_le_bytes().to_vec()
+            }
+            CommunicationProtocolIDs::QueuedCmd(_) => Vec::new(),
+            CommunicationProtocolIDs::Ptp(PtpIDs::Cmd | PtpIDs::WithLCmd)
+            | CommunicationProtocolIDs::Home(HomeIDs::HomeCmd)
+            | CommunicationProtocolIDs::Jog(JogIDs::Cmd) => {
+                self.execute(id.into(), params);
+                Vec::new()
+            }
+            CommunicationProtocolIDs::Home(HomeIDs::AutoLeveling) if is_write => {
+                self.execute(id.into(), params);
+                Vec::new()
+            }
+            _ => {
+                let key = Self::param_key(id, params);
+                if is_write {
+                    self.params.insert(key, params.to_vec());
+                    return Vec::new();
+                }
+                match self.params.get(&key) {
+                    Some(stored) => stored.clone(),
+                    None => {
+                        let mut reply = vec![0u8; default_read_len(id)];
+                        // Address-indexed replies start with the address.
+                        if let (Some(address), Some(first)) = (key.1, reply.first_mut()) {
+                            *first = address;
+                        }
+                        reply
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl SimulatedCommandSender {
+    /// Answers `request_packet` without any latency.
+    fn answer(&self, request_packet: &[u8], response_buffer: &mut [u8]) -> Result<usize, DobotError> {
+        let request = Protocol::<GeneralResponse>::from_packet(request_packet)?;
+        let params = request.body.params;
+        let reply = self.with_state(|state| {
+            if !state.connected {
+                return Err(DobotError::IO);
+            }
+            state.log.push(SimulatedCommand {
+                id: request.command_id.into(),
+                is_write: request.is_read,
+                is_queued: request.is_queued,
+                params: params.to_vec(),
+            });
+            Ok(state.respond(
+                request.command_id,
+                request.is_read,
+                request.is_queued,
+                params,
+            ))
+        })?;
+
+        let response = Protocol::new(
+            request.command_id,
+            request.is_queued,
+            request.is_read,
+            GeneralRequest { params: &reply },
+        );
+        let len = response
+            .to_packet(response_buffer)
+            .map_err(DobotError::Protocol)?;
+        Ok(len)
+    }
+}
+
+impl CommandSender for SimulatedCommandSender {
+    fn send_raw_packet(
+        &mut self,
+        request_packet: &[u8],
+        response_buffer: &mut [u8],
+    ) -> Result<usize, DobotError> {
+        let latency = self.with_state(|state| state.latency);
+        if !latency.is_zero() {
+            thread::sleep(latency);
+        }
+        self.answer(request_packet, response_buffer)
+    }
+
+    fn get_status_str(&self, buffer: &mut [u8]) -> Result<usize, DobotError>
+    where
+        Self: core::fmt::Debug,
+    {
+        let message = format!("Simulated sender status: {:?}", self);
+        if buffer.len() < message.len() {
+            return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
+        }
+        buffer[..message.len()].copy_from_slice(message.as_bytes());
+        Ok(message.len())
+    }
+}
+
+/// Requests are answered as soon as they are sent; each reply becomes
+/// readable one latency later, so replies to pipelined requests overlap.
+impl PipelinedSender for SimulatedCommandSender {
+    fn send_packet(&mut self, request_packet: &[u8]) -> Result<(), DobotError> {
+        let mut response_buffer = [0u8; MAX_PACKET_SIZE];
+        let len = self.answer(request_packet, &mut response_buffer)?;
+        self.with_state(|state| {
+            let arrival = Instant::now() + state.latency;
+            state
+                .in_flight
+                .push_back((arrival, response_buffer[..len].to_vec()));
+        });
+        Ok(())
+    }
+
+    fn receive_packet(
+        &mut self,
+        _request_packet: &[u8],
+        response_buffer: &mut [u8],
+    ) -> Result<usize, DobotError> {
+        let (arrival, reply) = self
+            .with_state(|state| state.in_flight.pop_front())
+            .ok_or(DobotError::NoResponse)?;
+        if response_buffer.len() < reply.len() {
+            return Err(DobotError::Protocol(ProtocolError::BufferTooSmall));
+        }
+        thread::sleep(arrival.saturating_duration_since(Instant::now()));
+        response_buffer[..reply.len()].copy_from_slice(&reply);
+        Ok(reply.len())
+    }
+}
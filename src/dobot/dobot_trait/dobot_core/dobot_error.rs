@@ -20,6 +20,57 @@ pub enum DobotError {
     #[error("A serial error occured: {0}")]
     SerialError(#[from] serialport::Error),
 
+    #[cfg(feature = "std")]
+    #[error("A worker thread panicked while talking to the device")]
+    WorkerPanicked,
+
+    #[cfg(feature = "std")]
+    #[error("No device with serial number {0} could be found")]
+    DeviceNotFound(String),
+
+    #[cfg(feature = "std")]
+    #[error("An arm named {0} is already in the fleet")]
+    DuplicateName(String),
+
+    #[cfg(feature = "std")]
+    #[error("Parameters read back from the device differ from the profile: {0:?}")]
+    ProfileVerification(Vec<String>),
+
+    #[cfg(feature = "std")]
+    #[error("Target is out of reach: {0}")]
+    OutOfReach(String),
+
+    #[cfg(feature = "std")]
+    #[error("The arm did not end up at its home position: {0:?}")]
+    HomeVerification(Vec<String>),
+
+    #[cfg(feature = "std")]
+    #[error("Calibration failed: {0:?}")]
+    Calibration(Vec<String>),
+
+    #[cfg(feature = "std")]
+    #[error("Couldn't teach frame: {0}")]
+    FrameTeaching(String),
+
+    #[cfg(feature = "std")]
+    #[error("No tool preset named {0}")]
+    UnknownTool(String),
+
+    #[cfg(feature = "std")]
+    #[error("Couldn't work out where the move ends: {0}")]
+    UntrackedMove(String),
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[error("Couldn't convert profile: {0}")]
+    ProfileFormat(String),
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[error("Couldn't read or write the alarm history: {0}")]
+    AlarmHistory(String),
+
+    #[error("Pipeline is out of step with the device after a transport error")]
+    PipelinePoisoned,
+
     #[error("No response from device")]
     NoResponse,
     #[error("Timeout waiting for response")]
@@ -17,4 +17,31 @@ pub trait CalibrationControl {
     ///
     /// Returns a tuple containing the (rear_arm_angle_error, front_arm_angle_error).
     fn get_angle_sensor_static_error(&mut self) -> Result<(f32, f32), DobotError>;
+
+    /// Sets the gain coefficients of the angle sensors.
+    ///
+    /// `rear_arm_angle_coef`: Coefficient of the rear arm angle sensor.
+    /// `front_arm_angle_coef`: Coefficient of the front arm angle sensor.
+    fn set_angle_sensor_coef(
+        &mut self,
+        rear_arm_angle_coef: f32,
+        front_arm_angle_coef: f32,
+    ) -> Result<(), DobotError>;
+
+    /// Gets the gain coefficients of the angle sensors.
+    ///
+    /// Returns a tuple containing the (rear_arm_angle_coef, front_arm_angle_coef).
+    fn get_angle_sensor_coef(&mut self) -> Result<(f32, f32), DobotError>;
+
+    /// Sets the static error of the base encoder, in degrees.
+    fn set_base_decoder_static_error(&mut self, base_decoder_error: f32) -> Result<(), DobotError>;
+
+    /// Gets the static error of the base encoder, in degrees.
+    fn get_base_decoder_static_error(&mut self) -> Result<f32, DobotError>;
+
+    /// Sets the calibration value used in right-hand mode.
+    fn set_rhand_calibrate_value(&mut self, rhand_calibrate_value: f32) -> Result<(), DobotError>;
+
+    /// Gets the calibration value used in right-hand mode.
+    fn get_rhand_calibrate_value(&mut self) -> Result<f32, DobotError>;
 }
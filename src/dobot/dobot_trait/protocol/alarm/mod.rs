@@ -0,0 +1,771 @@
+mod set;
+mod test;
+
+pub use set::{AlarmDiff, AlarmSet};
+
+use crate::dobot::dobot_trait::protocol::protocol_error::ProtocolError;
+
+/// The group an alarm code belongs to, given by its high nibble.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AlarmCategory {
+    Common,
+    Plan,
+    Move,
+    Overspeed,
+    Limit,
+    LoseStep,
+    /// Driver, motor and end effector faults, and codes outside the known groups.
+    Other,
+}
+
+impl AlarmCategory {
+    pub fn of_code(code: u8) -> Self {
+        match code >> 4 {
+            0x0 => AlarmCategory::Common,
+            0x1 => AlarmCategory::Plan,
+            0x2 => AlarmCategory::Move,
+            0x3 => AlarmCategory::Overspeed,
+            0x4 => AlarmCategory::Limit,
+            0x5 => AlarmCategory::LoseStep,
+            _ => AlarmCategory::Other,
+        }
+    }
+}
+
+/// How serious an alarm is, from least to most.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum AlarmSeverity {
+    /// Reports a state of the arm; nothing needs to be done.
+    Info,
+    /// A command was rejected; the arm holds its position.
+    Warning,
+    /// Motion was stopped; the alarm must be cleared before moving on.
+    Error,
+    /// A hardware fault that needs attention before the arm is used again.
+    Critical,
+}
+
+/// Represents the various alarm codes from the Dobot Magician.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[repr(u8)]
+pub enum Alarm {
+    // Common alarms
+    CommonResetting = 0x00,
+    CommonUndefinedInstruction = 0x01,
+    CommonFileSystem = 0x02,
+    CommonMcuFpgaComm = 0x03,
+    CommonAngleSensor = 0x04,
+
+    // Plan alarms
+    PlanInvSingularity = 0x10,
+    PlanInvCalc = 0x11,
+    PlanInvLimit = 0x12,
+    PlanPushDataRepeat = 0x13,
+    PlanArcInputParam = 0x14,
+    PlanJumpParam = 0x15,
+    PlanLineHand = 0x16,
+    PlanLineOutSpace = 0x17,
+    PlanArcOutSpace = 0x18,
+    PlanMotionType = 0x19,
+    PlanSpeedInputParam = 0x1A,
+    PlanCpCalc = 0x1B,
+
+    // Move alarms
+    MoveInvSingularity = 0x20,
+    MoveInvCalc = 0x21,
+    MoveInvLimit = 0x22,
+
+    // Overspeed alarms
+    OverspeedAxis1 = 0x30,
+    OverspeedAxis2 = 0x31,
+    OverspeedAxis3 = 0x32,
+    OverspeedAxis4 = 0x33,
+
+    // Limit alarms
+    LimitAxis1Pos = 0x40,
+    LimitAxis1Neg = 0x41,
+    LimitAxis2Pos = 0x42,
+    LimitAxis2Neg = 0x43,
+    LimitAxis3Pos = 0x44,
+    LimitAxis3Neg = 0x45,
+    LimitAxis4Pos = 0x46,
+    LimitAxis4Neg = 0x47,
+    LimitAxis23Pos = 0x48,
+    LimitAxis23Neg = 0x49,
+
+    // Lose step alarms
+    LoseStepAxis1 = 0x50,
+    LoseStepAxis2 = 0x51,
+    LoseStepAxis3 = 0x52,
+    LoseStepAxis4 = 0x53,
+
+    // Other alarms
+    OtherAxis1Drv = 0x60,
+    OtherAxis1Overflow = 0x61,
+    OtherAxis1Follow = 0x62,
+    OtherAxis2Drv = 0x63,
+    OtherAxis2Overflow = 0x64,
+    OtherAxis2Follow = 0x65,
+    OtherAxis3Drv = 0x66,
+    OtherAxis3Overflow = 0x67,
+    OtherAxis3Follow = 0x68,
+    OtherAxis4Drv = 0x69,
+    OtherAxis4Overflow = 0x6A,
+    OtherAxis4Follow = 0x6B,
+
+    // Motor rear alarms
+    MotorRearEncoder = 0x70,
+    MotorRearTemperatureHigh = 0x71,
+    MotorRearTemperatureLow = 0x72,
+    MotorRearLockCurrent = 0x73,
+    MotorRearBusvHigh = 0x74,
+    MotorRearBusvLow = 0x75,
+    MotorRearOverheat = 0x76,
+    MotorRearRunaway = 0x77,
+    MotorRearBatteryLow = 0x78,
+    MotorRearPhaseShort = 0x79,
+    MotorRearPhaseWrong = 0x7A,
+    MotorRearLostSpeed = 0x7B,
+    MotorRearNotStandardize = 0x7C,
+    EncoderRearNotStandardize = 0x7D,
+    MotorRearCanBroke = 0x7E,
+
+    // Motor front alarms
+    MotorFrontEncoder = 0x80,
+    MotorFrontTemperatureHigh = 0x81,
+    MotorFrontTemperatureLow = 0x82,
+    MotorFrontLockCurrent = 0x83,
+    MotorFrontBusvHigh = 0x84,
+    MotorFrontBusvLow = 0x85,
+    MotorFrontOverheat = 0x86,
+    MotorFrontRunaway = 0x87,
+    MotorFrontBatteryLow = 0x88,
+    MotorFrontPhaseShort = 0x89,
+    MotorFrontPhaseWrong = 0x8A,
+    MotorFrontLostSpeed = 0x8B,
+    MotorFrontNotStandardize = 0x8C,
+    EncoderFrontNotStandardize = 0x8D,
+    MotorFrontCanBroke = 0x8E,
+
+    // Motor Z alarms
+    MotorZEncoder = 0x90,
+    MotorZTemperatureHigh = 0x91,
+    MotorZTemperatureLow = 0x92,
+    MotorZLockCurrent = 0x93,
+    MotorZBusvHigh = 0x94,
+    MotorZBusvLow = 0x95,
+    MotorZOverheat = 0x96,
+    MotorZRunaway = 0x97,
+    MotorZBatteryLow = 0x98,
+    MotorZPhaseShort = 0x99,
+    MotorZPhaseWrong = 0x9A,
+    MotorZLostSpeed = 0x9B,
+    MotorZNotStandardize = 0x9C,
+    EncoderZNotStandardize = 0x9D,
+    MotorZCanBroke = 0x9E,
+
+    // Motor R alarms
+    MotorREncoder = 0xA0,
+    MotorRThermperatureHigh = 0xA1,
+    MotorRThermperatureLow = 0xA2,
+    MotorRLockCurrent = 0xA3,
+    MotorRBusvHigh = 0xA4,
+    MotorRBusvLow = 0xA5,
+    MotorROverheat = 0xA6,
+    MotorRRunaway = 0xA7,
+    MotorRBatteryLow = 0xA8,
+    MotorRPhaseShort = 0xA9,
+    MotorRPhaseWrong = 0xAA,
+    MotorRLostSpeed = 0xAB,
+    MotorRNotStandardize = 0xAC,
+    EncoderRNotStandardize = 0xAD,
+    MotorRCanBroke = 0xAE,
+
+    // Motor ENDIO alarms
+    MotorEndioIo = 0xB0,
+    MotorEndioRs485Wrong = 0xB1,
+    MotorEndioCanBroke = 0xB2,
+}
+
+impl Alarm {
+    pub fn try_from_u8(value: u8) -> Result<Self, ProtocolError> {
+        match value {
+            0x00 => Ok(Alarm::CommonResetting),
+            0x01 => Ok(Alarm::CommonUndefinedInstruction),
+            0x02 => Ok(Alarm::CommonFileSystem),
+            0x03 => Ok(Alarm::CommonMcuFpgaComm),
+            0x04 => Ok(Alarm::CommonAngleSensor),
+            0x10 => Ok(Alarm::PlanInvSingularity),
+            0x11 => Ok(Alarm::PlanInvCalc),
+            0x12 => Ok(Alarm::PlanInvLimit),
+            0x13 => Ok(Alarm::PlanPushDataRepeat),
+            0x14 => Ok(Alarm::PlanArcInputParam),
+            0x15 => Ok(Alarm::PlanJumpParam),
+            0x16 => Ok(Alarm::PlanLineHand),
+            0x17 => Ok(Alarm::PlanLineOutSpace),
+            0x18 => Ok(Alarm::PlanArcOutSpace),
+            0x19 => Ok(Alarm::PlanMotionType),
+            0x1A => Ok(Alarm::PlanSpeedInputParam),
+            0x1B => Ok(Alarm::PlanCpCalc),
+            0x20 => Ok(Alarm::MoveInvSingularity),
+            0x21 => Ok(Alarm::MoveInvCalc),
+            0x22 => Ok(Alarm::MoveInvLimit),
+            0x30 => Ok(Alarm::OverspeedAxis1),
+            0x31 => Ok(Alarm::OverspeedAxis2),
+            0x32 => Ok(Alarm::OverspeedAxis3),
+            0x33 => Ok(Alarm::OverspeedAxis4),
+            0x40 => Ok(Alarm::LimitAxis1Pos),
+            0x41 => Ok(Alarm::LimitAxis1Neg),
+            0x42 => Ok(Alarm::LimitAxis2Pos),
+            0x43 => Ok(Alarm::LimitAxis2Neg),
+            0x44 => Ok(Alarm::LimitAxis3Pos),
+            0x45 => Ok(Alarm::LimitAxis3Neg),
+            0x46 => Ok(Alarm::LimitAxis4Pos),
+            0x47 => Ok(Alarm::LimitAxis4Neg),
+            0x48 => Ok(Alarm::LimitAxis23Pos),
+            0x49 => Ok(Alarm::LimitAxis23Neg),
+            0x50 => Ok(Alarm::LoseStepAxis1),
+            0x51 => Ok(Alarm::LoseStepAxis2),
+            0x52 => Ok(Alarm::LoseStepAxis3),
+            0x53 => Ok(Alarm::LoseStepAxis4),
+            0x60 => Ok(Alarm::OtherAxis1Drv),
+            0x61 => Ok(Alarm::OtherAxis1Overflow),
+            0x62 => Ok(Alarm::OtherAxis1Follow),
+            0x63 => Ok(Alarm::OtherAxis2Drv),
+            0x64 => Ok(Alarm::OtherAxis2Overflow),
+            0x65 => Ok(Alarm::OtherAxis2Follow),
+            0x66 => Ok(Alarm::OtherAxis3Drv),
+            0x67 => Ok(Alarm::OtherAxis3Overflow),
+            0x68 => Ok(Alarm::OtherAxis3Follow),
+            0x69 => Ok(Alarm::OtherAxis4Drv),
+            0x6A => Ok(Alarm::OtherAxis4Overflow),
+            0x6B => Ok(Alarm::OtherAxis4Follow),
+            0x70 => Ok(Alarm::MotorRearEncoder),
+            0x71 => Ok(Alarm::MotorRearTemperatureHigh),
+            0x72 => Ok(Alarm::MotorRearTemperatureLow),
+            0x73 => Ok(Alarm::MotorRearLockCurrent),
+            0x74 => Ok(Alarm::MotorRearBusvHigh),
+            0x75 => Ok(Alarm::MotorRearBusvLow),
+            0x76 => Ok(Alarm::MotorRearOverheat),
+            0x77 => Ok(Alarm::MotorRearRunaway),
+            0x78 => Ok(Alarm::MotorRearBatteryLow),
+            0x79 => Ok(Alarm::MotorRearPhaseShort),
+            0x7A => Ok(Alarm::MotorRearPhaseWrong),
+            0x7B => Ok(Alarm::MotorRearLostSpeed),
+            0x7C => Ok(Alarm::MotorRearNotStandardize),
+            0x7D => Ok(Alarm::EncoderRearNotStandardize),
+            0x7E => Ok(Alarm::MotorRearCanBroke),
+            0x80 => Ok(Alarm::MotorFrontEncoder),
+            0x81 => Ok(Alarm::MotorFrontTemperatureHigh),
+            0x82 => Ok(Alarm::MotorFrontTemperatureLow),
+            0x83 => Ok(Alarm::MotorFrontLockCurrent),
+            0x84 => Ok(Alarm::MotorFrontBusvHigh),
+            0x85 => Ok(Alarm::MotorFrontBusvLow),
+            0x86 => Ok(Alarm::MotorFrontOverheat),
+            0x87 => Ok(Alarm::MotorFrontRunaway),
+            0x88 => Ok(Alarm::MotorFrontBatteryLow),
+            0x89 => Ok(Alarm::MotorFrontPhaseShort),
+            0x8A => Ok(Alarm::MotorFrontPhaseWrong),
+            0x8B => Ok(Alarm::MotorFrontLostSpeed),
+            0x8C => Ok(Alarm::MotorFrontNotStandardize),
+            0x8D => Ok(Alarm::EncoderFrontNotStandardize),
+            0x8E => Ok(Alarm::MotorFrontCanBroke),
+            0x90 => Ok(Alarm::MotorZEncoder),
+            0x91 => Ok(Alarm::MotorZTemperatureHigh),
+            0x92 => Ok(Alarm::MotorZTemperatureLow),
+            0x93 => Ok(Alarm::MotorZLockCurrent),
+            0x94 => Ok(Alarm::MotorZBusvHigh),
+            0x95 => Ok(Alarm::MotorZBusvLow),
+            0x96 => Ok(Alarm::MotorZOverheat),
+            0x97 => Ok(Alarm::MotorZRunaway),
+            0x98 => Ok(Alarm::MotorZBatteryLow),
+            0x99 => Ok(Alarm::MotorZPhaseShort),
+            0x9A => Ok(Alarm::MotorZPhaseWrong),
+            0x9B => Ok(Alarm::MotorZLostSpeed),
+            0x9C => Ok(Alarm::MotorZNotStandardize),
+            0x9D => Ok(Alarm::EncoderZNotStandardize),
+            0x9E => Ok(Alarm::MotorZCanBroke),
+            0xA0 => Ok(Alarm::MotorREncoder),
+            0xA1 => Ok(Alarm::MotorRThermperatureHigh),
+            0xA2 => Ok(Alarm::MotorRThermperatureLow),
+            0xA3 => Ok(Alarm::MotorRLockCurrent),
+            0xA4 => Ok(Alarm::MotorRBusvHigh),
+            0xA5 => Ok(Alarm::MotorRBusvLow),
+            0xA6 => Ok(Alarm::MotorROverheat),
+            0xA7 => Ok(Alarm::MotorRRunaway),
+            0xA8 => Ok(Alarm::MotorRBatteryLow),
+            0xA9 => Ok(Alarm::MotorRPhaseShort),
+            0xAA => Ok(Alarm::MotorRPhaseWrong),
+            0xAB => Ok(Alarm::MotorRLostSpeed),
+            0xAC => Ok(Alarm::MotorRNotStandardize),
+            0xAD => Ok(Alarm::EncoderRNotStandardize),
+            0xAE => Ok(Alarm::MotorRCanBroke),
+            0xB0 => Ok(Alarm::MotorEndioIo),
+            0xB1 => Ok(Alarm::MotorEndioRs485Wrong),
+            0xB2 => Ok(Alarm::MotorEndioCanBroke),
+            code => Err(ProtocolError::InvalidAlarmCode(code)),
+        }
+    }
+
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+
+    pub fn category(self) -> AlarmCategory {
+        AlarmCategory::of_code(self.code())
+    }
+
+    /// One-line description of the alarm, for display.
+    pub fn description(self) -> &'static str {
+        match self {
+            Alarm::CommonResetting => "The arm is resetting",
+            Alarm::CommonUndefinedInstruction => "Undefined instruction received",
+            Alarm::CommonFileSystem => "File system error",
+            Alarm::CommonMcuFpgaComm => "Communication between MCU and FPGA failed",
+            Alarm::CommonAngleSensor => "Angle sensor reading error",
+            Alarm::PlanInvSingularity => "Target point is a singularity",
+            Alarm::PlanInvCalc => "Inverse kinematics failed for the target point",
+            Alarm::PlanInvLimit => "Target point is out of the joint limits",
+            Alarm::PlanPushDataRepeat => "Repeated point in the motion data",
+            Alarm::PlanArcInputParam => "Invalid arc parameters",
+            Alarm::PlanJumpParam => "Invalid JUMP parameters",
+            Alarm::PlanLineHand => "Line motion would change the arm orientation",
+            Alarm::PlanLineOutSpace => "Line passes outside the workspace",
+            Alarm::PlanArcOutSpace => "Arc passes outside the workspace",
+            Alarm::PlanMotionType => "Invalid motion type",
+            Alarm::PlanSpeedInputParam => "Invalid speed parameters",
+            Alarm::PlanCpCalc => "CP path planning failed",
+            Alarm::MoveInvSingularity => "Motion passed through a singularity",
+            Alarm::MoveInvCalc => "Inverse kinematics failed during motion",
+            Alarm::MoveInvLimit => "Motion ran into the joint limits",
+            Alarm::OverspeedAxis1 => "Joint 1 overspeed",
+            Alarm::OverspeedAxis2 => "Joint 2 overspeed",
+            Alarm::OverspeedAxis3 => "Joint 3 overspeed",
+            Alarm::OverspeedAxis4 => "Joint 4 overspeed",
+            Alarm::LimitAxis1Pos => "Joint 1 reached its positive limit",
+            Alarm::LimitAxis1Neg => "Joint 1 reached its negative limit",
+            Alarm::LimitAxis2Pos => "Joint 2 reached its positive limit",
+            Alarm::LimitAxis2Neg => "Joint 2 reached its negative limit",
+            Alarm::LimitAxis3Pos => "Joint 3 reached its positive limit",
+            Alarm::LimitAxis3Neg => "Joint 3 reached its negative limit",
+            Alarm::LimitAxis4Pos => "Joint 4 reached its positive limit",
+            Alarm::LimitAxis4Neg => "Joint 4 reached its negative limit",
+            Alarm::LimitAxis23Pos => "Joints 2 and 3 reached their positive combined limit",
+            Alarm::LimitAxis23Neg => "Joints 2 and 3 reached their negative combined limit",
+            Alarm::LoseStepAxis1 => "Joint 1 lost steps",
+            Alarm::LoseStepAxis2 => "Joint 2 lost steps",
+            Alarm::LoseStepAxis3 => "Joint 3 lost steps",
+            Alarm::LoseStepAxis4 => "Joint 4 lost steps",
+            Alarm::OtherAxis1Drv => "Joint 1 driver fault",
+            Alarm::OtherAxis1Overflow => "Joint 1 driver overflow",
+            Alarm::OtherAxis1Follow => "Joint 1 following error too large",
+            Alarm::OtherAxis2Drv => "Joint 2 driver fault",
+            Alarm::OtherAxis2Overflow => "Joint 2 driver overflow",
+            Alarm::OtherAxis2Follow => "Joint 2 following error too large",
+            Alarm::OtherAxis3Drv => "Joint 3 driver fault",
+            Alarm::OtherAxis3Overflow => "Joint 3 driver overflow",
+            Alarm::OtherAxis3Follow => "Joint 3 following error too large",
+            Alarm::OtherAxis4Drv => "Joint 4 driver fault",
+            Alarm::OtherAxis4Overflow => "Joint 4 driver overflow",
+            Alarm::OtherAxis4Follow => "Joint 4 following error too large",
+            Alarm::MotorRearEncoder => "Rear arm motor: encoder error",
+            Alarm::MotorRearTemperatureHigh => "Rear arm motor: temperature too high",
+            Alarm::MotorRearTemperatureLow => "Rear arm motor: temperature too low",
+            Alarm::MotorRearLockCurrent => "Rear arm motor: locked-rotor current",
+            Alarm::MotorRearBusvHigh => "Rear arm motor: bus voltage too high",
+            Alarm::MotorRearBusvLow => "Rear arm motor: bus voltage too low",
+            Alarm::MotorRearOverheat => "Rear arm motor: overheated",
+            Alarm::MotorRearRunaway => "Rear arm motor: ran away from its commanded position",
+            Alarm::MotorRearBatteryLow => "Rear arm motor: encoder battery low",
+            Alarm::MotorRearPhaseShort => "Rear arm motor: phase short circuit",
+            Alarm::MotorRearPhaseWrong => "Rear arm motor: phase wiring error",
+            Alarm::MotorRearLostSpeed => "Rear arm motor: lost speed",
+            Alarm::MotorRearNotStandardize => "Rear arm motor: not calibrated",
+            Alarm::EncoderRearNotStandardize => "Rear arm motor: encoder not calibrated",
+            Alarm::MotorRearCanBroke => "Rear arm motor: CAN bus connection lost",
+            Alarm::MotorFrontEncoder => "Front arm motor: encoder error",
+            Alarm::MotorFrontTemperatureHigh => "Front arm motor: temperature too high",
+            Alarm::MotorFrontTemperatureLow => "Front arm motor: temperature too low",
+            Alarm::MotorFrontLockCurrent => "Front arm motor: locked-rotor current",
+            Alarm::MotorFrontBusvHigh => "Front arm motor: bus voltage too high",
+            Alarm::MotorFrontBusvLow => "Front arm motor: bus voltage too low",
+            Alarm::MotorFrontOverheat => "Front arm motor: overheated",
+            Alarm::MotorFrontRunaway => "Front arm motor: ran away from its commanded position",
+            Alarm::MotorFrontBatteryLow => "Front arm motor: encoder battery low",
+            Alarm::MotorFrontPhaseShort => "Front arm motor: phase short circuit",
+            Alarm::MotorFrontPhaseWrong => "Front arm motor: phase wiring error",
+            Alarm::MotorFrontLostSpeed => "Front arm motor: lost speed",
+            Alarm::MotorFrontNotStandardize => "Front arm motor: not calibrated",
+            Alarm::EncoderFrontNotStandardize => "Front arm motor: encoder not calibrated",
+            Alarm::MotorFrontCanBroke => "Front arm motor: CAN bus connection lost",
+            Alarm::MotorZEncoder => "Z axis motor: encoder error",
+            Alarm::MotorZTemperatureHigh => "Z axis motor: temperature too high",
+            Alarm::MotorZTemperatureLow => "Z axis motor: temperature too low",
+            Alarm::MotorZLockCurrent => "Z axis motor: locked-rotor current",
+            Alarm::MotorZBusvHigh => "Z axis motor: bus voltage too high",
+            Alarm::MotorZBusvLow => "Z axis motor: bus voltage too low",
+            Alarm::MotorZOverheat => "Z axis motor: overheated",
+            Alarm::MotorZRunaway => "Z axis motor: ran away from its commanded position",
+            Alarm::MotorZBatteryLow => "Z axis motor: encoder battery low",
+            Alarm::MotorZPhaseShort => "Z axis motor: phase short circuit",
+            Alarm::MotorZPhaseWrong => "Z axis motor: phase wiring error",
+            Alarm::MotorZLostSpeed => "Z axis motor: lost speed",
+            Alarm::MotorZNotStandardize => "Z axis motor: not calibrated",
+            Alarm::EncoderZNotStandardize => "Z axis motor: encoder not calibrated",
+            Alarm::MotorZCanBroke => "Z axis motor: CAN bus connection lost",
+            Alarm::MotorREncoder => "R axis motor: encoder error",
+            Alarm::MotorRThermperatureHigh => "R axis motor: temperature too high",
+            Alarm::MotorRThermperatureLow => "R axis motor: temperature too low",
+            Alarm::MotorRLockCurrent => "R axis motor: locked-rotor current",
+            Alarm::MotorRBusvHigh => "R axis motor: bus voltage too high",
+            Alarm::MotorRBusvLow => "R axis motor: bus voltage too low",
+            Alarm::MotorROverheat => "R axis motor: overheated",
+            Alarm::MotorRRunaway => "R axis motor: ran away from its commanded position",
+            Alarm::MotorRBatteryLow => "R axis motor: encoder battery low",
+            Alarm::MotorRPhaseShort => "R axis motor: phase short circuit",
+            Alarm::MotorRPhaseWrong => "R axis motor: phase wiring error",
+            Alarm::MotorRLostSpeed => "R axis motor: lost speed",
+            Alarm::MotorRNotStandardize => "R axis motor: not calibrated",
+            Alarm::EncoderRNotStandardize => "R axis motor: encoder not calibrated",
+            Alarm::MotorRCanBroke => "R axis motor: CAN bus connection lost",
+            Alarm::MotorEndioIo => "End effector IO fault",
+            Alarm::MotorEndioRs485Wrong => "End effector RS485 communication error",
+            Alarm::MotorEndioCanBroke => "End effector CAN bus connection lost",
+        }
+    }
+
+    /// How serious the alarm is.
+    pub fn severity(self) -> AlarmSeverity {
+        match self {
+            Alarm::CommonResetting => AlarmSeverity::Info,
+            Alarm::CommonUndefinedInstruction
+            | Alarm::PlanInvSingularity
+            | Alarm::PlanInvCalc
+            | Alarm::PlanInvLimit
+            | Alarm::PlanPushDataRepeat
+            | Alarm::PlanArcInputParam
+            | Alarm::PlanJumpParam
+            | Alarm::PlanLineHand
+            | Alarm::PlanLineOutSpace
+            | Alarm::PlanArcOutSpace
+            | Alarm::PlanMotionType
+            | Alarm::PlanSpeedInputParam
+            | Alarm::PlanCpCalc
+            | Alarm::MotorRearTemperatureLow
+            | Alarm::MotorRearBatteryLow
+            | Alarm::MotorFrontTemperatureLow
+            | Alarm::MotorFrontBatteryLow
+            | Alarm::MotorZTemperatureLow
+            | Alarm::MotorZBatteryLow
+            | Alarm::MotorRThermperatureLow
+            | Alarm::MotorRBatteryLow => AlarmSeverity::Warning,
+            Alarm::CommonFileSystem
+            | Alarm::CommonMcuFpgaComm
+            | Alarm::OtherAxis1Drv
+            | Alarm::OtherAxis1Overflow
+            | Alarm::OtherAxis2Drv
+            | Alarm::OtherAxis2Overflow
+            | Alarm::OtherAxis3Drv
+            | Alarm::OtherAxis3Overflow
+            | Alarm::OtherAxis4Drv
+            | Alarm::OtherAxis4Overflow
+            | Alarm::MotorRearEncoder
+            | Alarm::MotorRearBusvHigh
+            | Alarm::MotorRearBusvLow
+            | Alarm::MotorRearOverheat
+            | Alarm::MotorRearRunaway
+            | Alarm::MotorRearPhaseShort
+            | Alarm::MotorRearPhaseWrong
+            | Alarm::MotorRearCanBroke
+            | Alarm::MotorFrontEncoder
+            | Alarm::MotorFrontBusvHigh
+            | Alarm::MotorFrontBusvLow
+            | Alarm::MotorFrontOverheat
+            | Alarm::MotorFrontRunaway
+            | Alarm::MotorFrontPhaseShort
+            | Alarm::MotorFrontPhaseWrong
+            | Alarm::MotorFrontCanBroke
+            | Alarm::MotorZEncoder
+            | Alarm::MotorZBusvHigh
+            | Alarm::MotorZBusvLow
+            | Alarm::MotorZOverheat
+            | Alarm::MotorZRunaway
+            | Alarm::MotorZPhaseShort
+            | Alarm::MotorZPhaseWrong
+            | Alarm::MotorZCanBroke
+            | Alarm::MotorREncoder
+            | Alarm::MotorRBusvHigh
+            | Alarm::MotorRBusvLow
+            | Alarm::MotorROverheat
+            | Alarm::MotorRRunaway
+            | Alarm::MotorRPhaseShort
+            | Alarm::MotorRPhaseWrong
+            | Alarm::MotorRCanBroke => AlarmSeverity::Critical,
+            Alarm::CommonAngleSensor
+            | Alarm::MoveInvSingularity
+            | Alarm::MoveInvCalc
+            | Alarm::MoveInvLimit
+            | Alarm::OverspeedAxis1
+            | Alarm::OverspeedAxis2
+            | Alarm::OverspeedAxis3
+            | Alarm::OverspeedAxis4
+            | Alarm::LimitAxis1Pos
+            | Alarm::LimitAxis1Neg
+            | Alarm::LimitAxis2Pos
+            | Alarm::LimitAxis2Neg
+            | Alarm::LimitAxis3Pos
+            | Alarm::LimitAxis3Neg
+            | Alarm::LimitAxis4Pos
+            | Alarm::LimitAxis4Neg
+            | Alarm::LimitAxis23Pos
+            | Alarm::LimitAxis23Neg
+            | Alarm::LoseStepAxis1
+            | Alarm::LoseStepAxis2
+            | Alarm::LoseStepAxis3
+            | Alarm::LoseStepAxis4
+            | Alarm::OtherAxis1Follow
+            | Alarm::OtherAxis2Follow
+            | Alarm::OtherAxis3Follow
+            | Alarm::OtherAxis4Follow
+            | Alarm::MotorRearTemperatureHigh
+            | Alarm::MotorRearLockCurrent
+            | Alarm::MotorRearLostSpeed
+            | Alarm::MotorRearNotStandardize
+            | Alarm::EncoderRearNotStandardize
+            | Alarm::MotorFrontTemperatureHigh
+            | Alarm::MotorFrontLockCurrent
+            | Alarm::MotorFrontLostSpeed
+            | Alarm::MotorFrontNotStandardize
+            | Alarm::EncoderFrontNotStandardize
+            | Alarm::MotorZTemperatureHigh
+            | Alarm::MotorZLockCurrent
+            | Alarm::MotorZLostSpeed
+            | Alarm::MotorZNotStandardize
+            | Alarm::EncoderZNotStandardize
+            | Alarm::MotorRThermperatureHigh
+            | Alarm::MotorRLockCurrent
+            | Alarm::MotorRLostSpeed
+            | Alarm::MotorRNotStandardize
+            | Alarm::EncoderRNotStandardize
+            | Alarm::MotorEndioIo
+            | Alarm::MotorEndioRs485Wrong
+            | Alarm::MotorEndioCanBroke => AlarmSeverity::Error,
+        }
+    }
+
+    /// What an operator should do to recover from the alarm.
+    pub fn recovery(self) -> &'static str {
+        match self {
+            Alarm::CommonResetting => "Wait for the reset to finish.",
+            Alarm::CommonUndefinedInstruction => {
+                "Check that the command is supported by the firmware, then clear the alarm."
+            }
+            Alarm::CommonFileSystem => {
+                "Power-cycle the arm; if the alarm returns, reflash the firmware."
+            }
+            Alarm::CommonMcuFpgaComm => {
+                "Power-cycle the arm; if the alarm returns, contact service."
+            }
+            Alarm::CommonAngleSensor => {
+                "Check the angle sensor cables, then recalibrate the angle sensors."
+            }
+            Alarm::PlanInvSingularity
+            | Alarm::PlanInvCalc
+            | Alarm::PlanInvLimit
+            | Alarm::PlanPushDataRepeat
+            | Alarm::PlanArcInputParam
+            | Alarm::PlanJumpParam
+            | Alarm::PlanLineHand
+            | Alarm::PlanLineOutSpace
+            | Alarm::PlanArcOutSpace
+            | Alarm::PlanMotionType
+            | Alarm::PlanSpeedInputParam
+            | Alarm::PlanCpCalc => {
+                "Check the target point and motion parameters, clear the alarm and send a reachable command."
+            }
+            Alarm::MoveInvSingularity | Alarm::MoveInvCalc | Alarm::MoveInvLimit => {
+                "Clear the alarm, jog the arm away from the pose and plan the motion again."
+            }
+            Alarm::OverspeedAxis1
+            | Alarm::OverspeedAxis2
+            | Alarm::OverspeedAxis3
+            | Alarm::OverspeedAxis4 => {
+                "Lower the velocity and acceleration, clear the alarm and retry."
+            }
+            Alarm::LimitAxis1Pos
+            | Alarm::LimitAxis1Neg
+            | Alarm::LimitAxis2Pos
+            | Alarm::LimitAxis2Neg
+            | Alarm::LimitAxis3Pos
+            | Alarm::LimitAxis3Neg
+            | Alarm::LimitAxis4Pos
+            | Alarm::LimitAxis4Neg
+            | Alarm::LimitAxis23Pos
+            | Alarm::LimitAxis23Neg => "Clear the alarm and jog the axis back inside its range.",
+            Alarm::LoseStepAxis1
+            | Alarm::LoseStepAxis2
+            | Alarm::LoseStepAxis3
+            | Alarm::LoseStepAxis4 => {
+                "Remove any obstruction or reduce the payload, clear the alarm and home the arm."
+            }
+            Alarm::OtherAxis1Drv
+            | Alarm::OtherAxis1Overflow
+            | Alarm::OtherAxis2Drv
+            | Alarm::OtherAxis2Overflow
+            | Alarm::OtherAxis3Drv
+            | Alarm::OtherAxis3Overflow
+            | Alarm::OtherAxis4Drv
+            | Alarm::OtherAxis4Overflow => {
+                "Power-cycle the arm; if the alarm returns, the axis driver needs service."
+            }
+            Alarm::OtherAxis1Follow
+            | Alarm::OtherAxis2Follow
+            | Alarm::OtherAxis3Follow
+            | Alarm::OtherAxis4Follow => {
+                "Lower the velocity or payload, clear the alarm and home the arm."
+            }
+            Alarm::MotorRearEncoder
+            | Alarm::MotorRearPhaseShort
+            | Alarm::MotorRearPhaseWrong
+            | Alarm::MotorRearCanBroke
+            | Alarm::MotorFrontEncoder
+            | Alarm::MotorFrontPhaseShort
+            | Alarm::MotorFrontPhaseWrong
+            | Alarm::MotorFrontCanBroke
+            | Alarm::MotorZEncoder
+            | Alarm::MotorZPhaseShort
+            | Alarm::MotorZPhaseWrong
+            | Alarm::MotorZCanBroke
+            | Alarm::MotorREncoder
+            | Alarm::MotorRPhaseShort
+            | Alarm::MotorRPhaseWrong
+            | Alarm::MotorRCanBroke => {
+                "Power off and check the motor wiring; contact service if the alarm returns."
+            }
+            Alarm::MotorRearTemperatureHigh
+            | Alarm::MotorRearOverheat
+            | Alarm::MotorFrontTemperatureHigh
+            | Alarm::MotorFrontOverheat
+            | Alarm::MotorZTemperatureHigh
+            | Alarm::MotorZOverheat
+            | Alarm::MotorRThermperatureHigh
+            | Alarm::MotorROverheat => "Stop the arm and let the motor cool down before resuming.",
+            Alarm::MotorRearTemperatureLow
+            | Alarm::MotorFrontTemperatureLow
+            | Alarm::MotorZTemperatureLow
+            | Alarm::MotorRThermperatureLow => {
+                "Let the arm warm up to its operating temperature before resuming."
+            }
+            Alarm::MotorRearLockCurrent
+            | Alarm::MotorRearRunaway
+            | Alarm::MotorRearLostSpeed
+            | Alarm::MotorFrontLockCurrent
+            | Alarm::MotorFrontRunaway
+            | Alarm::MotorFrontLostSpeed
+            | Alarm::MotorZLockCurrent
+            | Alarm::MotorZRunaway
+            | Alarm::MotorZLostSpeed
+            | Alarm::MotorRLockCurrent
+            | Alarm::MotorRRunaway
+            | Alarm::MotorRLostSpeed => "Remove any obstruction, clear the alarm and home the arm.",
+            Alarm::MotorRearBusvHigh
+            | Alarm::MotorRearBusvLow
+            | Alarm::MotorFrontBusvHigh
+            | Alarm::MotorFrontBusvLow
+            | Alarm::MotorZBusvHigh
+            | Alarm::MotorZBusvLow
+            | Alarm::MotorRBusvHigh
+            | Alarm::MotorRBusvLow => "Check the power supply, then power-cycle the arm.",
+            Alarm::MotorRearBatteryLow
+            | Alarm::MotorFrontBatteryLow
+            | Alarm::MotorZBatteryLow
+            | Alarm::MotorRBatteryLow => "Replace the encoder battery.",
+            Alarm::MotorRearNotStandardize
+            | Alarm::EncoderRearNotStandardize
+            | Alarm::MotorFrontNotStandardize
+            | Alarm::EncoderFrontNotStandardize
+            | Alarm::MotorZNotStandardize
+            | Alarm::EncoderZNotStandardize
+            | Alarm::MotorRNotStandardize
+            | Alarm::EncoderRNotStandardize => "Calibrate the motor and its encoder.",
+            Alarm::MotorEndioIo | Alarm::MotorEndioRs485Wrong | Alarm::MotorEndioCanBroke => {
+                "Check the end effector cable and connection, then clear the alarm."
+            }
+        }
+    }
+}
+
+/// An alarm bit reported by the arm.
+///
+/// Firmware versions add alarm codes, so a code this crate does not know is
+/// kept as [`ActiveAlarm::Unknown`] instead of being dropped.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ActiveAlarm {
+    Known(Alarm),
+    Unknown(u8),
+}
+
+impl ActiveAlarm {
+    pub fn from_code(code: u8) -> Self {
+        match Alarm::try_from_u8(code) {
+            Ok(alarm) => ActiveAlarm::Known(alarm),
+            Err(_) => ActiveAlarm::Unknown(code),
+        }
+    }
+
+    pub fn code(self) -> u8 {
+        match self {
+            ActiveAlarm::Known(alarm) => alarm.code(),
+            ActiveAlarm::Unknown(code) => code,
+        }
+    }
+
+    pub fn alarm(self) -> Option<Alarm> {
+        match self {
+            ActiveAlarm::Known(alarm) => Some(alarm),
+            ActiveAlarm::Unknown(_) => None,
+        }
+    }
+
+    pub fn category(self) -> AlarmCategory {
+        AlarmCategory::of_code(self.code())
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            ActiveAlarm::Known(alarm) => alarm.description(),
+            ActiveAlarm::Unknown(_) => "Alarm code unknown to this library",
+        }
+    }
+
+    /// Unknown codes are treated as errors.
+    pub fn severity(self) -> AlarmSeverity {
+        match self {
+            ActiveAlarm::Known(alarm) => alarm.severity(),
+            ActiveAlarm::Unknown(_) => AlarmSeverity::Error,
+        }
+    }
+
+    pub fn recovery(self) -> &'static str {
+        match self {
+            ActiveAlarm::Known(alarm) => alarm.recovery(),
+            ActiveAlarm::Unknown(_) => {
+                "Look the code up in the documentation of the firmware, then clear the alarm."
+            }
+        }
+    }
+}
+
+impl From<Alarm> for ActiveAlarm {
+    fn from(alarm: Alarm) -> Self {
+        ActiveAlarm::Known(alarm)
+    }
+}
@@ -0,0 +1,131 @@
+use super::{ActiveAlarm, Alarm};
+
+/// Number of bytes needed for one bit per alarm code.
+const ALARM_SET_BYTES: usize = 32;
+
+/// The alarm state of an arm, one bit per alarm code.
+///
+/// Every code the arm reports is kept, including codes this crate has no
+/// [`Alarm`] for. Iteration runs in ascending code order.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlarmSet {
+    bits: [u8; ALARM_SET_BYTES],
+}
+
+/// The alarms that changed between two [`AlarmSet`] snapshots.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlarmDiff {
+    /// Active in the newer snapshot only.
+    pub raised: AlarmSet,
+    /// Active in the older snapshot only.
+    pub cleared: AlarmSet,
+}
+
+impl AlarmDiff {
+    pub fn is_empty(&self) -> bool {
+        self.raised.is_empty() && self.cleared.is_empty()
+    }
+}
+
+impl AlarmSet {
+    pub const fn new() -> Self {
+        Self {
+            bits: [0; ALARM_SET_BYTES],
+        }
+    }
+
+    /// Reads the alarm state bytes returned by the arm. Bit `n % 8` of byte
+    /// `n / 8` is set while alarm code `n` is active; bytes beyond the 32
+    /// needed for every code are ignored.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut set = Self::new();
+        for (bits, byte) in set.bits.iter_mut().zip(bytes) {
+            *bits = *byte;
+        }
+        set
+    }
+
+    pub fn as_bytes(&self) -> &[u8; ALARM_SET_BYTES] {
+        &self.bits
+    }
+
+    pub fn contains_code(&self, code: u8) -> bool {
+        self.bits[code as usize / 8] & (1 << (code % 8)) != 0
+    }
+
+    pub fn contains(&self, alarm: Alarm) -> bool {
+        self.contains_code(alarm.code())
+    }
+
+    pub fn insert_code(&mut self, code: u8) {
+        self.bits[code as usize / 8] |= 1 << (code % 8);
+    }
+
+    pub fn insert(&mut self, alarm: Alarm) {
+        self.insert_code(alarm.code());
+    }
+
+    pub fn remove_code(&mut self, code: u8) {
+        self.bits[code as usize / 8] &= !(1 << (code % 8));
+    }
+
+    pub fn remove(&mut self, alarm: Alarm) {
+        self.remove_code(alarm.code());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|byte| *byte == 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns the active alarm codes.
+    pub fn codes(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..=u8::MAX).filter(|code| self.contains_code(*code))
+    }
+
+    /// Returns every active alarm, known or not.
+    pub fn iter(&self) -> impl Iterator<Item = ActiveAlarm> + '_ {
+        self.codes().map(ActiveAlarm::from_code)
+    }
+
+    /// Returns the active alarms this crate knows, skipping unknown codes.
+    pub fn alarms(&self) -> impl Iterator<Item = Alarm> + '_ {
+        self.iter().filter_map(ActiveAlarm::alarm)
+    }
+
+    /// Returns the active codes this crate has no [`Alarm`] for.
+    pub fn unknown_codes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.iter().filter_map(|alarm| match alarm {
+            ActiveAlarm::Unknown(code) => Some(code),
+            ActiveAlarm::Known(_) => None,
+        })
+    }
+
+    /// Compares this snapshot with a `newer` one.
+    pub fn diff(&self, newer: &AlarmSet) -> AlarmDiff {
+        let mut diff = AlarmDiff::default();
+        for (index, (old, new)) in self.bits.iter().zip(newer.bits.iter()).enumerate() {
+            diff.raised.bits[index] = new & !old;
+            diff.cleared.bits[index] = old & !new;
+        }
+        diff
+    }
+}
+
+impl FromIterator<Alarm> for AlarmSet {
+    fn from_iter<I: IntoIterator<Item = Alarm>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for alarm in iter {
+            set.insert(alarm);
+        }
+        set
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::dobot_trait::protocol::alarm::{
+        ActiveAlarm, Alarm, AlarmCategory, AlarmSet, AlarmSeverity,
+    };
+
+    fn known_alarms() -> impl Iterator<Item = Alarm> {
+        (0..=u8::MAX).filter_map(|code| Alarm::try_from_u8(code).ok())
+    }
+
+    #[test]
+    fn test_every_alarm_is_described() {
+        assert_eq!(known_alarms().count(), 113);
+        for alarm in known_alarms() {
+            assert_eq!(Alarm::try_from_u8(alarm.code()), Ok(alarm));
+            assert!(!alarm.description().is_empty(), "{:?}", alarm);
+            assert!(alarm.recovery().ends_with('.'), "{:?}", alarm);
+        }
+    }
+
+    #[test]
+    fn test_categories_follow_the_code_groups() {
+        assert_eq!(Alarm::CommonAngleSensor.category(), AlarmCategory::Common);
+        assert_eq!(Alarm::PlanCpCalc.category(), AlarmCategory::Plan);
+        assert_eq!(Alarm::MoveInvLimit.category(), AlarmCategory::Move);
+        assert_eq!(Alarm::OverspeedAxis4.category(), AlarmCategory::Overspeed);
+        assert_eq!(Alarm::LimitAxis23Neg.category(), AlarmCategory::Limit);
+        assert_eq!(Alarm::LoseStepAxis2.category(), AlarmCategory::LoseStep);
+        assert_eq!(Alarm::OtherAxis1Drv.category(), AlarmCategory::Other);
+        assert_eq!(Alarm::MotorEndioCanBroke.category(), AlarmCategory::Other);
+        assert_eq!(ActiveAlarm::Unknown(0x1F).category(), AlarmCategory::Plan);
+    }
+
+    #[test]
+    fn test_severity_and_recovery() {
+        assert_eq!(Alarm::CommonResetting.severity(), AlarmSeverity::Info);
+        assert_eq!(Alarm::PlanInvLimit.severity(), AlarmSeverity::Warning);
+        assert_eq!(Alarm::LimitAxis2Pos.severity(), AlarmSeverity::Error);
+        assert_eq!(Alarm::MotorZPhaseShort.severity(), AlarmSeverity::Critical);
+        assert!(AlarmSeverity::Warning < AlarmSeverity::Critical);
+        assert_eq!(
+            Alarm::LimitAxis2Pos.description(),
+            "Joint 2 reached its positive limit"
+        );
+        assert_eq!(
+            Alarm::MotorRBatteryLow.recovery(),
+            "Replace the encoder battery."
+        );
+
+        let unknown = ActiveAlarm::from_code(0xF0);
+        assert_eq!(unknown, ActiveAlarm::Unknown(0xF0));
+        assert_eq!(unknown.alarm(), None);
+        assert_eq!(unknown.severity(), AlarmSeverity::Error);
+        assert!(!unknown.description().is_empty());
+    }
+
+    #[test]
+    fn test_alarm_set_keeps_every_code() {
+        let mut bytes = [0u8; 16];
+        bytes[0] = 0b0000_0001; // CommonResetting
+        bytes[1] = 0b1000_0000; // 0x0F, unassigned
+        bytes[8] = 0b0000_0100; // LimitAxis2Pos
+
+        let mut set = AlarmSet::from_bytes(&bytes);
+
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.codes().collect::<Vec<_>>(), vec![0x00, 0x0F, 0x42]);
+        assert_eq!(set.unknown_codes().collect::<Vec<_>>(), vec![0x0F]);
+        assert!(set.contains(Alarm::LimitAxis2Pos));
+        set.insert(Alarm::MotorEndioIo);
+        set.remove_code(0x0F);
+        assert_eq!(
+            set.alarms().collect::<Vec<_>>(),
+            vec![
+                Alarm::CommonResetting,
+                Alarm::LimitAxis2Pos,
+                Alarm::MotorEndioIo
+            ]
+        );
+        assert_eq!(set.as_bytes()[0xB0 / 8], 1);
+        assert!(AlarmSet::new().is_empty());
+    }
+
+    #[test]
+    fn test_alarm_set_diff() {
+        let before = AlarmSet::from_iter([Alarm::LimitAxis1Pos, Alarm::PlanInvCalc]);
+        let mut after = AlarmSet::from_iter([Alarm::PlanInvCalc]);
+        after.insert_code(0xFE);
+
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.raised.codes().collect::<Vec<_>>(), vec![0xFE]);
+        assert_eq!(
+            diff.cleared.alarms().collect::<Vec<_>>(),
+            vec![Alarm::LimitAxis1Pos]
+        );
+        assert!(after.diff(&after).is_empty());
+    }
+}
@@ -0,0 +1,3 @@
+mod received;
+mod test;
+pub use received::FixedResponse;
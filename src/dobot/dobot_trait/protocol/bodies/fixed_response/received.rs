@@ -0,0 +1,48 @@
+use crate::dobot::dobot_trait::protocol::{Body, ProtocolError};
+
+/// An owned copy of a response body of at most `N` bytes.
+///
+/// Used for responses that have no dedicated tag, so that a command can
+/// return its parameters without borrowing the packet buffer.
+#[derive(PartialEq, Debug, Clone)]
+pub struct FixedResponse<const N: usize> {
+    len: usize,
+    bytes: [u8; N],
+}
+
+impl<const N: usize> FixedResponse<N> {
+    pub fn params(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Copies the parameters into `buffer` and returns their length.
+    pub fn copy_to(&self, buffer: &mut [u8]) -> Result<usize, ProtocolError> {
+        if buffer.len() < self.len {
+            return Err(ProtocolError::BufferTooSmall);
+        }
+        buffer[..self.len].copy_from_slice(self.params());
+        Ok(self.len)
+    }
+}
+
+impl<'a, const N: usize> Body<'a> for FixedResponse<N> {
+    fn size(&self) -> usize {
+        self.len
+    }
+
+    fn serialize(&self, buffer: &mut [u8]) -> Result<usize, ProtocolError> {
+        self.copy_to(buffer)
+    }
+
+    fn deserialize(buffer: &'a [u8]) -> Result<Self, ProtocolError> {
+        if buffer.len() > N {
+            return Err(ProtocolError::BufferTooSmall);
+        }
+        let mut bytes = [0u8; N];
+        bytes[..buffer.len()].copy_from_slice(buffer);
+        Ok(Self {
+            len: buffer.len(),
+            bytes,
+        })
+    }
+}
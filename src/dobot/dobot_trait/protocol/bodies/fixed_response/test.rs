@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::dobot_trait::protocol::bodies::fixed_response::FixedResponse;
+    use crate::dobot::dobot_trait::protocol::{Body, ProtocolError};
+
+    #[test]
+    fn test_fixed_response_deserialize_success() {
+        let response = FixedResponse::<4>::deserialize(&[0xAA, 0xBB, 0xCC]).unwrap();
+
+        assert_eq!(response.params(), &[0xAA, 0xBB, 0xCC]);
+        assert_eq!(response.size(), 3);
+    }
+
+    #[test]
+    fn test_fixed_response_deserialize_too_long() {
+        let result = FixedResponse::<2>::deserialize(&[1, 2, 3]);
+
+        assert_eq!(result, Err(ProtocolError::BufferTooSmall));
+    }
+
+    #[test]
+    fn test_fixed_response_round_trip() {
+        let response = FixedResponse::<8>::deserialize(b"SN01").unwrap();
+        let mut buffer = [0u8; 8];
+
+        let len = response.serialize(&mut buffer).unwrap();
+
+        assert_eq!(&buffer[..len], b"SN01");
+    }
+
+    #[test]
+    fn test_fixed_response_copy_to_small_buffer() {
+        let response = FixedResponse::<8>::deserialize(b"SN01").unwrap();
+        let mut buffer = [0u8; 2];
+
+        assert_eq!(
+            response.copy_to(&mut buffer),
+            Err(ProtocolError::BufferTooSmall)
+        );
+    }
+}
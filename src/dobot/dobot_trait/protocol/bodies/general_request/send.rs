@@ -1,6 +1,7 @@
 use crate::dobot::dobot_trait::protocol::{Body, ProtocolError};
 
 #[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct GeneralRequest<'a> {
     pub params: &'a [u8],
 }
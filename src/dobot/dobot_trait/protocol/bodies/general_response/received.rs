@@ -4,6 +4,7 @@ use crate::dobot::dobot_trait::{
 };
 
 #[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct GeneralResponse<'a> {
     pub params: &'a [u8],
 }
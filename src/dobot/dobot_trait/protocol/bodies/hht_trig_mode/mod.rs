@@ -1,9 +1,11 @@
 mod test;
 
-use crate::dobot::dobot_trait::protocol::protocol_error::ProtocolError;
+use crate::dobot::dobot_trait::protocol::{Body, protocol_error::ProtocolError};
 
 /// Represents the trigger mode for Handhold Teaching (HHT).
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[repr(u8)]
 pub enum HHTTrigMode {
     /// The teaching mode is triggered when a key is released.
@@ -26,3 +28,11 @@ impl HHTTrigMode {
         }
     }
 }
+
+impl TryFrom<u8> for HHTTrigMode {
+    type Error = ProtocolError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::try_from_u8(value)
+    }
+}
@@ -1,14 +1,14 @@
 use crate::dobot::dobot_trait::protocol::{protocol_error::ProtocolError, Body};
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[repr(u8)]
 pub enum Level {
     Low = 0x00,
     High = 0x01,
 }
 
-
-
 impl TryFrom<u8> for Level {
     type Error = ProtocolError;
 
@@ -21,20 +21,3 @@ impl TryFrom<u8> for Level {
         }
     }
 }
-
-impl<'a> Body<'a> for Level {
-    fn size(&self) -> usize {
-        core::mem::size_of::<u8>()
-    }
-
-    fn serialize(&self, buffer: &mut [u8]) -> Result<usize, ProtocolError> {
-        let level = *self as u8;
-        buffer[0] = level;
-        Ok(core::mem::size_of::<u8>())
-    }
-
-    fn deserialize(buffer: &'a [u8]) -> Result<Self, ProtocolError> {
-        let level = Level::try_from(buffer[0])?;
-        Ok(level)
-    }
-}
@@ -2,17 +2,22 @@ pub mod hht_trig_mode;
 pub mod level;
 pub mod tag_arc_cmd;
 pub mod tag_arc_params;
+pub mod tag_arm_orientation;
 pub mod tag_auto_leveling_params;
+pub mod tag_calibration;
 pub mod tag_color;
 pub mod tag_cp_cmd;
 pub mod tag_cp_params;
 pub mod tag_device;
 pub mod tag_emotor;
 pub mod tag_empty_body;
+pub mod tag_enabled;
 pub mod tag_end_effector_params;
+pub mod tag_end_effector_state;
 pub mod tag_home_cmd;
 pub mod tag_home_params;
 pub mod tag_io_adc;
+pub mod tag_io_address;
 pub mod tag_io_di;
 pub mod tag_io_do;
 pub mod tag_io_multiplexing;
@@ -22,6 +27,7 @@ pub mod tag_jog_common_params;
 pub mod tag_jog_coordinate_params;
 pub mod tag_jog_joint_params;
 pub mod tag_jog_l_params;
+pub mod tag_lost_step_params;
 pub mod tag_po_cmd;
 pub mod tag_pose;
 pub mod tag_ptp_cmd;
@@ -32,6 +38,8 @@ pub mod tag_ptp_jump2_params;
 pub mod tag_ptp_jump_params;
 pub mod tag_ptp_with_l_cmd;
 pub mod tag_ptpl_params;
+pub mod tag_queued_cmd_download;
+pub mod tag_reset_pose;
 pub mod tag_trig_cmd;
 pub mod tag_wait_cmd;
 pub mod tag_wifi_dns;
@@ -40,5 +48,6 @@ pub mod tag_wifi_ip_address;
 pub mod tag_wifi_netmask;
 pub mod tag_with_l;
 pub mod general_request;
+pub mod fixed_response;
 pub mod general_response;
 pub mod tag_queue;
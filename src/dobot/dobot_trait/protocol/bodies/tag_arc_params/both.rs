@@ -1,8 +1,9 @@
-use crate::dobot::dobot_trait::protocol::{Body, protocol_error::ProtocolError};
+use crate::dobot::dobot_trait::protocol::Body;
 
 /// Represents ARC parameters with velocity and acceleration.
 /// This struct corresponds to the Python `tagARCParams` dataclass.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TagARCParams {
     /// The coordinate velocity for the xyz axes.
     pub xyz_velocity: f32,
@@ -13,82 +14,3 @@ pub struct TagARCParams {
     /// The coordinate acceleration for the r axis.
     pub r_acceleration: f32,
 }
-
-impl<'a> Body<'a> for TagARCParams {
-    /// Returns the size of the serialized body in bytes.
-    /// This is composed of 4 floats (`f32`), each 4 bytes, totaling 16 bytes.
-    fn size(&self) -> usize {
-        4 * core::mem::size_of::<f32>()
-    }
-
-    /// Packs the `TagARCParams` struct into a byte sequence.
-    /// It serializes the four `f32` values into the buffer using little-endian byte order.
-    fn serialize(&self, buffer: &mut [u8]) -> Result<usize, ProtocolError> {
-        let size = self.size();
-        if buffer.len() < size {
-            return Err(ProtocolError::BufferTooSmall);
-        }
-
-        let mut offset = 0;
-        let float_size = core::mem::size_of::<f32>();
-
-        // Serialize the xyz velocity
-        buffer[offset..offset + float_size].copy_from_slice(&self.xyz_velocity.to_le_bytes());
-        offset += float_size;
-
-        // Serialize the r velocity
-        buffer[offset..offset + float_size].copy_from_slice(&self.r_velocity.to_le_bytes());
-        offset += float_size;
-
-        // Serialize the xyz acceleration
-        buffer[offset..offset + float_size].copy_from_slice(&self.xyz_acceleration.to_le_bytes());
-        offset += float_size;
-
-        // Serialize the r acceleration
-        buffer[offset..offset + float_size].copy_from_slice(&self.r_acceleration.to_le_bytes());
-
-        Ok(size)
-    }
-
-    /// Unpacks a byte sequence into a `TagARCParams` struct.
-    /// This corresponds to the Python `unpack` method.
-    fn deserialize(buffer: &'a [u8]) -> Result<Self, ProtocolError> {
-        let size = 4 * core::mem::size_of::<f32>();
-        if buffer.len() < size {
-            return Err(ProtocolError::BufferTooSmall);
-        }
-
-        let mut offset = 0;
-        let float_size = core::mem::size_of::<f32>();
-
-        // Deserialize the xyz velocity
-        let mut xyz_velocity_bytes = [0u8; 4];
-        xyz_velocity_bytes.copy_from_slice(&buffer[offset..offset + float_size]);
-        let xyz_velocity = f32::from_le_bytes(xyz_velocity_bytes);
-        offset += float_size;
-
-        // Deserialize the r velocity
-        let mut r_velocity_bytes = [0u8; 4];
-        r_velocity_bytes.copy_from_slice(&buffer[offset..offset + float_size]);
-        let r_velocity = f32::from_le_bytes(r_velocity_bytes);
-        offset += float_size;
-
-        // Deserialize the xyz acceleration
-        let mut xyz_acceleration_bytes = [0u8; 4];
-        xyz_acceleration_bytes.copy_from_slice(&buffer[offset..offset + float_size]);
-        let xyz_acceleration = f32::from_le_bytes(xyz_acceleration_bytes);
-        offset += float_size;
-
-        // Deserialize the r acceleration
-        let mut r_acceleration_bytes = [0u8; 4];
-        r_acceleration_bytes.copy_from_slice(&buffer[offset..offset + float_size]);
-        let r_acceleration = f32::from_le_bytes(r_acceleration_bytes);
-
-        Ok(Self {
-            xyz_velocity,
-            r_velocity,
-            xyz_acceleration,
-            r_acceleration,
-        })
-    }
-}
@@ -0,0 +1,24 @@
+use crate::dobot::dobot_trait::protocol::{Body, protocol_error::ProtocolError};
+use core::convert::TryFrom;
+
+/// Which way the elbow of a SCARA-style arm points.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[repr(u8)]
+pub enum ArmOrientation {
+    Lefty = 0x00,
+    Righty = 0x01,
+}
+
+impl TryFrom<u8> for ArmOrientation {
+    type Error = ProtocolError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(ArmOrientation::Lefty),
+            0x01 => Ok(ArmOrientation::Righty),
+            _ => Err(ProtocolError::InvalidEnumValue),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod both;
+mod test;
+
+pub use both::ArmOrientation;
@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::dobot_trait::protocol::{
+        Body, bodies::tag_arm_orientation::ArmOrientation, protocol_error::ProtocolError,
+    };
+
+    /// Test case for deserialization of an unknown orientation.
+    #[test]
+    fn test_arm_orientation_unpack_invalid_value() {
+        let result = ArmOrientation::deserialize(&[2]);
+
+        assert_eq!(result, Err(ProtocolError::InvalidEnumValue));
+    }
+}
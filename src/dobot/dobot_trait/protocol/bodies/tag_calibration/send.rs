@@ -0,0 +1,18 @@
+use crate::dobot::dobot_trait::protocol::Body;
+
+/// A pair of angle sensor values, used for both the static error and the
+/// coefficient of the rear and front arm sensors.
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagAngleSensorParams {
+    pub rear_arm: f32,
+    pub front_arm: f32,
+}
+
+/// A single calibration value, such as the base decoder static error or the
+/// right-hand calibration value.
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagCalibrationValue {
+    pub value: f32,
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::dobot_trait::protocol::{
+        Body,
+        bodies::tag_calibration::{TagAngleSensorParams, TagCalibrationValue},
+        protocol_error::ProtocolError,
+    };
+
+    /// Test case for the wire layout of TagAngleSensorParams.
+    #[test]
+    fn test_tag_angle_sensor_params_serialize_layout() {
+        let original = TagAngleSensorParams {
+            rear_arm: 1.5,
+            front_arm: -2.0,
+        };
+
+        let mut buffer = [0u8; 8];
+        let size = original.serialize(&mut buffer).unwrap();
+
+        assert_eq!(size, 8);
+        assert_eq!(buffer[..4], 1.5f32.to_le_bytes());
+        assert_eq!(buffer[4..], (-2.0f32).to_le_bytes());
+    }
+
+    /// Test case for deserialization with a buffer that is too small.
+    #[test]
+    fn test_tag_calibration_value_unpack_buffer_too_small() {
+        let buffer = [0u8; 3];
+        let result = TagCalibrationValue::deserialize(&buffer);
+
+        assert_eq!(result, Err(ProtocolError::BufferTooSmall));
+    }
+}
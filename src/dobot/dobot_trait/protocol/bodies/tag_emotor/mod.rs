@@ -1,3 +1,3 @@
 mod send;
 mod test;
-pub use send::{EMotorIndex, TagEMotor};
+pub use send::{EMotorIndex, TagEMotor, TagEMotorS};
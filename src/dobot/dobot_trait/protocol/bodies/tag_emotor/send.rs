@@ -1,6 +1,8 @@
 use crate::dobot::dobot_trait::protocol::{Body, protocol_error::ProtocolError};
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[repr(u8)]
 pub enum EMotorIndex {
     Stepper1 = 0x00,
@@ -20,77 +22,23 @@ impl TryFrom<u8> for EMotorIndex {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TagEMotor {
     pub address: EMotorIndex,
     pub ins_enabled: bool,
     pub speed: f64,
 }
 
-impl<'a> Body<'a> for TagEMotor {
-    /// Returns the size of the serialized body in bytes.
-    /// This is composed of two `u8`s (1 byte each) and one `f64` (8 bytes),
-    /// totaling 1 + 1 + 8 = 10 bytes.
-    fn size(&self) -> usize {
-        (2 * core::mem::size_of::<u8>()) + core::mem::size_of::<f64>()
-    }
-
-    /// Packs the `TagEMotor` struct into a byte sequence.
-    /// It serializes the `address` (`EMotorIndex` as `u8`), `ins_enabled` (`bool` as `u8`),
-    /// and `speed` (`f64`) into the buffer using little-endian byte order.
-    fn serialize(&self, buffer: &mut [u8]) -> Result<usize, ProtocolError> {
-        let size = self.size();
-        if buffer.len() < size {
-            return Err(ProtocolError::BufferTooSmall);
-        }
-
-        let mut offset = 0;
-        let u8_size = core::mem::size_of::<u8>();
-        let f64_size = core::mem::size_of::<f64>();
-
-        // Serialize the address enum value as a u8
-        buffer[offset..offset + u8_size].copy_from_slice(&(self.address as u8).to_le_bytes());
-        offset += u8_size;
-
-        // Serialize the boolean `ins_enabled` as a u8 (1 or 0)
-        let ins_enabled_byte = if self.ins_enabled { 1u8 } else { 0u8 };
-        buffer[offset..offset + u8_size].copy_from_slice(&ins_enabled_byte.to_le_bytes());
-        offset += u8_size;
-
-        // Serialize the speed
-        buffer[offset..offset + f64_size].copy_from_slice(&self.speed.to_le_bytes());
-
-        Ok(size)
-    }
-
-    /// Unpacks a byte sequence into a `TagEMotor` struct.
-    fn deserialize(buffer: &[u8]) -> Result<Self, ProtocolError> {
-        let size = (2 * core::mem::size_of::<u8>()) + core::mem::size_of::<f64>();
-        if buffer.len() < size {
-            return Err(ProtocolError::BufferTooSmall);
-        }
-
-        let mut offset = 0;
-        let u8_size = core::mem::size_of::<u8>();
-        let f64_size = core::mem::size_of::<f64>();
-
-        // Deserialize the address `u8` and convert to `EMotorIndex` enum
-        let address = EMotorIndex::try_from(buffer[offset])?;
-        offset += u8_size;
-
-        // Deserialize the `ins_enabled` u8 and convert to bool
-        let ins_enabled = buffer[offset] == 1;
-        offset += u8_size;
-
-        // Deserialize the speed
-        let mut speed_bytes = [0u8; 8];
-        speed_bytes.copy_from_slice(&buffer[offset..offset + f64_size]);
-        let speed = f64::from_le_bytes(speed_bytes);
-
-        Ok(Self {
-            address,
-            ins_enabled,
-            speed,
-        })
-    }
+/// Runs a stepper output for a given distance.
+/// This struct corresponds to the Python `tagEMotorS` dataclass.
+#[derive(Debug, PartialEq, Clone, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagEMotorS {
+    pub address: EMotorIndex,
+    pub is_enabled: bool,
+    /// Pulses per second.
+    pub speed: i32,
+    /// Number of pulses to run.
+    pub distance: u32,
 }
@@ -2,7 +2,7 @@
 mod tests {
     use crate::dobot::dobot_trait::protocol::{
         Body,
-        bodies::tag_emotor::{TagEMotor, send::EMotorIndex},
+        bodies::tag_emotor::{TagEMotor, TagEMotorS, send::EMotorIndex},
         protocol_error::ProtocolError,
     };
 
@@ -52,4 +52,24 @@ mod tests {
         // Assert that the deserialization failed with an InvalidEnumValue error
         assert_eq!(result, Err(ProtocolError::InvalidEnumValue));
     }
+
+    /// Test case for the wire layout of TagEMotorS.
+    #[test]
+    fn test_tag_e_motor_s_serialize_layout() {
+        let original_cmd = TagEMotorS {
+            address: EMotorIndex::Stepper2,
+            is_enabled: true,
+            speed: -1000,
+            distance: 20000,
+        };
+
+        let mut buffer = [0u8; 10];
+        let size = original_cmd.serialize(&mut buffer).unwrap();
+
+        // 2 u8s + 1 i32 + 1 u32 = 10 bytes
+        assert_eq!(size, 10);
+        assert_eq!(buffer[..2], [1, 1]);
+        assert_eq!(buffer[2..6], (-1000i32).to_le_bytes());
+        assert_eq!(buffer[6..], 20000u32.to_le_bytes());
+    }
 }
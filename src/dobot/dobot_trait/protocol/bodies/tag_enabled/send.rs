@@ -0,0 +1,9 @@
+use crate::dobot::dobot_trait::protocol::Body;
+
+/// A single on/off switch, such as the HHT trigger output or the WiFi
+/// configuration mode.
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagEnabled {
+    pub is_enabled: bool,
+}
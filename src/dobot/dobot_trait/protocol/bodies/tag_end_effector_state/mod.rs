@@ -0,0 +1,4 @@
+mod send;
+mod test;
+
+pub use send::TagEndEffectorState;
@@ -0,0 +1,11 @@
+use crate::dobot::dobot_trait::protocol::Body;
+
+/// The state of a laser, suction cup or gripper.
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagEndEffectorState {
+    /// Whether the arm drives the end effector at all.
+    pub is_ctrl_enabled: bool,
+    /// Laser on, suction on or gripper closed.
+    pub is_on: bool,
+}
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::dobot_trait::protocol::{
+        Body, bodies::tag_end_effector_state::TagEndEffectorState, protocol_error::ProtocolError,
+    };
+
+    /// Test case for the wire layout of TagEndEffectorState.
+    #[test]
+    fn test_tag_end_effector_state_serialize_layout() {
+        let original = TagEndEffectorState {
+            is_ctrl_enabled: true,
+            is_on: false,
+        };
+
+        let mut buffer = [0u8; 2];
+        let size = original.serialize(&mut buffer).unwrap();
+
+        assert_eq!(size, 2);
+        assert_eq!(buffer, [1, 0]);
+    }
+
+    /// Test case for deserialization with a buffer that is too small.
+    #[test]
+    fn test_tag_end_effector_state_unpack_buffer_too_small() {
+        let buffer = [0u8; 1];
+        let result = TagEndEffectorState::deserialize(&buffer);
+
+        assert_eq!(result, Err(ProtocolError::BufferTooSmall));
+    }
+}
@@ -0,0 +1,8 @@
+use crate::dobot::dobot_trait::protocol::Body;
+
+/// Selects the extended IO interface or sensor port a read refers to.
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagIOAddress {
+    pub address: u8,
+}
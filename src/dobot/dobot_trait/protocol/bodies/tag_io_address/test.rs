@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::dobot_trait::protocol::{
+        Body, bodies::tag_io_address::TagIOAddress, protocol_error::ProtocolError,
+    };
+
+    /// Test case for the wire layout of TagIOAddress.
+    #[test]
+    fn test_tag_io_address_serialize_layout() {
+        let original = TagIOAddress { address: 7 };
+
+        let mut buffer = [0u8; 1];
+        let size = original.serialize(&mut buffer).unwrap();
+
+        assert_eq!(size, 1);
+        assert_eq!(buffer, [7]);
+    }
+
+    /// Test case for deserialization with a buffer that is too small.
+    #[test]
+    fn test_tag_io_address_unpack_buffer_too_small() {
+        let buffer = [0u8; 0];
+        let result = TagIOAddress::deserialize(&buffer);
+
+        assert_eq!(result, Err(ProtocolError::BufferTooSmall));
+    }
+}
@@ -51,4 +51,23 @@ mod tests {
         // Assert that the deserialization failed with an InvalidEnumValue error
         assert_eq!(result, Err(ProtocolError::InvalidEnumValue));
     }
+
+    /// Unknown IO function names are rejected when deserializing.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_tag_io_multiplexing_serde() {
+        let io = serde_json::from_str::<TagIOMultiplexing>(r#"{"address":4,"multiplex":"adc"}"#)
+            .unwrap();
+        assert_eq!(
+            io,
+            TagIOMultiplexing {
+                address: 4,
+                multiplex: IOFunction::Adc,
+            }
+        );
+
+        let result =
+            serde_json::from_str::<TagIOMultiplexing>(r#"{"address":4,"multiplex":"analog"}"#);
+        assert!(result.is_err());
+    }
 }
@@ -2,7 +2,9 @@ use crate::dobot::dobot_trait::protocol::{Body, protocol_error::ProtocolError};
 use core::convert::TryFrom;
 
 /// Represents the JOG command mode.
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[repr(u8)]
 pub enum JogMode {
     Coordinate = 0,
@@ -23,18 +25,22 @@ impl TryFrom<u8> for JogMode {
 }
 
 /// Represents the JOG command type with more descriptive names.
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[repr(u8)]
 pub enum JogCmd {
-    Idle = 0,   // Void
-    ApDown = 1, // X+/Joint1+
-    AnDown = 2, // X-/Joint1-
-    BpDown = 3, // Y+/Joint2+
-    BnDown = 4, // Y-/Joint2-
-    CpDown = 5, // Z+/Joint3+
-    CnDown = 6, // Z-/Joint3-
-    DpDown = 7, // R+/Joint4+
-    DnDown = 8, // R-/Joint4-
+    Idle = 0,    // Void
+    ApDown = 1,  // X+/Joint1+
+    AnDown = 2,  // X-/Joint1-
+    BpDown = 3,  // Y+/Joint2+
+    BnDown = 4,  // Y-/Joint2-
+    CpDown = 5,  // Z+/Joint3+
+    CnDown = 6,  // Z-/Joint3-
+    DpDown = 7,  // R+/Joint4+
+    DnDown = 8,  // R-/Joint4-
+    LpDown = 9,  // Rail+
+    LnDown = 10, // Rail-
 }
 
 impl TryFrom<u8> for JogCmd {
@@ -52,6 +58,8 @@ impl TryFrom<u8> for JogCmd {
             6 => Ok(JogCmd::CnDown),
             7 => Ok(JogCmd::DpDown),
             8 => Ok(JogCmd::DnDown),
+            9 => Ok(JogCmd::LpDown),
+            10 => Ok(JogCmd::LnDown),
             _ => Err(ProtocolError::InvalidEnumValue),
         }
     }
@@ -59,47 +67,9 @@ impl TryFrom<u8> for JogCmd {
 
 /// Represents a JOG command with mode and command type.
 /// This struct corresponds to the Python `tagJOGCmd` dataclass.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TagJOGCmd {
     pub is_joint: JogMode,
     pub cmd: JogCmd,
 }
-
-impl<'a> Body<'a> for TagJOGCmd {
-    /// Returns the size of the serialized body in bytes.
-    /// This is composed of two unsigned 8-bit integers (`u8`), each 1 byte.
-    fn size(&self) -> usize {
-        2 * core::mem::size_of::<u8>()
-    }
-
-    /// Packs the `TagJOGCmd` struct into a byte sequence.
-    /// It serializes the `is_joint` and `cmd` enum values as `u8`s.
-    fn serialize(&self, buffer: &mut [u8]) -> Result<usize, ProtocolError> {
-        let size = self.size();
-        if buffer.len() < size {
-            return Err(ProtocolError::BufferTooSmall);
-        }
-
-        // Serialize the enum values as u8s
-        buffer[0] = self.is_joint as u8;
-        buffer[1] = self.cmd as u8;
-
-        Ok(size)
-    }
-
-    /// Unpacks a byte sequence into a `TagJOGCmd` struct.
-    /// It deserializes the `u8`s and converts them into `JogMode` and `JogCmd`
-    /// enums, handling any invalid values.
-    fn deserialize(buffer: &[u8]) -> Result<Self, ProtocolError> {
-        let size = 2;
-        if buffer.len() != size {
-            return Err(ProtocolError::BufferTooSmall);
-        }
-
-        // Deserialize the u8s and convert to enums
-        let is_joint = JogMode::try_from(buffer[0])?;
-        let cmd = JogCmd::try_from(buffer[1])?;
-
-        Ok(Self { is_joint, cmd })
-    }
-}
@@ -59,4 +59,17 @@ mod tests {
         // Assert that the deserialization failed with an InvalidEnumValue error
         assert_eq!(result, Err(ProtocolError::InvalidEnumValue));
     }
+
+    /// Test case for the rail JOG commands.
+    #[test]
+    fn test_tag_jog_cmd_rail_commands() {
+        let buffer = [1u8, 9, 1, 10];
+
+        let plus = TagJOGCmd::deserialize(&buffer[..2]).unwrap();
+        let minus = TagJOGCmd::deserialize(&buffer[2..]).unwrap();
+
+        assert_eq!(plus.cmd, JogCmd::LpDown);
+        assert_eq!(minus.cmd, JogCmd::LnDown);
+        assert_eq!(minus.is_joint, JogMode::Joint);
+    }
 }
@@ -0,0 +1,9 @@
+use crate::dobot::dobot_trait::protocol::Body;
+
+/// Configures the lost-step detection.
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagLostStepParams {
+    /// Deviation in degrees above which a step counts as lost.
+    pub threshold: f32,
+}
@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::dobot_trait::protocol::{
+        Body, bodies::tag_lost_step_params::TagLostStepParams, protocol_error::ProtocolError,
+    };
+
+    /// Test case for the wire layout of TagLostStepParams.
+    #[test]
+    fn test_tag_lost_step_params_serialize_layout() {
+        let original = TagLostStepParams { threshold: 5.0 };
+
+        let mut buffer = [0u8; 4];
+        let size = original.serialize(&mut buffer).unwrap();
+
+        assert_eq!(size, 4);
+        assert_eq!(buffer, [0, 0, 160, 64]);
+    }
+
+    /// Test case for deserialization with a buffer that is too small.
+    #[test]
+    fn test_tag_lost_step_params_unpack_buffer_too_small() {
+        let buffer = [0u8; 3];
+        let result = TagLostStepParams::deserialize(&buffer);
+
+        assert_eq!(result, Err(ProtocolError::BufferTooSmall));
+    }
+}
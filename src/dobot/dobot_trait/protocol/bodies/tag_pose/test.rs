@@ -40,4 +40,21 @@ mod tests {
         // Assert that the deserialization failed with a BufferTooSmall error
         assert_eq!(result, Err(ProtocolError::BufferTooSmall));
     }
+
+    /// Test case for loading a pose fixture through serde.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_tag_pose_serde_round_trip() {
+        let pose = TagPose {
+            x: 250.0,
+            y: 12.5,
+            z: -40.0,
+            r: 90.0,
+            joint_angle: [2.8, 45.0, 30.0, 87.2],
+        };
+
+        let json = serde_json::to_string(&pose).unwrap();
+
+        assert_eq!(serde_json::from_str::<TagPose>(&json).unwrap(), pose);
+    }
 }
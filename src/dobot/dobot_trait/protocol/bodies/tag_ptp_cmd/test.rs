@@ -53,4 +53,25 @@ mod tests {
         // Assert that the deserialization failed with an InvalidEnumValue error
         assert_eq!(result, Err(ProtocolError::InvalidEnumValue));
     }
+
+    /// The serde representation uses snake_case mode names.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_tag_ptp_cmd_serde_round_trip() {
+        let cmd = TagPTPCmd {
+            ptp_mode: PTPMode::JumpMovlXyz,
+            x: 200.0,
+            y: -10.5,
+            z: 30.0,
+            r: 0.0,
+        };
+
+        let json = serde_json::to_string(&cmd).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"ptp_mode":"jump_movl_xyz","x":200.0,"y":-10.5,"z":30.0,"r":0.0}"#
+        );
+        assert_eq!(serde_json::from_str::<TagPTPCmd>(&json).unwrap(), cmd);
+    }
 }
@@ -0,0 +1,11 @@
+use crate::dobot::dobot_trait::protocol::Body;
+
+/// Starts downloading queued commands for offline execution.
+#[derive(Debug, PartialEq, Clone, Copy, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagQueuedCmdDownload {
+    /// Number of times the downloaded commands are run.
+    pub total_loop: u32,
+    /// Number of commands in one loop.
+    pub line_per_loop: u32,
+}
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::dobot_trait::protocol::{
+        Body, bodies::tag_queued_cmd_download::TagQueuedCmdDownload, protocol_error::ProtocolError,
+    };
+
+    /// Test case for the wire layout of TagQueuedCmdDownload.
+    #[test]
+    fn test_tag_queued_cmd_download_serialize_layout() {
+        let original = TagQueuedCmdDownload {
+            total_loop: 2,
+            line_per_loop: 300,
+        };
+
+        let mut buffer = [0u8; 8];
+        let size = original.serialize(&mut buffer).unwrap();
+
+        assert_eq!(size, 8);
+        assert_eq!(buffer, [2, 0, 0, 0, 44, 1, 0, 0]);
+    }
+
+    /// Test case for deserialization with a buffer that is too small.
+    #[test]
+    fn test_tag_queued_cmd_download_unpack_buffer_too_small() {
+        let buffer = [0u8; 7];
+        let result = TagQueuedCmdDownload::deserialize(&buffer);
+
+        assert_eq!(result, Err(ProtocolError::BufferTooSmall));
+    }
+}
@@ -0,0 +1,12 @@
+use crate::dobot::dobot_trait::protocol::Body;
+
+/// Resets the reference of the real-time pose.
+/// This struct corresponds to the arguments of the Python `ResetPose` call.
+#[derive(Debug, PartialEq, Clone, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagResetPose {
+    /// 0 to reset from the angle sensors, 1 to use the angles below.
+    pub manual: u8,
+    pub rear_arm_angle: f32,
+    pub front_arm_angle: f32,
+}
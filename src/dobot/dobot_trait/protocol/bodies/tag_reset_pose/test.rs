@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::dobot_trait::protocol::{
+        Body, bodies::tag_reset_pose::TagResetPose, protocol_error::ProtocolError,
+    };
+
+    /// Test case for the wire layout of TagResetPose.
+    #[test]
+    fn test_tag_reset_pose_serialize_layout() {
+        let original = TagResetPose {
+            manual: 1,
+            rear_arm_angle: 45.0,
+            front_arm_angle: -1.0,
+        };
+
+        let mut buffer = [0u8; 9];
+        let size = original.serialize(&mut buffer).unwrap();
+
+        assert_eq!(size, 9);
+        assert_eq!(buffer, [1, 0, 0, 52, 66, 0, 0, 128, 191]);
+    }
+
+    /// Test case for deserialization with a buffer that is too small.
+    #[test]
+    fn test_tag_reset_pose_unpack_buffer_too_small() {
+        let buffer = [0u8; 8];
+        let result = TagResetPose::deserialize(&buffer);
+
+        assert_eq!(result, Err(ProtocolError::BufferTooSmall));
+    }
+}
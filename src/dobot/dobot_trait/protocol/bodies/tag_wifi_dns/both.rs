@@ -1,44 +1,9 @@
-use crate::dobot::dobot_trait::protocol::{Body, protocol_error::ProtocolError};
+use crate::dobot::dobot_trait::protocol::Body;
 
 /// Represents a WiFi DNS server address configuration.
 /// This struct corresponds to the Python `tagWIFIDNS` dataclass.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Body)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TagWIFIDNS {
     pub addr: [u8; 4], // Changed from Ipv4Addr to a fixed-size array [u8; 4]
 }
-
-impl<'a> Body<'a> for TagWIFIDNS {
-    /// Returns the size of the serialized body in bytes.
-    /// This is composed of one 4-byte array.
-    fn size(&self) -> usize {
-        self.addr.len() // The size is simply the length of the array (4 bytes)
-    }
-
-    /// Packs the `TagWIFIDNS` struct into a byte sequence.
-    /// It serializes the `addr` (4-byte array) into the buffer.
-    fn serialize(&self, buffer: &mut [u8]) -> Result<usize, ProtocolError> {
-        let size = self.size();
-        if buffer.len() < size {
-            return Err(ProtocolError::BufferTooSmall);
-        }
-
-        // Serialize the 4-byte IP address directly from the array
-        buffer[..size].copy_from_slice(&self.addr);
-
-        Ok(size)
-    }
-
-    /// Unpacks a byte sequence into a `TagWIFIDNS` struct.
-    fn deserialize(buffer: &[u8]) -> Result<Self, ProtocolError> {
-        let size = 4; // The size is fixed at 4 bytes for an IPv4 address
-        if buffer.len() < size {
-            return Err(ProtocolError::BufferTooSmall);
-        }
-
-        // Deserialize the 4-byte address into a new array
-        let mut addr_bytes = [0u8; 4];
-        addr_bytes.copy_from_slice(&buffer[..size]);
-
-        Ok(Self { addr: addr_bytes })
-    }
-}
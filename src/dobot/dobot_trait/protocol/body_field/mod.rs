@@ -0,0 +1,74 @@
+mod test;
+
+use super::protocol_error::ProtocolError;
+
+/// A fixed-size value that can appear as a field inside a protocol body.
+///
+/// This is what `#[derive(Body)]` builds on: every field of a derived struct
+/// must implement it, and derived structs and enums implement it themselves so
+/// they can be nested inside other bodies.
+pub trait BodyField: Sized {
+    /// Number of bytes the value occupies on the wire.
+    const SIZE: usize;
+
+    /// Writes the value into `buffer`, which is exactly `SIZE` bytes long.
+    fn write(&self, buffer: &mut [u8]);
+
+    /// Reads a value from `buffer`, which is exactly `SIZE` bytes long.
+    fn read(buffer: &[u8]) -> Result<Self, ProtocolError>;
+}
+
+macro_rules! impl_body_field_for_number {
+    ($($ty:ty),*) => {
+        $(
+            impl BodyField for $ty {
+                const SIZE: usize = core::mem::size_of::<$ty>();
+
+                fn write(&self, buffer: &mut [u8]) {
+                    buffer.copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn read(buffer: &[u8]) -> Result<Self, ProtocolError> {
+                    let mut bytes = [0u8; core::mem::size_of::<$ty>()];
+                    bytes.copy_from_slice(buffer);
+                    Ok(<$ty>::from_le_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_body_field_for_number!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+impl BodyField for bool {
+    const SIZE: usize = 1;
+
+    fn write(&self, buffer: &mut [u8]) {
+        buffer[0] = *self as u8;
+    }
+
+    /// Only `1` reads as `true`; any other byte reads as `false`.
+    fn read(buffer: &[u8]) -> Result<Self, ProtocolError> {
+        Ok(buffer[0] == 1)
+    }
+}
+
+impl<T: BodyField, const N: usize> BodyField for [T; N] {
+    const SIZE: usize = N * T::SIZE;
+
+    fn write(&self, buffer: &mut [u8]) {
+        for (index, item) in self.iter().enumerate() {
+            let start = index * T::SIZE;
+            item.write(&mut buffer[start..start + T::SIZE]);
+        }
+    }
+
+    fn read(buffer: &[u8]) -> Result<Self, ProtocolError> {
+        let mut items: [Option<T>; N] = [const { None }; N];
+        for (index, slot) in items.iter_mut().enumerate() {
+            let start = index * T::SIZE;
+            *slot = Some(T::read(&buffer[start..start + T::SIZE])?);
+        }
+        Ok(items.map(|item| item.unwrap()))
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::dobot_trait::protocol::{
+        Body, BodyField, ProtocolError,
+        bodies::{level::Level, tag_ptp_cmd::PTPMode},
+    };
+
+    #[derive(Debug, PartialEq, Body)]
+    struct Padded {
+        #[body(pad_after = 2)]
+        mode: PTPMode,
+        #[body(reserved)]
+        reserved: u16,
+        #[body(pad_before = 1)]
+        value: i32,
+    }
+
+    #[derive(Debug, PartialEq, Body)]
+    struct Nested {
+        levels: [Level; 2],
+        inner: Padded,
+    }
+
+    #[derive(Debug, PartialEq, Body)]
+    struct Tagged<'a> {
+        address: u8,
+        params: &'a [u8],
+    }
+
+    #[derive(Debug, PartialEq, Body)]
+    struct Pair(u8, bool);
+
+    #[test]
+    fn test_padding_and_reserved_layout() {
+        let padded = Padded {
+            mode: PTPMode::MovlXyz,
+            reserved: 0xBEEF,
+            value: -2,
+        };
+        let mut buffer = [0xFFu8; 10];
+
+        assert_eq!(Padded::SIZE, 10);
+        assert_eq!(padded.serialize(&mut buffer), Ok(10));
+        assert_eq!(buffer, [0x02, 0, 0, 0, 0, 0, 0xFE, 0xFF, 0xFF, 0xFF]);
+
+        buffer[3] = 0x12;
+        let decoded = Padded::deserialize(&buffer).unwrap();
+        assert_eq!(decoded.reserved, 0);
+        assert_eq!(decoded.value, -2);
+    }
+
+    #[test]
+    fn test_nested_bodies_and_enum_arrays() {
+        let nested = Nested {
+            levels: [Level::High, Level::Low],
+            inner: Padded {
+                mode: PTPMode::JumpXyz,
+                reserved: 0,
+                value: 7,
+            },
+        };
+        let mut buffer = [0u8; 12];
+
+        assert_eq!(nested.serialize(&mut buffer), Ok(12));
+        assert_eq!(&buffer[..3], &[0x01, 0x00, 0x00]);
+        assert_eq!(Nested::deserialize(&buffer), Ok(nested));
+
+        buffer[1] = 0x07;
+        assert_eq!(
+            Nested::deserialize(&buffer),
+            Err(ProtocolError::InvalidEnumValue)
+        );
+    }
+
+    #[test]
+    fn test_trailing_byte_slice_takes_the_rest() {
+        let params = [0x0A, 0x0B, 0x0C];
+        let tagged = Tagged {
+            address: 4,
+            params: &params,
+        };
+        let mut buffer = [0u8; 8];
+
+        assert_eq!(tagged.size(), 4);
+        assert_eq!(tagged.serialize(&mut buffer), Ok(4));
+        assert_eq!(Tagged::deserialize(&buffer[..4]), Ok(tagged));
+        assert_eq!(
+            Tagged::deserialize(&buffer[..1]).unwrap().params,
+            &[] as &[u8]
+        );
+        assert_eq!(
+            Tagged::deserialize(&[]),
+            Err(ProtocolError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_tuple_struct_and_bool_encoding() {
+        let mut buffer = [0u8; 2];
+
+        assert_eq!(Pair(9, true).serialize(&mut buffer), Ok(2));
+        assert_eq!(buffer, [9, 1]);
+        assert_eq!(Pair::deserialize(&[9, 2]), Ok(Pair(9, false)));
+        assert_eq!(
+            Pair(1, false).serialize(&mut buffer[..1]),
+            Err(ProtocolError::BufferTooSmall)
+        );
+    }
+}
@@ -0,0 +1,355 @@
+mod test;
+
+use super::{
+    Body, CommunicationProtocolIDs, ProtocolError,
+    bodies::{
+        fixed_response::FixedResponse,
+        general_request::GeneralRequest,
+        hht_trig_mode::HHTTrigMode,
+        tag_arc_cmd::TagARCCmd,
+        tag_arc_params::TagARCParams,
+        tag_arm_orientation::ArmOrientation,
+        tag_auto_leveling_params::TagAutoLevelingParams,
+        tag_calibration::{TagAngleSensorParams, TagCalibrationValue},
+        tag_color::TagColor,
+        tag_cp_cmd::TagCPCmd,
+        tag_cp_params::TagCPParams,
+        tag_device::TagDevice,
+        tag_emotor::{TagEMotor, TagEMotorS},
+        tag_empty_body::EmptyBody,
+        tag_enabled::TagEnabled,
+        tag_end_effector_params::TagEndEffectorParams,
+        tag_end_effector_state::TagEndEffectorState,
+        tag_home_cmd::TagHomeCmd,
+        tag_home_params::TagHomeParams,
+        tag_io_address::TagIOAddress,
+        tag_io_do::TagIODO,
+        tag_io_multiplexing::TagIOMultiplexing,
+        tag_io_pwm::TagIOPWM,
+        tag_jog_cmd::TagJOGCmd,
+        tag_jog_common_params::TagJOGCommonParams,
+        tag_jog_coordinate_params::TagJOGCoordinateParams,
+        tag_jog_joint_params::TagJOGJointParams,
+        tag_jog_l_params::TagJOGLParams,
+        tag_lost_step_params::TagLostStepParams,
+        tag_pose::TagPose,
+        tag_ptp_cmd::TagPTPCmd,
+        tag_ptp_common_params::TagPTPCommonParams,
+        tag_ptp_coordinate_params::TagPTPCoordinateParams,
+        tag_ptp_joint_params::TagPTPJointParams,
+        tag_ptp_jump_params::TagPTPJumpParams,
+        tag_ptp_jump2_params::TagPTPJump2Params,
+        tag_ptp_with_l_cmd::TagPTPWithLCmd,
+        tag_ptpl_params::TagPTPLParams,
+        tag_queued_cmd_download::TagQueuedCmdDownload,
+        tag_reset_pose::TagResetPose,
+        tag_trig_cmd::TagTRIGCmd,
+        tag_wait_cmd::TagWAITCmd,
+        tag_wifi_dns::TagWIFIDNS,
+        tag_wifi_gateway::TagWIFIGateway,
+        tag_wifi_ip_address::TagWIFIIPAddress,
+        tag_wifi_netmask::TagWIFINetmask,
+        tag_with_l::TagWithL,
+    },
+    command_id::{
+        AlarmIDs, ArcIDs, ArmOrientationIDs, CalIDs, CheckModelIDs, CpIDs, DeviceInfoIDs,
+        DevicePoseIDs, EioIDs, EndEffectorIDs, HHTIDs, HomeIDs, JogIDs, LostStepIDs, PtpIDs,
+        PulseModeIDs, QueuedCmdIDs, TestIDs, TrigIDs, WaitIDs, WifiIDs,
+    },
+};
+
+/// Size of the response to a queued command, which carries the queue index.
+pub const QUEUED_RESPONSE_SIZE: usize = core::mem::size_of::<u64>();
+
+/// Largest string (serial number, name, SSID, password) read back from the
+/// arm.
+pub const MAX_STRING_SIZE: usize = 64;
+
+/// A single operation of the communication protocol.
+///
+/// Every operation fixes the ID it is sent with, the direction of the
+/// read/write flag and the bodies exchanged, so a controller can only send a
+/// request body the arm expects for that ID and can only decode the response
+/// into the matching type.
+pub trait Command {
+    const NAME: &'static str;
+    const ID: CommunicationProtocolIDs;
+    /// Whether the write flag of the control byte is set.
+    const IS_WRITE: bool;
+    /// Whether the command may be placed in the command queue. Queueable
+    /// commands also implement [`Queueable`].
+    const QUEUEABLE: bool;
+    /// Largest parameter block the arm answers an immediate command with.
+    const MAX_RESPONSE_SIZE: usize;
+
+    type Request<'a>: Body<'a> + 'a;
+    type Response: for<'a> Body<'a>;
+
+    const DESCRIPTOR: CommandDescriptor = CommandDescriptor {
+        name: Self::NAME,
+        id: Self::ID,
+        is_write: Self::IS_WRITE,
+        queueable: Self::QUEUEABLE,
+        max_response_size: Self::MAX_RESPONSE_SIZE,
+        parse_response: parse_response::<Self>,
+    };
+}
+
+/// Commands that the arm accepts in its command queue.
+pub trait Queueable: Command {}
+
+/// The properties of a [`Command`] available at runtime.
+#[derive(Debug, Clone, Copy)]
+pub struct CommandDescriptor {
+    pub name: &'static str,
+    pub id: CommunicationProtocolIDs,
+    pub is_write: bool,
+    pub queueable: bool,
+    pub max_response_size: usize,
+    /// Checks that a parameter block is a valid immediate response.
+    pub parse_response: fn(&[u8]) -> Result<(), ProtocolError>,
+}
+
+impl CommandDescriptor {
+    pub fn is_readable(&self) -> bool {
+        !self.is_write
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.is_write
+    }
+}
+
+fn parse_response<C: Command + ?Sized>(params: &[u8]) -> Result<(), ProtocolError> {
+    if params.len() > C::MAX_RESPONSE_SIZE {
+        return Err(ProtocolError::BufferTooSmall);
+    }
+    C::Response::deserialize(params).map(|_| ())
+}
+
+/// Looks up the command sent with `id` and the given read/write flag.
+pub fn find_command(
+    id: CommunicationProtocolIDs,
+    is_write: bool,
+) -> Option<&'static CommandDescriptor> {
+    COMMANDS
+        .iter()
+        .find(|descriptor| descriptor.id == id && descriptor.is_write == is_write)
+}
+
+macro_rules! define_commands {
+    (@is_write read) => { false };
+    (@is_write write) => { true };
+    (@queueable) => { false };
+    (@queueable queue) => { true };
+    (@impl_queueable $name:ident) => {};
+    (@impl_queueable $name:ident queue) => {
+        impl Queueable for $name {}
+    };
+
+    ($(
+        $(#[$meta:meta])*
+        $name:ident = $id:expr, $dir:ident $(+ $queue:ident)?, $request:ty => $response:ty, $max:expr;
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy)]
+            pub struct $name;
+
+            impl Command for $name {
+                const NAME: &'static str = stringify!($name);
+                const ID: CommunicationProtocolIDs = $id;
+                const IS_WRITE: bool = define_commands!(@is_write $dir);
+                const QUEUEABLE: bool = define_commands!(@queueable $($queue)?);
+                const MAX_RESPONSE_SIZE: usize = $max;
+
+                type Request<'a> = $request;
+                type Response = $response;
+            }
+
+            define_commands!(@impl_queueable $name $($queue)?);
+        )*
+
+        /// Every command of the protocol.
+        pub const COMMANDS: &[CommandDescriptor] = &[$($name::DESCRIPTOR),*];
+    };
+}
+
+use CommunicationProtocolIDs as Id;
+
+define_commands! {
+    // INFO
+    GetDeviceSn = Id::DeviceInfo(DeviceInfoIDs::Sn), read, EmptyBody => FixedResponse<MAX_STRING_SIZE>, MAX_STRING_SIZE;
+    SetDeviceSn = Id::DeviceInfo(DeviceInfoIDs::Sn), write, GeneralRequest<'a> => EmptyBody, 0;
+    GetDeviceName = Id::DeviceInfo(DeviceInfoIDs::Name), read, EmptyBody => FixedResponse<MAX_STRING_SIZE>, MAX_STRING_SIZE;
+    SetDeviceName = Id::DeviceInfo(DeviceInfoIDs::Name), write, GeneralRequest<'a> => EmptyBody, 0;
+    /// Major, minor and revision number of the firmware.
+    GetDeviceVersion = Id::DeviceInfo(DeviceInfoIDs::Version), read, EmptyBody => FixedResponse<3>, 3;
+    GetDeviceWithRail = Id::DeviceInfo(DeviceInfoIDs::WithRail), read, EmptyBody => FixedResponse<1>, 1;
+    SetDeviceWithRail = Id::DeviceInfo(DeviceInfoIDs::WithRail), write, TagWithL => EmptyBody, 0;
+    /// System tick in milliseconds.
+    GetDeviceTime = Id::DeviceInfo(DeviceInfoIDs::Time), read, EmptyBody => FixedResponse<4>, 4;
+    GetDeviceId = Id::DeviceInfo(DeviceInfoIDs::Id), read, EmptyBody => FixedResponse<12>, 12;
+
+    // POSE
+    GetPose = Id::DevicePose(DevicePoseIDs::GetPose), read, EmptyBody => TagPose, 32;
+    ResetPose = Id::DevicePose(DevicePoseIDs::ResetPose), write, TagResetPose => EmptyBody, 0;
+    /// Velocity and acceleration of the current motion.
+    GetKinematics = Id::DevicePose(DevicePoseIDs::GetKinematics), read, EmptyBody => FixedResponse<8>, 8;
+    GetPoseL = Id::DevicePose(DevicePoseIDs::GetPoseL), read, EmptyBody => FixedResponse<4>, 4;
+
+    // ALARM
+    /// One bit per alarm code; older firmware sends only the first 16 bytes.
+    GetAlarmState = Id::Alarm(AlarmIDs::GetAlarmState), read, EmptyBody => FixedResponse<32>, 32;
+    ClearAllAlarmsState = Id::Alarm(AlarmIDs::ClearAlarmState), write, EmptyBody => EmptyBody, 0;
+
+    // HOME
+    GetHomeParams = Id::Home(HomeIDs::HomeParams), read, EmptyBody => TagHomeParams, 16;
+    SetHomeParams = Id::Home(HomeIDs::HomeParams), write + queue, TagHomeParams => EmptyBody, 0;
+    SetHomeCmd = Id::Home(HomeIDs::HomeCmd), write + queue, TagHomeCmd => EmptyBody, 0;
+    /// Result of the last auto-leveling run.
+    GetAutoLeveling = Id::Home(HomeIDs::AutoLeveling), read, EmptyBody => FixedResponse<4>, 4;
+    SetAutoLeveling = Id::Home(HomeIDs::AutoLeveling), write + queue, TagAutoLevelingParams => EmptyBody, 0;
+
+    // HHT
+    GetHhtTrigMode = Id::HHT(HHTIDs::HHTTrigMode), read, EmptyBody => FixedResponse<1>, 1;
+    SetHhtTrigMode = Id::HHT(HHTIDs::HHTTrigMode), write, HHTTrigMode => EmptyBody, 0;
+    GetHhtTrigOutputEnabled = Id::HHT(HHTIDs::HHTTrigOutputEnabled), read, EmptyBody => FixedResponse<1>, 1;
+    SetHhtTrigOutputEnabled = Id::HHT(HHTIDs::HHTTrigOutputEnabled), write, TagEnabled => EmptyBody, 0;
+    GetHhtTrigOutput = Id::HHT(HHTIDs::HHTTrigOutput), read, EmptyBody => FixedResponse<1>, 1;
+
+    // ARM ORIENTATION
+    GetArmOrientation = Id::ArmOrientation(ArmOrientationIDs::ArmOrientation), read, EmptyBody => FixedResponse<1>, 1;
+    SetArmOrientation = Id::ArmOrientation(ArmOrientationIDs::ArmOrientation), write + queue, ArmOrientation => EmptyBody, 0;
+
+    // END EFFECTOR
+    GetEndEffectorParams = Id::EndEffector(EndEffectorIDs::Params), read, EmptyBody => TagEndEffectorParams, 12;
+    SetEndEffectorParams = Id::EndEffector(EndEffectorIDs::Params), write + queue, TagEndEffectorParams => EmptyBody, 0;
+    GetEndEffectorLaser = Id::EndEffector(EndEffectorIDs::Laser), read, EmptyBody => FixedResponse<2>, 2;
+    SetEndEffectorLaser = Id::EndEffector(EndEffectorIDs::Laser), write + queue, TagEndEffectorState => EmptyBody, 0;
+    GetEndEffectorSuctionCup = Id::EndEffector(EndEffectorIDs::SuctionCup), read, EmptyBody => FixedResponse<2>, 2;
+    SetEndEffectorSuctionCup = Id::EndEffector(EndEffectorIDs::SuctionCup), write + queue, TagEndEffectorState => EmptyBody, 0;
+    GetEndEffectorGripper = Id::EndEffector(EndEffectorIDs::Gripper), read, EmptyBody => FixedResponse<2>, 2;
+    SetEndEffectorGripper = Id::EndEffector(EndEffectorIDs::Gripper), write + queue, TagEndEffectorState => EmptyBody, 0;
+
+    // JOG
+    GetJogJointParams = Id::Jog(JogIDs::JointParams), read, EmptyBody => TagJOGJointParams, 32;
+    SetJogJointParams = Id::Jog(JogIDs::JointParams), write + queue, TagJOGJointParams => EmptyBody, 0;
+    GetJogCoordinateParams = Id::Jog(JogIDs::CoordinateParams), read, EmptyBody => TagJOGCoordinateParams, 32;
+    SetJogCoordinateParams = Id::Jog(JogIDs::CoordinateParams), write + queue, TagJOGCoordinateParams => EmptyBody, 0;
+    GetJogCommonParams = Id::Jog(JogIDs::CommonParams), read, EmptyBody => TagJOGCommonParams, 8;
+    SetJogCommonParams = Id::Jog(JogIDs::CommonParams), write + queue, TagJOGCommonParams => EmptyBody, 0;
+    SetJogCmd = Id::Jog(JogIDs::Cmd), write + queue, TagJOGCmd => EmptyBody, 0;
+    GetJogLParams = Id::Jog(JogIDs::LParams), read, EmptyBody => TagJOGLParams, 8;
+    SetJogLParams = Id::Jog(JogIDs::LParams), write + queue, TagJOGLParams => EmptyBody, 0;
+
+    // PTP
+    GetPtpJointParams = Id::Ptp(PtpIDs::JointParams), read, EmptyBody => TagPTPJointParams, 32;
+    SetPtpJointParams = Id::Ptp(PtpIDs::JointParams), write + queue, TagPTPJointParams => EmptyBody, 0;
+    GetPtpCoordinateParams = Id::Ptp(PtpIDs::CoordinateParams), read, EmptyBody => TagPTPCoordinateParams, 16;
+    SetPtpCoordinateParams = Id::Ptp(PtpIDs::CoordinateParams), write + queue, TagPTPCoordinateParams => EmptyBody, 0;
+    GetPtpJumpParams = Id::Ptp(PtpIDs::JumpParams), read, EmptyBody => TagPTPJumpParams, 8;
+    SetPtpJumpParams = Id::Ptp(PtpIDs::JumpParams), write + queue, TagPTPJumpParams => EmptyBody, 0;
+    GetPtpCommonParams = Id::Ptp(PtpIDs::CommonParams), read, EmptyBody => TagPTPCommonParams, 8;
+    SetPtpCommonParams = Id::Ptp(PtpIDs::CommonParams), write + queue, TagPTPCommonParams => EmptyBody, 0;
+    SetPtpCmd = Id::Ptp(PtpIDs::Cmd), write + queue, TagPTPCmd => EmptyBody, 0;
+    GetPtpLParams = Id::Ptp(PtpIDs::LParams), read, EmptyBody => TagPTPLParams, 8;
+    SetPtpLParams = Id::Ptp(PtpIDs::LParams), write + queue, TagPTPLParams => EmptyBody, 0;
+    SetPtpWithLCmd = Id::Ptp(PtpIDs::WithLCmd), write + queue, TagPTPWithLCmd => EmptyBody, 0;
+    GetPtpJump2Params = Id::Ptp(PtpIDs::JumpToParams), read, EmptyBody => TagPTPJump2Params, 12;
+    SetPtpJump2Params = Id::Ptp(PtpIDs::JumpToParams), write + queue, TagPTPJump2Params => EmptyBody, 0;
+    /// A PTP motion followed by a list of output changes, serialized by the caller.
+    SetPtpPoCmd = Id::Ptp(PtpIDs::PoCmd), write + queue, GeneralRequest<'a> => EmptyBody, 0;
+    SetPtpPoWithLCmd = Id::Ptp(PtpIDs::PoWithLCmd), write + queue, GeneralRequest<'a> => EmptyBody, 0;
+
+    // CP
+    GetCpParams = Id::Cp(CpIDs::CpParams), read, EmptyBody => TagCPParams, 13;
+    SetCpParams = Id::Cp(CpIDs::CpParams), write + queue, TagCPParams => EmptyBody, 0;
+    SetCpCmd = Id::Cp(CpIDs::CpCmd), write + queue, TagCPCmd => EmptyBody, 0;
+    SetCpleCmd = Id::Cp(CpIDs::CpleCmd), write + queue, TagCPCmd => EmptyBody, 0;
+
+    // ARC
+    GetArcParams = Id::Arc(ArcIDs::ArcParams), read, EmptyBody => TagARCParams, 16;
+    SetArcParams = Id::Arc(ArcIDs::ArcParams), write + queue, TagARCParams => EmptyBody, 0;
+    SetArcCmd = Id::Arc(ArcIDs::ArcCmd), write + queue, TagARCCmd => EmptyBody, 0;
+
+    // WAIT
+    SetWaitCmd = Id::Wait(WaitIDs::WaitCmd), write + queue, TagWAITCmd => EmptyBody, 0;
+
+    // TRIG
+    SetTrigCmd = Id::Trig(TrigIDs::TrigCmd), write + queue, TagTRIGCmd => EmptyBody, 0;
+
+    // EIO
+    GetIoMultiplexing = Id::Eio(EioIDs::IoMultiplexing), read, TagIOAddress => TagIOMultiplexing, 2;
+    SetIoMultiplexing = Id::Eio(EioIDs::IoMultiplexing), write + queue, TagIOMultiplexing => EmptyBody, 0;
+    GetIoDo = Id::Eio(EioIDs::Iodo), read, TagIOAddress => FixedResponse<2>, 2;
+    SetIoDo = Id::Eio(EioIDs::Iodo), write + queue, TagIODO => EmptyBody, 0;
+    GetIoPwm = Id::Eio(EioIDs::IoPwm), read, TagIOAddress => TagIOPWM, 9;
+    SetIoPwm = Id::Eio(EioIDs::IoPwm), write + queue, TagIOPWM => EmptyBody, 0;
+    GetIoDi = Id::Eio(EioIDs::Iodi), read, TagIOAddress => FixedResponse<2>, 2;
+    GetIoAdc = Id::Eio(EioIDs::IoAdc), read, TagIOAddress => FixedResponse<3>, 3;
+    SetEMotor = Id::Eio(EioIDs::Emotor), write + queue, TagEMotor => EmptyBody, 0;
+    SetEMotors = Id::Eio(EioIDs::Emotors), write + queue, TagEMotorS => EmptyBody, 0;
+    GetColorSensor = Id::Eio(EioIDs::ColorSensor), read, TagIOAddress => TagColor, 3;
+    SetColorSensor = Id::Eio(EioIDs::ColorSensor), write + queue, TagDevice => EmptyBody, 0;
+    GetIrSwitch = Id::Eio(EioIDs::IrSwitch), read, TagIOAddress => FixedResponse<1>, 1;
+    SetIrSwitch = Id::Eio(EioIDs::IrSwitch), write + queue, TagDevice => EmptyBody, 0;
+
+    // CAL
+    GetAngleSensorStaticError = Id::Cal(CalIDs::AngleSensorStaticError), read, EmptyBody => FixedResponse<8>, 8;
+    SetAngleSensorStaticError = Id::Cal(CalIDs::AngleSensorStaticError), write, TagAngleSensorParams => EmptyBody, 0;
+    GetAngleSensorCoef = Id::Cal(CalIDs::AngleSensorCoef), read, EmptyBody => FixedResponse<8>, 8;
+    SetAngleSensorCoef = Id::Cal(CalIDs::AngleSensorCoef), write, TagAngleSensorParams => EmptyBody, 0;
+    GetBaseDecoderStaticError = Id::Cal(CalIDs::BaseDecoderStaticError), read, EmptyBody => FixedResponse<4>, 4;
+    SetBaseDecoderStaticError = Id::Cal(CalIDs::BaseDecoderStaticError), write, TagCalibrationValue => EmptyBody, 0;
+    GetRhandCalibrateValue = Id::Cal(CalIDs::RhandCalibrateValue), read, EmptyBody => FixedResponse<4>, 4;
+    SetRhandCalibrateValue = Id::Cal(CalIDs::RhandCalibrateValue), write, TagCalibrationValue => EmptyBody, 0;
+
+    // WIFI
+    GetWifiConfigMode = Id::Wifi(WifiIDs::ConfigMode), read, EmptyBody => FixedResponse<1>, 1;
+    SetWifiConfigMode = Id::Wifi(WifiIDs::ConfigMode), write, TagEnabled => EmptyBody, 0;
+    GetWifiSsid = Id::Wifi(WifiIDs::Ssid), read, EmptyBody => FixedResponse<MAX_STRING_SIZE>, MAX_STRING_SIZE;
+    SetWifiSsid = Id::Wifi(WifiIDs::Ssid), write, GeneralRequest<'a> => EmptyBody, 0;
+    GetWifiPassword = Id::Wifi(WifiIDs::Password), read, EmptyBody => FixedResponse<MAX_STRING_SIZE>, MAX_STRING_SIZE;
+    SetWifiPassword = Id::Wifi(WifiIDs::Password), write, GeneralRequest<'a> => EmptyBody, 0;
+    GetWifiIpAddress = Id::Wifi(WifiIDs::IpAddress), read, EmptyBody => TagWIFIIPAddress, 5;
+    SetWifiIpAddress = Id::Wifi(WifiIDs::IpAddress), write, TagWIFIIPAddress => EmptyBody, 0;
+    GetWifiNetmask = Id::Wifi(WifiIDs::Netmask), read, EmptyBody => TagWIFINetmask, 4;
+    SetWifiNetmask = Id::Wifi(WifiIDs::Netmask), write, TagWIFINetmask => EmptyBody, 0;
+    GetWifiGateway = Id::Wifi(WifiIDs::Gateway), read, EmptyBody => TagWIFIGateway, 4;
+    SetWifiGateway = Id::Wifi(WifiIDs::Gateway), write, TagWIFIGateway => EmptyBody, 0;
+    GetWifiDns = Id::Wifi(WifiIDs::Dns), read, EmptyBody => TagWIFIDNS, 4;
+    SetWifiDns = Id::Wifi(WifiIDs::Dns), write, TagWIFIDNS => EmptyBody, 0;
+    GetWifiConnectStatus = Id::Wifi(WifiIDs::ConnectStatus), read, EmptyBody => FixedResponse<1>, 1;
+
+    // LOST STEP
+    SetLostStepParams = Id::LostStep(LostStepIDs::SetLostStepParams), write, TagLostStepParams => EmptyBody, 0;
+    SetLostStepCmd = Id::LostStep(LostStepIDs::SetLostStepCmd), write + queue, EmptyBody => EmptyBody, 0;
+
+    // CHECK MODEL
+    GetCheckModel = Id::CheckModel(CheckModelIDs::CheckModel), read, EmptyBody => FixedResponse<1>, 1;
+    /// The payload layout is not documented, so it is passed through as is.
+    SetCheckModel = Id::CheckModel(CheckModelIDs::CheckModel), write, GeneralRequest<'a> => EmptyBody, 0;
+
+    // PULSE MODE
+    /// The payload layout is not documented, so it is passed through as is.
+    SetPulseMode = Id::PulseMode(PulseModeIDs::PulseMode), write + queue, GeneralRequest<'a> => EmptyBody, 0;
+
+    // TEST MODE
+    /// Eight user-defined floating point values.
+    GetTestUserParams = Id::Test(TestIDs::TestUserParams), read, EmptyBody => FixedResponse<32>, 32;
+    /// Expected duration of the PTP motion given in the request, in milliseconds.
+    GetTestPtpTime = Id::Test(TestIDs::TestPtpTime), read, TagPTPCmd => FixedResponse<4>, 4;
+
+    // QUEUED CMD
+    SetQueuedCmdStartExec = Id::QueuedCmd(QueuedCmdIDs::StartExec), write, EmptyBody => EmptyBody, 0;
+    SetQueuedCmdStopExec = Id::QueuedCmd(QueuedCmdIDs::StopExec), write, EmptyBody => EmptyBody, 0;
+    SetQueuedCmdForceStopExec = Id::QueuedCmd(QueuedCmdIDs::ForceStopExec), write, EmptyBody => EmptyBody, 0;
+    SetQueuedCmdStartDownload = Id::QueuedCmd(QueuedCmdIDs::StartDownload), write, TagQueuedCmdDownload => EmptyBody, 0;
+    SetQueuedCmdStopDownload = Id::QueuedCmd(QueuedCmdIDs::StopDownload), write, EmptyBody => EmptyBody, 0;
+    SetQueuedCmdClear = Id::QueuedCmd(QueuedCmdIDs::Clear), write, EmptyBody => EmptyBody, 0;
+    GetQueuedCmdCurrentIndex = Id::QueuedCmd(QueuedCmdIDs::CurrentIndex), read, EmptyBody => FixedResponse<8>, 8;
+    /// Number of free slots in the command queue.
+    GetQueuedCmdLeftSpace = Id::QueuedCmd(QueuedCmdIDs::LeftSpace), read, EmptyBody => FixedResponse<4>, 4;
+    GetQueuedCmdFinish = Id::QueuedCmd(QueuedCmdIDs::Finish), read, EmptyBody => FixedResponse<1>, 1;
+}
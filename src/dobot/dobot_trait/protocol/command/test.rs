@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::dobot_trait::protocol::{
+        CommunicationProtocolIDs,
+        command::{
+            COMMANDS, Command, GetPtpCommonParams, MAX_STRING_SIZE, Queueable, SetPtpCmd,
+            find_command,
+        },
+        command_id::PtpIDs,
+    };
+
+    fn assert_queueable<C: Queueable>() {}
+
+    #[test]
+    fn test_every_id_has_a_command() {
+        for value in 0..=u8::MAX {
+            let Ok(id) = CommunicationProtocolIDs::try_from(value) else {
+                continue;
+            };
+            assert!(
+                COMMANDS.iter().any(|descriptor| descriptor.id == id),
+                "no command for ID {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_command_id_round_trips() {
+        for descriptor in COMMANDS {
+            let value: u8 = descriptor.id.into();
+            assert_eq!(
+                CommunicationProtocolIDs::try_from(value),
+                Ok(descriptor.id),
+                "{}",
+                descriptor.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_commands_are_unique() {
+        for (index, descriptor) in COMMANDS.iter().enumerate() {
+            let duplicate = COMMANDS[index + 1..]
+                .iter()
+                .find(|other| other.id == descriptor.id && other.is_write == descriptor.is_write);
+            assert!(
+                duplicate.is_none(),
+                "{} and {} share ID and direction",
+                descriptor.name,
+                duplicate.unwrap().name
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_response_fits_its_max_size() {
+        let zeros = [0u8; MAX_STRING_SIZE];
+        for descriptor in COMMANDS {
+            let params = &zeros[..descriptor.max_response_size];
+            assert!(
+                (descriptor.parse_response)(params).is_ok(),
+                "{} cannot decode a {} byte response",
+                descriptor.name,
+                params.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_writes_have_no_response_and_reads_are_not_queueable() {
+        for descriptor in COMMANDS {
+            if descriptor.is_writable() {
+                assert_eq!(descriptor.max_response_size, 0, "{}", descriptor.name);
+            } else {
+                assert!(!descriptor.queueable, "{}", descriptor.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_descriptor_matches_command() {
+        assert_eq!(
+            GetPtpCommonParams::ID,
+            CommunicationProtocolIDs::Ptp(PtpIDs::CommonParams)
+        );
+        assert_eq!(GetPtpCommonParams::MAX_RESPONSE_SIZE, 8);
+        assert_queueable::<SetPtpCmd>();
+
+        let descriptor = find_command(CommunicationProtocolIDs::Ptp(PtpIDs::Cmd), true).unwrap();
+        assert_eq!(descriptor.name, "SetPtpCmd");
+        assert!(descriptor.queueable);
+        assert!(find_command(CommunicationProtocolIDs::Ptp(PtpIDs::Cmd), false).is_none());
+    }
+}
@@ -1,13 +1,27 @@
 pub mod alarm;
 pub mod bodies;
 mod body;
+mod body_field;
+pub mod command;
 pub mod command_id;
 mod protocol_error;
+mod test;
 pub use body::Body;
+pub use body_field::BodyField;
+pub use dobot_api_rs_derive::Body;
 pub use command_id::CommunicationProtocolIDs;
 use core::marker::PhantomData;
 pub use protocol_error::ProtocolError;
 
+/// Largest value of the length byte: the ID, the control byte and the body.
+pub const MAX_CONTENT_LENGTH: usize = u8::MAX as usize;
+/// Largest body that fits in a single packet.
+pub const MAX_BODY_SIZE: usize = MAX_CONTENT_LENGTH - 2;
+/// Largest packet on the wire: start bytes, length, content and checksum.
+pub const MAX_PACKET_SIZE: usize = 2 + 1 + MAX_CONTENT_LENGTH + 1;
+/// Offset of the command ID within an encoded packet.
+pub const COMMAND_ID_OFFSET: usize = 3;
+
 pub struct Protocol<'a, T: Body<'a>> {
     pub command_id: CommunicationProtocolIDs,
     pub is_queued: bool,
@@ -43,10 +57,11 @@ impl<'a, T: Body<'a>> Protocol<'a, T> {
 
     pub fn to_packet(&self, buffer: &mut [u8]) -> Result<usize, ProtocolError> {
         let body_size = self.body.size();
+        if body_size > MAX_BODY_SIZE {
+            return Err(ProtocolError::BodyTooLarge(body_size));
+        }
 
-        let body_and_queued_index_size = body_size;
-
-        let content_length = 1 + 1 + body_and_queued_index_size;
+        let content_length = 1 + 1 + body_size;
         let total_packet_size = 2 + 1 + content_length + 1;
 
         if buffer.len() < total_packet_size {
@@ -70,8 +85,14 @@ impl<'a, T: Body<'a>> Protocol<'a, T> {
         buffer[index] = ctrl_byte;
         index += 1;
 
-        // Serialize the body directly into the buffer.
-        let bytes_written = self.body.serialize(&mut buffer[index..])?;
+        // Serialize the body into exactly the space announced by the length byte.
+        let bytes_written = self.body.serialize(&mut buffer[index..index + body_size])?;
+        if bytes_written != body_size {
+            return Err(ProtocolError::BodySizeMismatch {
+                expected: body_size,
+                written: bytes_written,
+            });
+        }
         index += bytes_written;
 
         // Calculate the checksum for the payload (length, id, ctrl, body).
@@ -93,6 +114,9 @@ impl<'a, T: Body<'a>> Protocol<'a, T> {
         }
 
         let content_length = packet[2] as usize;
+        if content_length < 2 {
+            return Err(ProtocolError::ContentTooShort(packet[2]));
+        }
         let total_packet_size = 2 + 1 + content_length + 1;
 
         if packet.len() < total_packet_size {
@@ -10,8 +10,16 @@ pub enum ProtocolError {
     MissingStartBytes,
     #[error("The packet size does not match the length field")]
     LengthMismatch,
+    #[error("The length field {0} is too short to hold the command ID and control byte")]
+    ContentTooShort(u8),
+    #[error("A body of {0} bytes does not fit in a single packet")]
+    BodyTooLarge(usize),
+    #[error("The body reported a size of {expected} bytes but wrote {written}")]
+    BodySizeMismatch { expected: usize, written: usize },
     #[error("The packet checksum is incorrect")]
     ChecksumError,
+    #[error("Expected a reply to command {expected} but received one to command {received}")]
+    UnexpectedResponse { expected: u8, received: u8 },
     #[error("Invalid command ID received: {0}")]
     InvalidCommandID(u8),
     #[error("Invalid Value in Body")]
@@ -1,108 +1,26 @@
-use super::{
-    Body, CommunicationProtocolIDs, Protocol, define_protocol_group,
-    implement_try_from_for_protocol_group, protocol_error::ProtocolError,
-};
-use core::convert::TryFrom;
-use paste::paste;
-
-// This macro simplifies the creation of structs that implement the `Body` trait.
-// It automatically generates the `size()`, `serialize()`, and `deserialize()`
-// methods for a given struct and its fields, using little-endian byte order.
-//
-// Example usage:
-// implement_body! {
-//     pub struct MyBody {
-//         pub my_u8: u8,
-//         pub my_u32: u32,
-//     }
-// }
-macro_rules! implement_body {
-    ($($visibility:vis struct $name:ident {
-        $(
-            $field_visibility:vis $field_name:ident: $field_type:ty,
-        )*
-    })*) => {
-        $(
-            #[derive(Debug, PartialEq, Eq)]
-            $visibility struct $name {
-                $(
-                    $field_visibility $field_name: $field_type,
-                )*
-            }
-
-            impl Body for $name {
-                fn size(&self) -> usize {
-                    let mut size = 0;
-                    $(
-                        size += core::mem::size_of::<$field_type>();
-                    )*
-                    size
-                }
-
-                fn serialize(&self, buffer: &mut [u8]) -> Result<usize, ProtocolError> {
-                    let mut index = 0;
-                    $(
-                        let field_size = core::mem::size_of::<$field_type>();
-                        if index + field_size > buffer.len() {
-                            return Err(ProtocolError::BufferTooSmall);
-                        }
-                        // Use little-endian byte order for serialization
-                        buffer[index..index + field_size].copy_from_slice(&self.$field_name.to_le_bytes());
-                        index += field_size;
-                    )*
-                    Ok(index)
-                }
-
-                fn deserialize(buffer: &[u8]) -> Result<Self, ProtocolError> {
-                    let mut index = 0;
-                    $(
-                        let field_size = core::mem::size_of::<$field_type>();
-                        if index + field_size > buffer.len() {
-                            return Err(ProtocolError::BufferTooSmall); // Can be a more specific error
-                        }
-                        let mut bytes = [0u8; core::mem::size_of::<$field_type>()];
-                        bytes.copy_from_slice(&buffer[index..index + field_size]);
-                        let $field_name = <$field_type>::from_le_bytes(bytes);
-                        index += field_size;
-                    )*
-                    Ok(Self {
-                        $(
-                            $field_name,
-                        )*
-                    })
-                }
-            }
-        )*
-    };
-}
-
-// Define a simple Body for testing purposes using the new macro.
-implement_body! {
-    pub struct TestBody {
-        pub data: u32,
-        pub status: u8,
-    }
-}
-
-// Define a specific command group for testing.
-define_protocol_group! {
-    TestIDs, 250, {
-        TestCmd = 0,
-        MacroTest = 1,
-    }
-}
-
-// Implement TryFrom for the test command group.
-implement_try_from_for_protocol_group!(TestIDs);
-
 // The `tests` module contains all the unit tests for the protocol.
 #[cfg(test)]
 mod tests {
+    use super::super::{
+        Body, CommunicationProtocolIDs, MAX_BODY_SIZE, MAX_CONTENT_LENGTH, MAX_PACKET_SIZE,
+        Protocol,
+        bodies::{general_request::GeneralRequest, general_response::GeneralResponse},
+        command::COMMANDS,
+        command_id::DevicePoseIDs,
+        protocol_error::ProtocolError,
+    };
+    use core::convert::TryFrom;
+
+    #[derive(Debug, PartialEq, Eq, Body)]
+    struct TestBody {
+        data: u32,
+        status: u8,
+    }
 
     // A test to ensure that a packet can be created and then deserialized correctly.
     #[test]
     fn test_to_packet_from_packet_success() {
-        let command_id = CommunicationProtocolIDs::Test(TestIDs::TestCmd);
+        let command_id = CommunicationProtocolIDs::DevicePose(DevicePoseIDs::GetPose);
         let is_queued = false;
         let is_read = false;
         let body = TestBody {
@@ -126,7 +44,7 @@ mod tests {
     // A test for the `to_packet` method when the buffer is too small.
     #[test]
     fn test_to_packet_buffer_too_small() {
-        let command_id = CommunicationProtocolIDs::Test(TestIDs::TestCmd);
+        let command_id = CommunicationProtocolIDs::DevicePose(DevicePoseIDs::GetPose);
         let body = TestBody {
             data: 0x12345678,
             status: 0xAB,
@@ -137,7 +55,7 @@ mod tests {
 
         let result = protocol.to_packet(&mut buffer);
 
-        assert_eq!(result, Err(ProtocolError::BufferTooSmall));
+        assert_eq!(result.err(), Some(ProtocolError::BufferTooSmall));
     }
 
     // A test for the `from_packet` method when the start bytes are missing.
@@ -147,7 +65,7 @@ mod tests {
             0xAB,
             0xAA,
             0x07, // Invalid start byte
-            CommunicationProtocolIDs::Test(TestIDs::TestCmd).into(),
+            CommunicationProtocolIDs::DevicePose(DevicePoseIDs::GetPose).into(),
             0x00,
             0x78,
             0x56,
@@ -158,7 +76,7 @@ mod tests {
         ];
 
         let result = Protocol::<TestBody>::from_packet(&packet);
-        assert_eq!(result, Err(ProtocolError::MissingStartBytes));
+        assert_eq!(result.err(), Some(ProtocolError::MissingStartBytes));
     }
 
     // A test for the `from_packet` method when the checksum is incorrect.
@@ -168,7 +86,7 @@ mod tests {
             0xAA,
             0xAA,
             0x07,
-            CommunicationProtocolIDs::Test(TestIDs::TestCmd).into(),
+            CommunicationProtocolIDs::DevicePose(DevicePoseIDs::GetPose).into(),
             0x00,
             0x78,
             0x56,
@@ -179,7 +97,7 @@ mod tests {
         ];
 
         let result = Protocol::<TestBody>::from_packet(&packet);
-        assert_eq!(result, Err(ProtocolError::ChecksumError));
+        assert_eq!(result.err(), Some(ProtocolError::ChecksumError));
     }
 
     // A test for the `from_packet` method when the buffer is too small to read the entire packet.
@@ -189,7 +107,7 @@ mod tests {
             0xAA,
             0xAA,
             0x07, // Length field says 7 bytes of content
-            CommunicationProtocolIDs::Test(TestIDs::TestCmd).into(),
+            CommunicationProtocolIDs::DevicePose(DevicePoseIDs::GetPose).into(),
             0x00,
             0x78,
             0x56,
@@ -200,23 +118,23 @@ mod tests {
         ];
 
         let result = Protocol::<TestBody>::from_packet(&packet);
-        assert_eq!(result, Err(ProtocolError::LengthMismatch));
+        assert_eq!(result.err(), Some(ProtocolError::LengthMismatch));
     }
 
     // Test the conversion from a u8 to a CommunicationProtocolIDs enum.
     #[test]
     fn test_command_id_conversion() {
         let raw_id = 10; // Corresponds to `DevicePoseIDs::GetPose`
-        let expected_id = CommunicationProtocolIDs::DevicePose(super::DevicePoseIDs::GetPose);
+        let expected_id = CommunicationProtocolIDs::DevicePose(DevicePoseIDs::GetPose);
 
         let converted_id = CommunicationProtocolIDs::try_from(raw_id);
 
         assert_eq!(converted_id, Ok(expected_id));
     }
 
-    // Test the macro for creating a Body implementation.
+    // Test the derived Body implementation.
     #[test]
-    fn test_macro_generated_body() {
+    fn test_derived_body() {
         let original_body = TestBody {
             data: 0xDEADBEEF,
             status: 0xFF,
@@ -229,4 +147,143 @@ mod tests {
         assert_eq!(original_body, deserialized_body);
         assert_eq!(size, 5); // u32 (4 bytes) + u8 (1 byte)
     }
+
+    /// Builds a frame with a correct checksum around `content`.
+    fn frame(content: &[u8], buffer: &mut [u8]) -> usize {
+        buffer[0] = 0xAA;
+        buffer[1] = 0xAA;
+        buffer[2] = content.len() as u8;
+        buffer[3..3 + content.len()].copy_from_slice(content);
+        buffer[3 + content.len()] =
+            Protocol::<TestBody>::calculate_checksum(&buffer[2..3 + content.len()]);
+        4 + content.len()
+    }
+
+    // Length bytes of 0 and 1 cannot hold the ID and control byte.
+    #[test]
+    fn test_from_packet_content_too_short() {
+        let mut buffer = [0u8; 8];
+
+        let len = frame(&[], &mut buffer);
+        let result = Protocol::<GeneralResponse>::from_packet(&buffer[..len]);
+        assert_eq!(result.err(), Some(ProtocolError::ContentTooShort(0)));
+
+        let len = frame(&[10], &mut buffer);
+        let result = Protocol::<GeneralResponse>::from_packet(&buffer[..len]);
+        assert_eq!(result.err(), Some(ProtocolError::ContentTooShort(1)));
+    }
+
+    // The largest body fills the length byte exactly; one more byte is rejected.
+    #[test]
+    fn test_to_packet_body_size_limits() {
+        let params = [0x5Au8; MAX_BODY_SIZE + 1];
+        let mut buffer = [0u8; MAX_PACKET_SIZE + 1];
+        let id = CommunicationProtocolIDs::DevicePose(DevicePoseIDs::GetPose);
+
+        let largest = Protocol::new(
+            id,
+            false,
+            true,
+            GeneralRequest {
+                params: &params[..MAX_BODY_SIZE],
+            },
+        );
+        assert_eq!(largest.to_packet(&mut buffer), Ok(MAX_PACKET_SIZE));
+        assert_eq!(buffer[2], 0xFF);
+        let parsed = Protocol::<GeneralResponse>::from_packet(&buffer[..MAX_PACKET_SIZE]).unwrap();
+        assert_eq!(parsed.body.params, &params[..MAX_BODY_SIZE]);
+
+        let too_large = Protocol::new(id, false, true, GeneralRequest { params: &params });
+        assert_eq!(
+            too_large.to_packet(&mut buffer),
+            Err(ProtocolError::BodyTooLarge(MAX_BODY_SIZE + 1))
+        );
+    }
+
+    /// Reports one size but writes fewer bytes.
+    struct ShortBody;
+
+    impl Body<'_> for ShortBody {
+        fn size(&self) -> usize {
+            4
+        }
+
+        fn serialize(&self, _buffer: &mut [u8]) -> Result<usize, ProtocolError> {
+            Ok(2)
+        }
+
+        fn deserialize(_buffer: &[u8]) -> Result<Self, ProtocolError> {
+            Ok(ShortBody)
+        }
+    }
+
+    #[test]
+    fn test_to_packet_body_size_mismatch() {
+        let protocol = Protocol::new(
+            CommunicationProtocolIDs::DevicePose(DevicePoseIDs::GetPose),
+            false,
+            false,
+            ShortBody,
+        );
+        let mut buffer = [0u8; 16];
+
+        assert_eq!(
+            protocol.to_packet(&mut buffer),
+            Err(ProtocolError::BodySizeMismatch {
+                expected: 4,
+                written: 2
+            })
+        );
+    }
+
+    /// Small xorshift generator so the malformed-frame tests are repeatable.
+    fn next(state: &mut u32) -> u32 {
+        *state ^= *state << 13;
+        *state ^= *state >> 17;
+        *state ^= *state << 5;
+        *state
+    }
+
+    // Random bytes, truncated frames and well-formed frames with random
+    // contents must all come back as values or errors, never as panics.
+    #[test]
+    fn test_from_packet_never_panics() {
+        let mut state = 0x2545_F491;
+        let mut noise = [0u8; 300];
+        let mut buffer = [0u8; MAX_PACKET_SIZE];
+
+        for round in 0..2000 {
+            for byte in noise.iter_mut() {
+                *byte = next(&mut state) as u8;
+            }
+            let len = next(&mut state) as usize % noise.len();
+            let _ = Protocol::<GeneralResponse>::from_packet(&noise[..len]);
+            let _ = Protocol::<TestBody>::from_packet(&noise[..len]);
+
+            let content_len = round % (MAX_CONTENT_LENGTH + 1);
+            let framed = frame(&noise[..content_len], &mut buffer);
+            for cut in [framed, framed.saturating_sub(1), 5, 4, 3] {
+                let _ = Protocol::<GeneralResponse>::from_packet(&buffer[..cut.min(framed)]);
+                let _ = Protocol::<TestBody>::from_packet(&buffer[..cut.min(framed)]);
+            }
+        }
+    }
+
+    // Every response body accepts or rejects arbitrary parameters without panicking.
+    #[test]
+    fn test_response_bodies_never_panic() {
+        let mut state = 0x1357_9BDF;
+        let mut noise = [0u8; 80];
+
+        for _ in 0..500 {
+            for byte in noise.iter_mut() {
+                *byte = next(&mut state) as u8;
+            }
+            for len in 0..=noise.len() {
+                for descriptor in COMMANDS {
+                    let _ = (descriptor.parse_response)(&noise[..len]);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,53 @@
+#[cfg(not(feature = "std"))]
+extern crate spin;
+
+#[cfg(feature = "std")]
+mod priority;
+#[cfg(feature = "std")]
+mod test;
+
+/// How urgently a caller needs the arm when several wait for it.
+///
+/// With `std`, a caller waiting with a higher priority is let in before every
+/// waiting caller with a lower one. Callers of the same priority are let in
+/// in no particular order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// Status polling and telemetry.
+    Background,
+    /// Regular commands, including bulk motion uploads.
+    #[default]
+    Normal,
+    /// Commands an operator is waiting on, such as JOG.
+    Interactive,
+    /// Commands that make the arm safe: stopping and clearing the queue and
+    /// switching end effectors off.
+    Emergency,
+}
+
+#[cfg(not(feature = "std"))]
+pub type RwLock<T> = spin::RwLock<T>;
+
+#[cfg(feature = "std")]
+pub use priority::{RwLock, RwLockWriteGuard};
+
+/// Locks `lock` for writing on behalf of a caller with `priority`.
+#[cfg(feature = "std")]
+pub fn write_with_priority<T>(
+    lock: &RwLock<T>,
+    priority: Priority,
+) -> std::sync::LockResult<RwLockWriteGuard<'_, T>> {
+    lock.write_with_priority(priority)
+}
+
+/// Locks `lock` for writing; without `std` every caller has the same priority.
+#[cfg(not(feature = "std"))]
+pub fn write_with_priority<T>(
+    lock: &RwLock<T>,
+    _priority: Priority,
+) -> Result<
+    spin::RwLockWriteGuard<'_, T>,
+    crate::dobot::dobot_trait::dobot_core::dobot_error::DobotError,
+> {
+    Ok(lock.write())
+}
@@ -0,0 +1,171 @@
+use core::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+use std::sync::{self, Condvar, LockResult, Mutex, MutexGuard, PoisonError, RwLockReadGuard};
+
+use super::Priority;
+
+const CLASSES: usize = Priority::Emergency as usize + 1;
+
+#[derive(Default)]
+struct GateState {
+    held: bool,
+    waiting: [usize; CLASSES],
+}
+
+impl GateState {
+    fn waiting_above(&self, priority: Priority) -> bool {
+        self.waiting[priority as usize + 1..]
+            .iter()
+            .any(|&count| count > 0)
+    }
+}
+
+/// Decides which waiting writer enters next.
+#[derive(Default)]
+struct Gate {
+    state: Mutex<GateState>,
+    released: Condvar,
+}
+
+impl Gate {
+    const fn new() -> Self {
+        Self {
+            state: Mutex::new(GateState {
+                held: false,
+                waiting: [0; CLASSES],
+            }),
+            released: Condvar::new(),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, GateState> {
+        // The state is only counters that are never left half-updated.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn enter(&self, priority: Priority) {
+        let mut state = self.state();
+        state.waiting[priority as usize] += 1;
+        while state.held || state.waiting_above(priority) {
+            state = self
+                .released
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        state.waiting[priority as usize] -= 1;
+        state.held = true;
+    }
+
+    fn leave(&self) {
+        self.state().held = false;
+        self.released.notify_all();
+    }
+}
+
+/// A reader-writer lock whose writers are let in by [`Priority`].
+///
+/// It has the interface of [`std::sync::RwLock`], where [`RwLock::write`]
+/// waits with [`Priority::Normal`]. A writer waits for at most the writer
+/// currently holding the lock, as long as no writer of a higher priority
+/// arrives in the meantime. Writers that hold the lock for long, such as a
+/// pipelined upload, can check [`RwLock::has_waiters_above`] to step aside.
+#[derive(Default)]
+pub struct RwLock<T: ?Sized> {
+    gate: Gate,
+    inner: sync::RwLock<T>,
+}
+
+impl<T> RwLock<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            gate: Gate::new(),
+            inner: sync::RwLock::new(value),
+        }
+    }
+
+    pub fn into_inner(self) -> LockResult<T> {
+        self.inner.into_inner()
+    }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    pub fn read(&self) -> LockResult<RwLockReadGuard<'_, T>> {
+        self.inner.read()
+    }
+
+    pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
+        self.write_with_priority(Priority::Normal)
+    }
+
+    /// Waits until every writer of a higher priority was let in and the lock
+    /// is free, then locks it for writing.
+    pub fn write_with_priority(&self, priority: Priority) -> LockResult<RwLockWriteGuard<'_, T>> {
+        self.gate.enter(priority);
+        let (guard, poisoned) = match self.inner.write() {
+            Ok(guard) => (guard, false),
+            Err(poisoned) => (poisoned.into_inner(), true),
+        };
+        let guard = RwLockWriteGuard {
+            guard,
+            gate: &self.gate,
+        };
+        if poisoned {
+            Err(PoisonError::new(guard))
+        } else {
+            Ok(guard)
+        }
+    }
+
+    /// Whether a writer with a higher priority than `priority` is waiting.
+    pub fn has_waiters_above(&self, priority: Priority) -> bool {
+        self.gate.state().waiting_above(priority)
+    }
+
+    pub fn is_poisoned(&self) -> bool {
+        self.inner.is_poisoned()
+    }
+
+    pub fn get_mut(&mut self) -> LockResult<&mut T> {
+        self.inner.get_mut()
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for RwLock<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Write access to the value behind a [`RwLock`].
+pub struct RwLockWriteGuard<'a, T: ?Sized> {
+    guard: sync::RwLockWriteGuard<'a, T>,
+    gate: &'a Gate,
+}
+
+impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for RwLockWriteGuard<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.guard.fmt(f)
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        self.gate.leave();
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+        time::Duration,
+    };
+
+    use crate::dobot::dobot_trait::rwlock::{Priority, RwLock};
+
+    #[test]
+    fn test_waiting_writers_enter_by_priority() {
+        let lock = Arc::new(RwLock::new(Vec::new()));
+        let holder = lock.write().unwrap();
+
+        let order = [
+            Priority::Background,
+            Priority::Normal,
+            Priority::Emergency,
+            Priority::Interactive,
+        ];
+        let waiters: Vec<_> = order
+            .into_iter()
+            .map(|priority| {
+                let lock = Arc::clone(&lock);
+                let waiter = thread::spawn(move || {
+                    lock.write_with_priority(priority).unwrap().push(priority);
+                });
+                // Give the waiter time to queue up behind the holder.
+                thread::sleep(Duration::from_millis(20));
+                waiter
+            })
+            .collect();
+        assert!(lock.has_waiters_above(Priority::Interactive));
+
+        drop(holder);
+        for waiter in waiters {
+            waiter.join().unwrap();
+        }
+        assert_eq!(
+            *lock.read().unwrap(),
+            vec![
+                Priority::Emergency,
+                Priority::Interactive,
+                Priority::Normal,
+                Priority::Background,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lower_priorities_do_not_count_as_waiting_above() {
+        let lock = Arc::new(RwLock::new(0u32));
+        let holder = lock.write_with_priority(Priority::Normal).unwrap();
+
+        let waiter = {
+            let lock = Arc::clone(&lock);
+            thread::spawn(move || *lock.write_with_priority(Priority::Background).unwrap() += 1)
+        };
+        thread::sleep(Duration::from_millis(20));
+        assert!(!lock.has_waiters_above(Priority::Normal));
+        assert!(!lock.has_waiters_above(Priority::Background));
+
+        drop(holder);
+        waiter.join().unwrap();
+        assert_eq!(*lock.read().unwrap(), 1);
+        assert!(!lock.has_waiters_above(Priority::Background));
+    }
+
+    #[test]
+    fn test_poisoned_lock_still_hands_out_the_value() {
+        let lock = Arc::new(RwLock::new(Mutex::new(5)));
+        let poisoner = Arc::clone(&lock);
+        let _ = thread::spawn(move || {
+            let _guard = poisoner.write().unwrap();
+            panic!("poison the lock");
+        })
+        .join();
+
+        assert!(lock.is_poisoned());
+        let guard = lock.write().unwrap_err().into_inner();
+        assert_eq!(*guard.lock().unwrap(), 5);
+        drop(guard);
+        assert!(lock.write_with_priority(Priority::Emergency).is_err());
+    }
+}
@@ -0,0 +1,297 @@
+mod test;
+
+use std::{sync::Barrier, thread};
+
+use crate::dobot::{
+    dobot_core_serial::DobotApiSerialController,
+    dobot_trait::{
+        dobot_core::{
+            command_sender::{CommandSender, Dobot},
+            dobot_error::DobotError,
+            sub_command_dobot::{
+                alarm_control::AlarmControl, device_control::DeviceControl,
+                queue_control::QueueControl,
+            },
+        },
+        protocol::alarm::AlarmSet,
+        rwlock::RwLock,
+    },
+};
+
+use crate::dobot::dobot_core_serial::command_sender_serial::DobotCommandSender;
+
+/// One arm of a [`Fleet`], identified by the name and serial number it reported
+/// when it was added.
+pub struct FleetMember<T: CommandSender> {
+    name: String,
+    serial_number: String,
+    dobot: RwLock<Dobot<T>>,
+}
+
+impl<T: CommandSender> FleetMember<T> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn serial_number(&self) -> &str {
+        &self.serial_number
+    }
+
+    /// Returns the lock shared by every controller of this arm.
+    pub fn dobot(&self) -> &RwLock<Dobot<T>> {
+        &self.dobot
+    }
+
+    /// Creates a controller borrowing this arm.
+    pub fn controller(&self) -> DobotApiSerialController<'_, T> {
+        DobotApiSerialController::new(&self.dobot)
+    }
+}
+
+/// The outcome of a fleet-wide operation for a single arm.
+#[derive(Debug)]
+pub struct ArmResult<R> {
+    pub name: String,
+    pub result: Result<R, DobotError>,
+}
+
+/// A health snapshot of a single arm.
+#[derive(Debug, Clone)]
+pub struct ArmHealth {
+    pub name: String,
+    /// The device tick, if the arm answered.
+    pub device_time: Option<u32>,
+    /// Alarms currently raised on the arm.
+    pub active_alarms: AlarmSet,
+    /// The first error encountered while probing the arm, if any.
+    pub error: Option<DobotError>,
+}
+
+impl ArmHealth {
+    /// Returns `true` if the arm answered and has no active alarms.
+    pub fn is_healthy(&self) -> bool {
+        self.error.is_none() && self.active_alarms.is_empty()
+    }
+}
+
+/// A named collection of Dobot arms driven from one host.
+///
+/// Fleet-wide operations run on one thread per arm and return one
+/// [`ArmResult`] per arm, so a failing or unplugged arm never prevents the
+/// others from being commanded.
+pub struct Fleet<T: CommandSender> {
+    members: Vec<FleetMember<T>>,
+}
+
+impl<T: CommandSender> Default for Fleet<T> {
+    fn default() -> Self {
+        Self {
+            members: Vec::new(),
+        }
+    }
+}
+
+impl<T: CommandSender> Fleet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an arm, naming it after the device name it reports.
+    ///
+    /// Arms without a device name are named after their serial number. If the
+    /// name is already taken, the serial number is appended to keep names unique.
+    /// Fails with [`DobotError::DuplicateName`] if that name is taken as well.
+    pub fn add(&mut self, sender: T) -> Result<&FleetMember<T>, DobotError> {
+        let dobot = RwLock::new(Dobot::new(sender));
+        let (name, serial_number) = {
+            let mut device_control = DobotApiSerialController::new(&dobot).device_controller;
+            let mut buffer = [0u8; 64];
+            let len = device_control.get_device_name(&mut buffer)?;
+            let name = String::from_utf8_lossy(&buffer[..len]).trim().to_string();
+            let len = device_control.get_device_sn(&mut buffer)?;
+            let serial_number = String::from_utf8_lossy(&buffer[..len]).trim().to_string();
+            (name, serial_number)
+        };
+
+        let name = if name.is_empty() {
+            serial_number.clone()
+        } else if self.get(&name).is_some() {
+            format!("{}#{}", name, serial_number)
+        } else {
+            name
+        };
+        self.push(name, serial_number, dobot)
+    }
+
+    /// Adds an arm under an explicit name without probing it.
+    ///
+    /// Fails with [`DobotError::DuplicateName`] if the name is already taken.
+    pub fn add_named(&mut self, name: &str, sender: T) -> Result<&FleetMember<T>, DobotError> {
+        self.push(
+            name.to_string(),
+            String::new(),
+            RwLock::new(Dobot::new(sender)),
+        )
+    }
+
+    fn push(
+        &mut self,
+        name: String,
+        serial_number: String,
+        dobot: RwLock<Dobot<T>>,
+    ) -> Result<&FleetMember<T>, DobotError> {
+        if self.get(&name).is_some() {
+            return Err(DobotError::DuplicateName(name));
+        }
+        self.members.push(FleetMember {
+            name,
+            serial_number,
+            dobot,
+        });
+        Ok(&self.members[self.members.len() - 1])
+    }
+
+    /// Removes an arm from the fleet and returns it.
+    pub fn remove(&mut self, name: &str) -> Option<FleetMember<T>> {
+        let index = self.members.iter().position(|member| member.name == name)?;
+        Some(self.members.remove(index))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&FleetMember<T>> {
+        self.members.iter().find(|member| member.name == name)
+    }
+
+    pub fn members(&self) -> impl Iterator<Item = &FleetMember<T>> {
+        self.members.iter()
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.members.iter().map(|member| member.name()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Runs `f` concurrently on every arm and collects one result per arm, in
+    /// the order the arms were added.
+    pub fn for_each<R, F>(&self, f: F) -> Vec<ArmResult<R>>
+    where
+        R: Send,
+        F: Fn(&mut DobotApiSerialController<'_, T>) -> Result<R, DobotError> + Sync,
+    {
+        self.run(None, &f)
+    }
+
+    /// Like [`Fleet::for_each`], but every thread waits on a shared barrier so
+    /// that `f` starts on all arms at the same instant.
+    pub fn synchronized<R, F>(&self, f: F) -> Vec<ArmResult<R>>
+    where
+        R: Send,
+        F: Fn(&mut DobotApiSerialController<'_, T>) -> Result<R, DobotError> + Sync,
+    {
+        let barrier = Barrier::new(self.members.len());
+        self.run(Some(&barrier), &f)
+    }
+
+    fn run<R, F>(&self, barrier: Option<&Barrier>, f: &F) -> Vec<ArmResult<R>>
+    where
+        R: Send,
+        F: Fn(&mut DobotApiSerialController<'_, T>) -> Result<R, DobotError> + Sync,
+    {
+        thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .members
+                .iter()
+                .map(|member| {
+                    scope.spawn(move || {
+                        let mut controller = member.controller();
+                        if let Some(barrier) = barrier {
+                            barrier.wait();
+                        }
+                        f(&mut controller)
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .zip(self.members.iter())
+                .map(|(handle, member)| ArmResult {
+                    name: member.name.clone(),
+                    result: handle.join().unwrap_or(Err(DobotError::WorkerPanicked)),
+                })
+                .collect()
+        })
+    }
+
+    /// Starts the command queues of all arms together.
+    pub fn start_all_queues(&self) -> Vec<ArmResult<()>> {
+        self.synchronized(|controller| controller.queue_controller.set_queued_cmd_start_exec())
+    }
+
+    /// Stops the command queues of all arms together.
+    pub fn stop_all_queues(&self) -> Vec<ArmResult<()>> {
+        self.synchronized(|controller| controller.queue_controller.set_queued_cmd_stop_exec())
+    }
+
+    /// Returns the active alarms of every arm.
+    pub fn alarms(&self) -> Vec<ArmResult<AlarmSet>> {
+        self.for_each(|controller| {
+            controller.alarm_controller.get_active_alarms()
+        })
+    }
+
+    /// Clears the alarms of every arm.
+    pub fn clear_all_alarms(&self) -> Vec<ArmResult<()>> {
+        self.for_each(|controller| controller.alarm_controller.clear_all_alarms_state())
+    }
+
+    /// Probes every arm with a device time query and an alarm query.
+    pub fn health(&self) -> Vec<ArmHealth> {
+        self.for_each(|controller| {
+            let device_time = controller.device_controller.get_device_time()?;
+            let alarms = controller.alarm_controller.get_active_alarms()?;
+            Ok((device_time, alarms))
+        })
+        .into_iter()
+        .map(|arm| match arm.result {
+            Ok((device_time, active_alarms)) => ArmHealth {
+                name: arm.name,
+                device_time: Some(device_time),
+                active_alarms,
+                error: None,
+            },
+            Err(error) => ArmHealth {
+                name: arm.name,
+                device_time: None,
+                active_alarms: AlarmSet::new(),
+                error: Some(error),
+            },
+        })
+        .collect()
+    }
+}
+
+impl Fleet<DobotCommandSender> {
+    /// Opens every serial port in `port_names` and adds the arms that answer.
+    ///
+    /// Ports that cannot be opened or probed are returned alongside the fleet
+    /// instead of aborting the whole operation.
+    pub fn open(port_names: &[&str]) -> (Self, Vec<(String, DobotError)>) {
+        let mut fleet = Self::new();
+        let mut failures = Vec::new();
+        for port_name in port_names {
+            let added =
+                DobotCommandSender::new(port_name).and_then(|sender| fleet.add(sender).map(|_| ()));
+            if let Err(error) = added {
+                failures.push((port_name.to_string(), error));
+            }
+        }
+        (fleet, failures)
+    }
+}
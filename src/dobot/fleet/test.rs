@@ -0,0 +1,222 @@
+#[cfg(test)]
+mod tests {
+    use crate::dobot::{
+        dobot_trait::{
+            dobot_core::{
+                command_sender::simulated_command_sender::SimulatedCommandSender,
+                dobot_error::DobotError,
+                sub_command_dobot::{ptp_control::PTPControl, real_time_control::RealTimeControl},
+            },
+            protocol::{
+                alarm::{Alarm, AlarmSet},
+                bodies::tag_ptp_cmd::{PTPMode, TagPTPCmd},
+            },
+        },
+        fleet::Fleet,
+    };
+
+    fn create_fleet(count: usize) -> (Fleet<SimulatedCommandSender>, Vec<SimulatedCommandSender>) {
+        let mut fleet = Fleet::new();
+        let mut handles = Vec::new();
+        for i in 0..count {
+            let sim = SimulatedCommandSender::with_identity(
+                &format!("arm-{}", i),
+                &format!("SN{:04}", i),
+            );
+            handles.push(sim.clone());
+            fleet.add(sim).unwrap();
+        }
+        (fleet, handles)
+    }
+
+    #[test]
+    fn test_fleet_add_names_arms_by_device_name() {
+        let (fleet, _) = create_fleet(3);
+
+        assert_eq!(fleet.len(), 3);
+        assert_eq!(fleet.names(), vec!["arm-0", "arm-1", "arm-2"]);
+        assert_eq!(fleet.get("arm-1").unwrap().serial_number(), "SN0001");
+        assert!(fleet.get("arm-3").is_none());
+    }
+
+    #[test]
+    fn test_fleet_add_disambiguates_duplicate_names() {
+        let mut fleet = Fleet::new();
+        fleet
+            .add(SimulatedCommandSender::with_identity("cell", "A"))
+            .unwrap();
+        fleet
+            .add(SimulatedCommandSender::with_identity("cell", "B"))
+            .unwrap();
+        fleet
+            .add(SimulatedCommandSender::with_identity("", "C"))
+            .unwrap();
+
+        assert_eq!(fleet.names(), vec!["cell", "cell#B", "C"]);
+    }
+
+    #[test]
+    fn test_fleet_rejects_names_already_taken() {
+        let mut fleet = Fleet::new();
+        fleet
+            .add(SimulatedCommandSender::with_identity("cell", "A"))
+            .unwrap();
+        fleet.add_named("A", SimulatedCommandSender::new()).unwrap();
+
+        let result = fleet.add_named("cell", SimulatedCommandSender::new());
+        assert!(matches!(result, Err(DobotError::DuplicateName(name)) if name == "cell"));
+        let result = fleet.add(SimulatedCommandSender::with_identity("", "A"));
+        assert!(matches!(result, Err(DobotError::DuplicateName(name)) if name == "A"));
+        fleet
+            .add(SimulatedCommandSender::with_identity("cell", "B"))
+            .unwrap();
+        let result = fleet.add(SimulatedCommandSender::with_identity("cell", "B"));
+        assert!(matches!(result, Err(DobotError::DuplicateName(name)) if name == "cell#B"));
+
+        assert_eq!(fleet.names(), vec!["cell", "A", "cell#B"]);
+    }
+
+    #[test]
+    fn test_fleet_add_propagates_probe_error() {
+        let sim = SimulatedCommandSender::new();
+        sim.set_connected(false);
+        let mut fleet = Fleet::new();
+
+        let result = fleet.add(sim);
+        assert!(matches!(result, Err(DobotError::IO)));
+        assert!(fleet.is_empty());
+    }
+
+    #[test]
+    fn test_fleet_for_each_returns_result_per_arm() {
+        let (fleet, _) = create_fleet(4);
+
+        let results = fleet.for_each(|controller| {
+            controller.ptp_controller.set_ptp_cmd(
+                TagPTPCmd {
+                    ptp_mode: PTPMode::MovjXyz,
+                    x: 210.0,
+                    y: 10.0,
+                    z: 5.0,
+                    r: 0.0,
+                },
+                false,
+            )?;
+            controller.realtime_controller.get_pose()
+        });
+
+        assert_eq!(results.len(), 4);
+        for (i, arm) in results.iter().enumerate() {
+            assert_eq!(arm.name, format!("arm-{}", i));
+            let pose = arm.result.as_ref().unwrap();
+            assert_eq!((pose.x, pose.y, pose.z), (210.0, 10.0, 5.0));
+        }
+    }
+
+    #[test]
+    fn test_fleet_isolates_failing_arm() {
+        let (fleet, handles) = create_fleet(3);
+        handles[1].set_connected(false);
+
+        let results = fleet.for_each(|controller| controller.realtime_controller.get_pose());
+
+        assert!(results[0].result.is_ok());
+        assert!(matches!(results[1].result, Err(DobotError::IO)));
+        assert!(results[2].result.is_ok());
+    }
+
+    #[test]
+    fn test_fleet_isolates_panicking_arm() {
+        let (fleet, handles) = create_fleet(2);
+        handles[0].with_state(|state| state.pose.x = -1.0);
+        let results = fleet.for_each(|controller| {
+            let pose = controller.realtime_controller.get_pose()?;
+            if pose.x < 0.0 {
+                panic!("simulated worker failure");
+            }
+            Ok(pose)
+        });
+        assert!(matches!(results[0].result, Err(DobotError::WorkerPanicked)));
+        assert!(results[1].result.is_ok());
+    }
+
+    #[test]
+    fn test_fleet_start_all_queues_runs_pending_commands() {
+        let (fleet, handles) = create_fleet(3);
+        fleet
+            .stop_all_queues()
+            .into_iter()
+            .for_each(|arm| arm.result.unwrap());
+
+        let queued = fleet.for_each(|controller| {
+            controller.ptp_controller.set_ptp_cmd(
+                TagPTPCmd {
+                    ptp_mode: PTPMode::MovlXyz,
+                    x: 180.0,
+                    y: -20.0,
+                    z: 30.0,
+                    r: 15.0,
+                },
+                true,
+            )
+        });
+        for arm in &queued {
+            assert_eq!(arm.result.as_ref().unwrap(), &Some(1));
+        }
+        for sim in &handles {
+            assert_eq!(sim.pose().x, 200.0);
+        }
+
+        let started = fleet.start_all_queues();
+        assert!(started.iter().all(|arm| arm.result.is_ok()));
+        for sim in &handles {
+            let pose = sim.pose();
+            assert_eq!((pose.x, pose.y, pose.z, pose.r), (180.0, -20.0, 30.0, 15.0));
+        }
+    }
+
+    #[test]
+    fn test_fleet_aggregates_alarms_and_health() {
+        let (fleet, handles) = create_fleet(3);
+        handles[2].raise_alarm(Alarm::LimitAxis1Pos as u8);
+        handles[1].set_connected(false);
+
+        let alarms = fleet.alarms();
+        assert_eq!(alarms[0].result.as_ref().unwrap(), &AlarmSet::new());
+        assert!(alarms[1].result.is_err());
+        assert_eq!(
+            alarms[2].result.as_ref().unwrap(),
+            &AlarmSet::from_iter([Alarm::LimitAxis1Pos])
+        );
+
+        let health = fleet.health();
+        assert!(health[0].is_healthy());
+        assert!(health[0].device_time.is_some());
+        assert!(!health[1].is_healthy());
+        assert!(health[1].error.is_some());
+        assert!(!health[2].is_healthy());
+        assert_eq!(
+            health[2].active_alarms,
+            AlarmSet::from_iter([Alarm::LimitAxis1Pos])
+        );
+
+        handles[1].set_connected(true);
+        assert!(
+            fleet
+                .clear_all_alarms()
+                .iter()
+                .all(|arm| arm.result.is_ok())
+        );
+        assert!(fleet.health().iter().all(|arm| arm.is_healthy()));
+    }
+
+    #[test]
+    fn test_fleet_remove() {
+        let (mut fleet, _) = create_fleet(2);
+
+        let removed = fleet.remove("arm-0").unwrap();
+        assert_eq!(removed.name(), "arm-0");
+        assert_eq!(fleet.names(), vec!["arm-1"]);
+        assert!(fleet.remove("arm-0").is_none());
+    }
+}
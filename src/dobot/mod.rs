@@ -1,3 +1,5 @@
 #[macro_use]
 pub mod dobot_trait;
 pub mod dobot_core_serial;
+#[cfg(feature = "std")]
+pub mod fleet;
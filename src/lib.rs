@@ -1,3 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+// Lets `#[derive(Body)]` refer to this crate by name from inside it.
+extern crate self as dobot_api_rs;
+
 pub mod dobot;
 